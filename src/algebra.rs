@@ -0,0 +1,127 @@
+// Products of multivectors stored as dense coefficient slices.
+//
+// Basis blades are indexed by bit masks over the basis vectors in ascending
+// order: with basis vectors e0, e1, e2 the blade e02 has index 0b101 and
+// e012 index 0b111. Every blade is stored in this canonical order, so a
+// blade such as e20 is the coefficient of e02 negated.
+//
+// These routines favor generality over speed and back the scalar geometry
+// modules, where the metric of each basis vector is a parameter.
+
+/// Sign from reordering the product of blades `a` and `b` into canonical
+/// order.
+fn reorder(a: usize, b: usize) -> f32 {
+    let mut a = a >> 1;
+    let mut swaps = 0;
+    while a != 0 {
+        swaps += (a & b).count_ones();
+        a >>= 1;
+    }
+    if swaps % 2 == 0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+fn grade(blade: usize) -> u32 {
+    blade.count_ones()
+}
+
+/// Coefficient of the geometric product of blades `a` and `b`, the squares
+/// of their shared basis vectors times the reordering sign.
+fn gp_factor(a: usize, b: usize, metric: &[f32]) -> f32 {
+    let mut factor = reorder(a, b);
+    for (k, square) in metric.iter().enumerate() {
+        if (a & b) >> k & 1 == 1 {
+            factor *= square;
+        }
+    }
+    factor
+}
+
+/// Geometric product, with `metric[k]` the square of basis vector `k`.
+pub(crate) fn gp(a: &[f32], b: &[f32], metric: &[f32], out: &mut [f32]) {
+    product(a, b, out, |i, j| Some((i ^ j, gp_factor(i, j, metric))))
+}
+
+/// Outer product (meet).
+pub(crate) fn outer(a: &[f32], b: &[f32], out: &mut [f32]) {
+    product(a, b, out, |i, j| {
+        if i & j == 0 {
+            Some((i | j, reorder(i, j)))
+        } else {
+            None
+        }
+    })
+}
+
+/// Symmetric inner product: the part of the geometric product of grade
+/// `|r - s|` for blades of grades `r` and `s`.
+pub(crate) fn inner(a: &[f32], b: &[f32], metric: &[f32], out: &mut [f32]) {
+    product(a, b, out, |i, j| {
+        let (r, s) = (grade(i), grade(j));
+        if grade(i ^ j) + r.min(s) == r.max(s) {
+            Some((i ^ j, gp_factor(i, j, metric)))
+        } else {
+            None
+        }
+    })
+}
+
+/// Regressive product (join), the outer product taken in the complements.
+pub(crate) fn regressive(a: &[f32], b: &[f32], out: &mut [f32]) {
+    let n = out.len();
+    let (mut ca, mut cb, mut c) = ([0.0; 16], [0.0; 16], [0.0; 16]);
+    complement(a, &mut ca[..n]);
+    complement(b, &mut cb[..n]);
+    outer(&ca[..n], &cb[..n], &mut c[..n]);
+    uncomplement(&c[..n], out);
+}
+
+/// Reverse, flipping the sign of grades 2 and 3 (mod 4).
+pub(crate) fn reverse(a: &mut [f32]) {
+    for (i, x) in a.iter_mut().enumerate() {
+        if grade(i) % 4 >= 2 {
+            *x = -*x;
+        }
+    }
+}
+
+/// Right complement: each blade maps to the blade completing it to the
+/// pseudoscalar, `e_i ^ J(e_i) = I`.
+fn complement(a: &[f32], out: &mut [f32]) {
+    let full = a.len() - 1;
+    for (i, &x) in a.iter().enumerate() {
+        out[full ^ i] = x * reorder(i, full ^ i);
+    }
+}
+
+fn uncomplement(a: &[f32], out: &mut [f32]) {
+    let full = a.len() - 1;
+    for (c, &x) in a.iter().enumerate() {
+        let i = full ^ c;
+        out[i] = x * reorder(i, c);
+    }
+}
+
+fn product(
+    a: &[f32],
+    b: &[f32],
+    out: &mut [f32],
+    rule: impl Fn(usize, usize) -> Option<(usize, f32)>,
+) {
+    for (i, &x) in a.iter().enumerate() {
+        if x == 0.0 {
+            continue;
+        }
+        for (j, &y) in b.iter().enumerate() {
+            if y == 0.0 {
+                continue;
+            }
+            if let Some((k, sign)) = rule(i, j) {
+                out[k] += sign * x * y;
+            }
+        }
+    }
+}
@@ -0,0 +1,300 @@
+//! # Skeletal animation
+//!
+//! A [`Skeleton`] is a flat array of joints stored parent-first. Each joint
+//! keeps the inverse of its bind pose (the motor taking the model origin to
+//! the joint in the rest pose) and owns a contiguous group of skin positions
+//! given in bind (model) space.
+//!
+//! A [`Pose`] holds one *local* motor per joint, relative to the parent joint
+//! (or to the world location of the instance for root joints). Animation
+//! clips are sequences of poses which are sampled with [`slerp`] between
+//! neighbouring keyframes.
+//!
+//! Forward kinematics then composes the local motors down the hierarchy to
+//! produce a world motor per joint, and applies the skinning motor
+//! `world * inv_bind_pose` to each joint's group of positions with
+//! [`Motor::conj_points`].
+//!
+//! ```rust
+//! use klein::anim::{Skeleton, SkeletonInstance};
+//! use klein::{Motor, Point, Translator};
+//!
+//! let mut skeleton = Skeleton::default();
+//! let root = skeleton.add_joint("root", None, Motor::IDENTITY, &[Point::new(0.0, 0.0, 0.0)]);
+//! let bind = Motor::from_translator(Translator::new(1.0, 0.0, 1.0, 0.0));
+//! skeleton.add_joint("tip", Some(root), bind, &[Point::new(0.0, 1.0, 0.0)]);
+//!
+//! let mut instance = SkeletonInstance::new(&skeleton, Motor::IDENTITY);
+//! instance.animate_keyframe(&skeleton, &skeleton.bind_pose());
+//! assert_eq!(instance.joint_positions.len(), 2);
+//! ```
+
+use crate::{Line, Motor, Point};
+use core::{convert::TryFrom, ops::Range};
+
+/// Blend between two motors with a parameter `t` in the range `[0, 1]`
+/// along the screw motion connecting them.
+pub fn slerp(a: Motor, b: Motor, t: f32) -> Motor {
+    // Starting from a, the motor needed to get to b is `b * a.reversed()`.
+    // Take the shorter of the two screw motions representing it.
+    let b = if dot(a, b) < 0.0 { -b } else { b };
+    let step: Line = (b * a.reversed()).log() * t;
+    step.exp() * a
+}
+
+/// Cheaper normalized linear blend between two motors. The result deviates
+/// from `slerp` for motors that are far apart.
+pub fn nlerp(a: Motor, b: Motor, t: f32) -> Motor {
+    let b = if dot(a, b) < 0.0 { -b } else { b };
+    (a * (1.0 - t) + b * t).normalized()
+}
+
+// Dot product of the rotational parts, used to pick the shorter path.
+fn dot(a: Motor, b: Motor) -> f32 {
+    let (a, b) = (a.p1.into_array(), b.p1.into_array());
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
+
+/// A single joint of a [`Skeleton`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Joint {
+    /// Inverse of the bind pose, taking model space to the joint space.
+    pub inv_bind_pose: Motor,
+    /// Distance to the parent joint in the joint array. Zero for roots.
+    pub parent_offset: u16,
+    /// Number of skin positions owned by this joint.
+    pub group_size: u16,
+}
+
+/// A joint hierarchy along with the bind-space skin positions of every joint.
+#[derive(Clone, Debug, Default)]
+pub struct Skeleton {
+    /// Joints and their names, parents first.
+    pub joints: Vec<(Joint, String)>,
+    /// Skin positions in bind space, grouped by joint in joint order.
+    pub positions: Vec<Point>,
+}
+
+impl Skeleton {
+    /// Append a joint and return its index.
+    ///
+    /// `bind_pose` is the model space motor of the joint in the rest pose
+    /// and `positions` are the bind space skin positions it drives.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` does not refer to an existing joint, if the joint
+    /// is more than `u16::MAX` joints past its parent, or if `positions` has
+    /// more than `u16::MAX` entries.
+    pub fn add_joint(
+        &mut self,
+        name: &str,
+        parent: Option<usize>,
+        bind_pose: Motor,
+        positions: &[Point],
+    ) -> usize {
+        let index = self.joints.len();
+        let parent_offset = match parent {
+            Some(parent) => {
+                assert!(parent < index, "parent joint must be added first");
+                u16::try_from(index - parent).expect("parent joint is too far away")
+            }
+            None => 0,
+        };
+
+        let joint = Joint {
+            inv_bind_pose: bind_pose.normalized().reversed(),
+            parent_offset,
+            group_size: u16::try_from(positions.len()).expect("too many positions for one joint"),
+        };
+        self.joints.push((joint, name.to_string()));
+        self.positions.extend_from_slice(positions);
+        index
+    }
+
+    /// Number of joints.
+    pub fn len(&self) -> usize {
+        self.joints.len()
+    }
+
+    /// Whether the skeleton has no joints.
+    pub fn is_empty(&self) -> bool {
+        self.joints.is_empty()
+    }
+
+    /// Index of the joint named `name`.
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.joints.iter().position(|(_, n)| n == name)
+    }
+
+    /// Index of the parent of joint `index`, if any.
+    pub fn parent(&self, index: usize) -> Option<usize> {
+        match self.joints[index].0.parent_offset {
+            0 => None,
+            offset => Some(index - offset as usize),
+        }
+    }
+
+    /// Range of `positions` owned by each joint, in joint order.
+    pub fn groups(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.joints.iter().scan(0, |start, (joint, _)| {
+            let range = *start..*start + joint.group_size as usize;
+            *start = range.end;
+            Some(range)
+        })
+    }
+
+    /// The rest pose expressed as local (parent relative) joint motors.
+    pub fn bind_pose(&self) -> Pose {
+        let joint_poses = (0..self.len())
+            .map(|i| {
+                let bind = self.joints[i].0.inv_bind_pose.reversed();
+                match self.parent(i) {
+                    Some(parent) => self.joints[parent].0.inv_bind_pose * bind,
+                    None => bind,
+                }
+            })
+            .collect();
+
+        Pose { joint_poses }
+    }
+}
+
+/// Local joint motors, one per joint of a skeleton.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Pose {
+    /// Array of poses for each joint.
+    pub joint_poses: Vec<Motor>,
+    // NOTE: some engines allow animators to include scale data with each joint
+    // pose, but we'll ignore that for the time being.
+}
+
+/// A sequence of keyframe poses.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Clip {
+    /// Array of poses.
+    pub poses: Vec<Pose>,
+    /// Timestamp of each pose (ascending, in ticks).
+    pub timestamps: Vec<u16>,
+    /// Duration of a tick in microseconds.
+    pub timestamp_us: u32,
+}
+
+impl Clip {
+    /// Duration of the clip in microseconds.
+    pub fn duration_us(&self) -> u64 {
+        let last = self.timestamps.last().copied().unwrap_or(0);
+        u64::from(last) * u64::from(self.timestamp_us)
+    }
+
+    /// The keyframes straddling `time_us` and the interpolation parameter
+    /// between them. Times outside of the clip are clamped to its ends.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clip has no poses.
+    pub fn pose_endpoints(&self, time_us: u64) -> (&Pose, &Pose, f32) {
+        assert!(!self.poses.is_empty(), "empty clip");
+        let tick = time_us as f64 / f64::from(self.timestamp_us.max(1));
+
+        let next = self
+            .timestamps
+            .iter()
+            .position(|&ts| f64::from(ts) > tick)
+            .unwrap_or(self.poses.len());
+
+        if next == 0 {
+            (&self.poses[0], &self.poses[0], 0.0)
+        } else if next >= self.poses.len() {
+            let last = &self.poses[self.poses.len() - 1];
+            (last, last, 0.0)
+        } else {
+            let t0 = f64::from(self.timestamps[next - 1]);
+            let t1 = f64::from(self.timestamps[next]);
+            let t = ((tick - t0) / (t1 - t0)) as f32;
+            (&self.poses[next - 1], &self.poses[next], t)
+        }
+    }
+
+    /// Sample the clip at `time_us` into `out`, blending the joint motors of
+    /// neighbouring keyframes with [`slerp`].
+    pub fn sample(&self, time_us: u64, out: &mut Pose) {
+        let (previous, next, t) = self.pose_endpoints(time_us);
+
+        out.joint_poses.clear();
+        out.joint_poses.extend(
+            previous
+                .joint_poses
+                .iter()
+                .zip(&next.joint_poses)
+                .map(|(&a, &b)| slerp(a, b, t)),
+        );
+    }
+}
+
+/// The animated state of a skeleton placed in the world.
+#[derive(Clone, Debug, Default)]
+pub struct SkeletonInstance {
+    /// Placement of the whole skeleton.
+    pub world_location: Motor,
+    /// World space motor of every joint.
+    pub world_motors: Vec<Motor>,
+    /// World space skin positions, grouped like `Skeleton::positions`.
+    pub joint_positions: Vec<Point>,
+}
+
+impl SkeletonInstance {
+    pub fn new(skeleton: &Skeleton, world_location: Motor) -> Self {
+        Self {
+            world_location,
+            world_motors: vec![Motor::IDENTITY; skeleton.len()],
+            joint_positions: skeleton.positions.clone(),
+        }
+    }
+
+    /// Forward kinematics: compose the local joint motors of `target` down
+    /// the hierarchy and transform every joint's skin positions.
+    pub fn animate_keyframe(&mut self, skeleton: &Skeleton, target: &Pose) {
+        self.world_motors.resize(skeleton.len(), Motor::IDENTITY);
+        self.joint_positions
+            .resize(skeleton.positions.len(), Point::ORIGIN);
+
+        for (i, group) in skeleton.groups().enumerate() {
+            let parent = match skeleton.parent(i) {
+                Some(parent) => self.world_motors[parent],
+                None => self.world_location,
+            };
+            let world = parent * target.joint_poses[i];
+            self.world_motors[i] = world;
+
+            // Positions are given in bind space, so undo the bind pose first.
+            // The same motor is applied to the whole group at once.
+            let skin = world * skeleton.joints[i].0.inv_bind_pose;
+            let input = skeleton.positions[group.clone()].iter().copied();
+            for (out, p) in self.joint_positions[group]
+                .iter_mut()
+                .zip(skin.conj_points(input))
+            {
+                *out = p;
+            }
+        }
+    }
+
+    /// Transform the instance to the pose sampled from `clip` at `time_us`.
+    /// `scratch` holds the interpolated joint poses.
+    pub fn animate_sample(
+        &mut self,
+        skeleton: &Skeleton,
+        clip: &Clip,
+        time_us: u64,
+        scratch: &mut Pose,
+    ) {
+        clip.sample(time_us, scratch);
+        self.animate_keyframe(skeleton, scratch);
+    }
+
+    /// World space location of joint `index` itself.
+    pub fn joint_origin(&self, index: usize) -> Point {
+        self.world_motors[index].conj_origin()
+    }
+}
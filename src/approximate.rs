@@ -0,0 +1,179 @@
+//! `approx` support (enabled with the `approx` feature).
+//!
+//! Every entity implements `AbsDiffEq`, `RelativeEq` and `UlpsEq` by comparing
+//! its stored components lane by lane, so the usual assertion macros work:
+//!
+//! ```ignore
+//! assert_relative_eq!(m1 * m2, expected, max_relative = 1e-5);
+//! ```
+//!
+//! Since entities in projective geometry are only defined up to a nonzero
+//! scale (a point `2e123 + 2e032` is the same point as `e123 + e032`), the
+//! [`Projective`] wrapper provides the same comparisons after bringing both
+//! operands to a common scale.
+
+use crate::{
+    Branch, Direction, Dual, Flector, IdealLine, Line, Motor, Plane, Point, Rotor, Translator,
+};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+/// Wraps an entity so that the `approx` comparisons treat it as equal to
+/// any nonzero multiple of itself.
+///
+/// Both operands are divided by their component at the position where the
+/// left operand is largest in magnitude before comparing, which makes the
+/// comparison insensitive to the overall sign and scale.
+///
+/// ```ignore
+/// use approx::assert_relative_eq;
+/// use klein::{Point, Projective};
+///
+/// let a = Point::new(1.0, 2.0, 3.0);
+/// assert_relative_eq!(Projective(a), Projective(a * -4.0));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Projective<T>(pub T);
+
+// Divides both `a` and `b` by their component at the position where `a` is
+// largest in magnitude. Returns `None` if `b` vanishes there but `a` does not.
+fn common_scale<A: AsMut<[f32]>>(mut a: A, mut b: A) -> Option<(A, A)> {
+    let (ka, kb) = {
+        let (a, b) = (a.as_mut(), b.as_mut());
+        let mut k = 0;
+        for i in 1..a.len() {
+            if a[i].abs() > a[k].abs() {
+                k = i;
+            }
+        }
+        (a[k], b[k])
+    };
+
+    if ka == 0.0 {
+        // The left operand vanishes so both must be (exactly) zero.
+        return Some((a, b));
+    }
+    if kb == 0.0 {
+        return None;
+    }
+
+    a.as_mut().iter_mut().for_each(|x| *x /= ka);
+    b.as_mut().iter_mut().for_each(|x| *x /= kb);
+    Some((a, b))
+}
+
+macro_rules! impl_approx {
+    ($ty:ident [$n:literal] |$s:ident| $lanes:expr) => {
+        impl $ty {
+            fn lanes(&self) -> [f32; $n] {
+                let $s = self;
+                $lanes
+            }
+        }
+
+        impl AbsDiffEq for $ty {
+            type Epsilon = f32;
+
+            fn default_epsilon() -> f32 {
+                f32::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+                self.lanes()[..].abs_diff_eq(&other.lanes()[..], epsilon)
+            }
+        }
+
+        impl RelativeEq for $ty {
+            fn default_max_relative() -> f32 {
+                f32::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+                self.lanes()[..].relative_eq(&other.lanes()[..], epsilon, max_relative)
+            }
+        }
+
+        impl UlpsEq for $ty {
+            fn default_max_ulps() -> u32 {
+                f32::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+                self.lanes()[..].ulps_eq(&other.lanes()[..], epsilon, max_ulps)
+            }
+        }
+
+        impl PartialEq for Projective<$ty> {
+            fn eq(&self, other: &Self) -> bool {
+                match common_scale(self.0.lanes(), other.0.lanes()) {
+                    Some((a, b)) => a == b,
+                    None => false,
+                }
+            }
+        }
+
+        impl AbsDiffEq for Projective<$ty> {
+            type Epsilon = f32;
+
+            fn default_epsilon() -> f32 {
+                f32::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+                match common_scale(self.0.lanes(), other.0.lanes()) {
+                    Some((a, b)) => a[..].abs_diff_eq(&b[..], epsilon),
+                    None => false,
+                }
+            }
+        }
+
+        impl RelativeEq for Projective<$ty> {
+            fn default_max_relative() -> f32 {
+                f32::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+                match common_scale(self.0.lanes(), other.0.lanes()) {
+                    Some((a, b)) => a[..].relative_eq(&b[..], epsilon, max_relative),
+                    None => false,
+                }
+            }
+        }
+
+        impl UlpsEq for Projective<$ty> {
+            fn default_max_ulps() -> u32 {
+                f32::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+                match common_scale(self.0.lanes(), other.0.lanes()) {
+                    Some((a, b)) => a[..].ulps_eq(&b[..], epsilon, max_ulps),
+                    None => false,
+                }
+            }
+        }
+    };
+}
+
+fn pair(a: [f32; 4], b: [f32; 4]) -> [f32; 8] {
+    [a[0], a[1], a[2], a[3], b[0], b[1], b[2], b[3]]
+}
+
+impl_approx!(Plane[4] | s | s.p0.into_array());
+impl_approx!(Point[4] | s | s.p3.into_array());
+impl_approx!(Direction[4] | s | s.p3.into_array());
+impl_approx!(Branch[4] | s | s.p1.into_array());
+impl_approx!(IdealLine[4] | s | s.p2.into_array());
+impl_approx!(Line[8] | s | pair(s.p1.into_array(), s.p2.into_array()));
+impl_approx!(Rotor[4] | s | s.p1.into_array());
+impl_approx!(Motor[8] | s | pair(s.p1.into_array(), s.p2.into_array()));
+impl_approx!(Flector[8] | s | pair(s.p0.into_array(), s.p3.into_array()));
+impl_approx!(Dual[2] | s | [s.p, s.q]);
+
+// The scalar of a translator is implied, but takes part in the projective
+// comparison.
+impl_approx!(
+    Translator[4] | s | {
+        let [_, e01, e02, e03] = s.p2.into_array();
+        [1.0, e01, e02, e03]
+    }
+);
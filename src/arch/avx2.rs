@@ -0,0 +1,200 @@
+// AVX2 + FMA kernels.
+//
+// Every function here is compiled with `avx2` and `fma` enabled regardless of
+// the crate-wide target features, so callers *must* check `has_avx2_fma()`
+// before invoking them (see `batch.rs`). The motor product calls `gp_mm_fma`
+// only when both features are enabled at compile time.
+//
+// The batch sandwich kernels pack two entities into a single YMM register.
+// `_mm256_shuffle_ps` and `_mm256_dp_ps` operate on each 128-bit lane
+// independently, which means the SSE swizzles from `sandwitch.rs` carry over
+// verbatim: the low lane holds the first entity and the high lane the second.
+
+use super::{
+    batch::{Sw012, Sw312, SwMM11, SwMM22},
+    f32x4,
+};
+use core::arch::x86_64::*;
+
+macro_rules! shuffle8 {
+    ($reg:expr, [$x:expr, $y:expr, $z:expr, $w:expr]) => {
+        f32x8(_mm256_shuffle_ps(
+            $reg.0,
+            $reg.0,
+            core::arch::x86_64::_MM_SHUFFLE($x, $y, $z, $w),
+        ))
+    };
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+struct f32x8(__m256);
+
+impl f32x8 {
+    /// Pack `lo` into the low lane and `hi` into the high lane.
+    #[inline(always)]
+    unsafe fn pair(lo: f32x4, hi: f32x4) -> Self {
+        Self(_mm256_insertf128_ps(_mm256_castps128_ps256(lo.0), hi.0, 1))
+    }
+
+    /// Broadcast a 128-bit register to both lanes.
+    #[inline(always)]
+    unsafe fn splat(a: f32x4) -> Self {
+        Self::pair(a, a)
+    }
+
+    #[inline(always)]
+    unsafe fn split(self) -> (f32x4, f32x4) {
+        (
+            f32x4(_mm256_castps256_ps128(self.0)),
+            f32x4(_mm256_extractf128_ps(self.0, 1)),
+        )
+    }
+
+    #[inline(always)]
+    unsafe fn add(self, b: Self) -> Self {
+        Self(_mm256_add_ps(self.0, b.0))
+    }
+
+    #[inline(always)]
+    unsafe fn mul(self, b: Self) -> Self {
+        Self(_mm256_mul_ps(self.0, b.0))
+    }
+
+    /// `self * b + c`
+    #[inline(always)]
+    unsafe fn fmadd(self, b: Self, c: Self) -> Self {
+        Self(_mm256_fmadd_ps(self.0, b.0, c.0))
+    }
+
+    /// Per-lane equivalent of `f32x4::hi_dp`
+    #[inline(always)]
+    unsafe fn hi_dp(self, b: Self) -> Self {
+        Self(_mm256_dp_ps(self.0, b.0, 0b1110_0001))
+    }
+}
+
+impl f32x4 {
+    /// `self * b + c`
+    #[inline(always)]
+    unsafe fn fmadd(self, b: Self, c: Self) -> Self {
+        Self(_mm_fmadd_ps(self.0, b.0, c.0))
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+pub unsafe fn sw012_x2(k: &Sw012, a: f32x4, b: f32x4) -> (f32x4, f32x4) {
+    let a = f32x8::pair(a, b);
+
+    let p = f32x8::splat(k.tmp3).mul(a);
+    let p = f32x8::splat(k.tmp1).fmadd(shuffle8!(a, [1, 3, 2, 0]), p);
+    let p = f32x8::splat(k.tmp2).fmadd(shuffle8!(a, [2, 1, 3, 0]), p);
+
+    let p = if let Some(tmp4) = k.tmp4 {
+        p.add(f32x8::splat(tmp4).hi_dp(a))
+    } else {
+        p
+    };
+
+    p.split()
+}
+
+#[target_feature(enable = "avx2,fma")]
+pub unsafe fn sw312_x2(k: &Sw312, a: f32x4, b: f32x4) -> (f32x4, f32x4) {
+    let a = f32x8::pair(a, b);
+
+    let p = f32x8::splat(k.tmp3).mul(a);
+    let p = f32x8::splat(k.tmp1).fmadd(shuffle8!(a, [2, 1, 3, 0]), p);
+    let p = f32x8::splat(k.tmp2).fmadd(shuffle8!(a, [1, 3, 2, 0]), p);
+
+    let p = if let Some(tmp4) = k.tmp4 {
+        f32x8::splat(tmp4).fmadd(shuffle8!(a, [0, 0, 0, 0]), p)
+    } else {
+        p
+    };
+
+    p.split()
+}
+
+#[target_feature(enable = "avx2,fma")]
+pub unsafe fn sw_mm11_x2(k: &SwMM11, a: f32x4, b: f32x4) -> (f32x4, f32x4) {
+    let p1 = f32x8::pair(a, b);
+
+    let out = f32x8::splat(k.tmp).mul(p1);
+    let out = f32x8::splat(k.tmp2).fmadd(shuffle8!(p1, [1, 3, 2, 0]), out);
+    let out = f32x8::splat(k.tmp3).fmadd(shuffle8!(p1, [2, 1, 3, 0]), out);
+
+    out.split()
+}
+
+#[target_feature(enable = "avx2,fma")]
+pub unsafe fn sw_mm22_x2(
+    k: &SwMM22,
+    a: (f32x4, f32x4),
+    b: (f32x4, f32x4),
+) -> ((f32x4, f32x4), (f32x4, f32x4)) {
+    let p1_in = f32x8::pair(a.0, b.0);
+    let p2_in = f32x8::pair(a.1, b.1);
+
+    let tmp = f32x8::splat(k.tmp);
+    let tmp2 = f32x8::splat(k.tmp2);
+    let tmp3 = f32x8::splat(k.tmp3);
+
+    let p1_in_xzwy = shuffle8!(p1_in, [1, 3, 2, 0]);
+    let p1_in_xwyz = shuffle8!(p1_in, [2, 1, 3, 0]);
+
+    let p1_out = tmp.mul(p1_in);
+    let p1_out = tmp2.fmadd(p1_in_xzwy, p1_out);
+    let p1_out = tmp3.fmadd(p1_in_xwyz, p1_out);
+
+    let p2_out = tmp.mul(p2_in);
+    let p2_out = tmp2.fmadd(shuffle8!(p2_in, [1, 3, 2, 0]), p2_out);
+    let p2_out = tmp3.fmadd(shuffle8!(p2_in, [2, 1, 3, 0]), p2_out);
+
+    // If what is being applied is a rotor, the non-directional
+    // components of the line are left untouched
+    let p2_out = if let Some((tmp4, tmp5, tmp6)) = k.translate {
+        let p2_out = f32x8::splat(tmp4).fmadd(p1_in, p2_out);
+        let p2_out = f32x8::splat(tmp5).fmadd(p1_in_xwyz, p2_out);
+        f32x8::splat(tmp6).fmadd(p1_in_xzwy, p2_out)
+    } else {
+        p2_out
+    };
+
+    let (a1, b1) = p1_out.split();
+    let (a2, b2) = p2_out.split();
+    ((a1, a2), (b1, b2))
+}
+
+/// Optimized motor * motor operation using fused multiply-add.
+/// See `gp_mm` in multivector_gp.rs for the expanded expression.
+///
+/// # Safety
+///
+/// The running CPU must support AVX2 and FMA (see `has_avx2_fma`).
+#[target_feature(enable = "avx2,fma")]
+pub(crate) unsafe fn gp_mm_fma(a: f32x4, b: f32x4, c: f32x4, d: f32x4) -> (f32x4, f32x4) {
+    let a_xxxx = shuffle!(a, [0, 0, 0, 0]);
+    let a_zyzw = shuffle!(a, [3, 2, 1, 2]);
+    let a_ywyz = shuffle!(a, [2, 1, 3, 1]);
+    let a_wzwy = shuffle!(a, [1, 3, 2, 3]);
+    let c_wwyz = shuffle!(c, [2, 1, 3, 3]);
+    let c_yzwy = shuffle!(c, [1, 3, 2, 1]);
+    let s_flip = f32x4::set0(-0.0);
+
+    // The rotational block is deliberately left unfused so that a motor times
+    // its inverse cancels to exactly the same result as the SSE path.
+    let tmp = (a_ywyz * c_yzwy + a_zyzw * shuffle!(c, [0, 0, 0, 2])) ^ s_flip;
+    let p1 = a_xxxx * c + tmp - a_wzwy * c_wwyz;
+
+    let p2 = a_xxxx.fmadd(d, b * shuffle!(c, [0, 0, 0, 0]));
+    let p2 = a_ywyz.fmadd(shuffle!(d, [1, 3, 2, 1]), p2);
+    let p2 = shuffle!(b, [2, 1, 3, 1]).fmadd(c_yzwy, p2);
+    let tmp = a_zyzw * shuffle!(d, [0, 0, 0, 2]);
+    let tmp = a_wzwy.fmadd(shuffle!(d, [2, 1, 3, 3]), tmp);
+    let tmp = shuffle!(b, [0, 0, 0, 2]).fmadd(shuffle!(c, [3, 2, 1, 2]), tmp);
+    let tmp = shuffle!(b, [1, 3, 2, 3]).fmadd(c_wwyz, tmp);
+    let p2 = p2 - (tmp ^ s_flip);
+
+    (p1, p2)
+}
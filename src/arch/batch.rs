@@ -0,0 +1,123 @@
+// Runtime backend selection for the batch sandwich routines.
+//
+// Each batch routine in `sandwitch.rs` precomputes a handful of temporaries
+// that only depend on the rotor/motor being applied and packs them into a
+// kernel. The kernel knows how to transform a single entity with 128-bit SSE
+// and, when the CPU supports it, two entities at once with 256-bit AVX2 and
+// fused multiply-add. `Batch` drives a kernel over an input iterator, pulling
+// entities in pairs whenever the wide path is available.
+
+use super::f32x4;
+
+/// Returns `true` if the AVX2 + FMA kernels can be used on the running CPU.
+///
+/// When the crate is compiled with both target features enabled (e.g. via
+/// `-C target-cpu=native`) this folds to a constant, otherwise the CPU is
+/// queried once and the answer is cached by the standard library.
+#[inline(always)]
+pub fn has_avx2_fma() -> bool {
+    if cfg!(all(target_feature = "avx2", target_feature = "fma")) {
+        true
+    } else {
+        is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma")
+    }
+}
+
+pub trait Kernel {
+    type Item: Copy;
+
+    /// Transform a single entity using SSE.
+    fn apply(&self, a: Self::Item) -> Self::Item;
+
+    /// Transform two entities at once using AVX2 + FMA.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure `has_avx2_fma()` holds.
+    unsafe fn apply2(&self, a: Self::Item, b: Self::Item) -> (Self::Item, Self::Item);
+}
+
+pub struct Batch<I, K: Kernel> {
+    input: I,
+    kernel: K,
+    wide: bool,
+    pending: Option<K::Item>,
+}
+
+impl<I, K: Kernel> Batch<I, K> {
+    #[inline(always)]
+    pub fn new(input: I, kernel: K) -> Self {
+        Self {
+            input,
+            kernel,
+            wide: has_avx2_fma(),
+            pending: None,
+        }
+    }
+}
+
+impl<I, K> Iterator for Batch<I, K>
+where
+    I: Iterator<Item = K::Item>,
+    K: Kernel,
+{
+    type Item = K::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(out) = self.pending.take() {
+            return Some(out);
+        }
+
+        let a = self.input.next()?;
+        if self.wide {
+            if let Some(b) = self.input.next() {
+                let (a, b) = unsafe { self.kernel.apply2(a, b) };
+                self.pending = Some(b);
+                return Some(a);
+            }
+        }
+
+        Some(self.kernel.apply(a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let extra = self.pending.is_some() as usize;
+        let (lo, hi) = self.input.size_hint();
+        (lo + extra, hi.map(|hi| hi + extra))
+    }
+}
+
+// Kernel temporaries are plain registers, so the kernels are trivially
+// copyable and can be shared between the SSE and AVX2 paths.
+#[derive(Clone, Copy)]
+pub struct Sw012 {
+    pub tmp1: f32x4,
+    pub tmp2: f32x4,
+    pub tmp3: f32x4,
+    pub tmp4: Option<f32x4>,
+}
+
+#[derive(Clone, Copy)]
+pub struct Sw312 {
+    pub tmp1: f32x4,
+    pub tmp2: f32x4,
+    pub tmp3: f32x4,
+    pub tmp4: Option<f32x4>,
+}
+
+#[derive(Clone, Copy)]
+pub struct SwMM11 {
+    pub tmp: f32x4,
+    pub tmp2: f32x4,
+    pub tmp3: f32x4,
+}
+
+#[derive(Clone, Copy)]
+pub struct SwMM22 {
+    pub tmp: f32x4,
+    pub tmp2: f32x4,
+    pub tmp3: f32x4,
+    pub translate: Option<(f32x4, f32x4, f32x4)>,
+}
@@ -1,9 +1,12 @@
 #[macro_use]
 pub mod sse;
 
+mod avx2;
+mod batch;
 mod sandwitch;
 
-pub use self::{sandwitch::*, sse::*};
+pub(crate) use self::avx2::gp_mm_fma;
+pub use self::{batch::has_avx2_fma, sandwitch::*, sse::*};
 
 use core::arch::x86_64::*;
 
@@ -107,6 +110,12 @@ impl f32x4 {
         Self::new(-0.0, -0.0, -0.0, 0.0)
     }
 
+    /// Same as `from_array`, but usable in constant expressions.
+    #[inline(always)]
+    pub const fn from_const(data: [f32; 4]) -> Self {
+        Self(unsafe { core::mem::transmute::<[f32; 4], __m128>(data) })
+    }
+
     #[inline(always)]
     pub fn from_array(data: [f32; 4]) -> Self {
         Self(unsafe { _mm_loadu_ps(data.as_ptr()) })
@@ -186,7 +195,7 @@ impl f32x4 {
                 _mm_andnot_ps(_mm_set1_ps(-0.0), _mm_sub_ps(self.0, other.0)),
                 eps,
             );
-            _mm_movemask_ps(cmp) != 0b1111
+            _mm_movemask_ps(cmp) == 0b1111
         }
     }
 
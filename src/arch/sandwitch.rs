@@ -11,7 +11,11 @@
 // 3. For efficiency, the sandwich operator is NOT implemented in terms of two
 //    geometric products and a reversion. The result is nevertheless equivalent.
 
-use super::f32x4;
+use super::{
+    avx2,
+    batch::{Batch, Kernel, Sw012, Sw312, SwMM11, SwMM22},
+    f32x4,
+};
 
 // Partition memory layouts
 //     LSB --> MSB
@@ -257,11 +261,23 @@ pub fn sw_mm11(input: impl Iterator<Item = f32x4>, b: f32x4) -> impl Iterator<It
     //  d3(b0^2 + b3^2 - b2^2 - b1^2)) e03
 
     // Rotation
-    input.map(move |p1| {
+    Batch::new(input, SwMM11 { tmp, tmp2, tmp3 })
+}
+
+impl Kernel for SwMM11 {
+    type Item = f32x4;
+
+    #[inline(always)]
+    fn apply(&self, p1: f32x4) -> f32x4 {
         let p1_xzwy = shuffle!(p1, [1, 3, 2, 0]);
         let p1_xwyz = shuffle!(p1, [2, 1, 3, 0]);
-        tmp * p1 + tmp2 * p1_xzwy + tmp3 * p1_xwyz
-    })
+        self.tmp * p1 + self.tmp2 * p1_xzwy + self.tmp3 * p1_xwyz
+    }
+
+    #[inline(always)]
+    unsafe fn apply2(&self, a: f32x4, b: f32x4) -> (f32x4, f32x4) {
+        avx2::sw_mm11_x2(self, a, b)
+    }
 }
 
 pub fn sw_mm22(
@@ -332,12 +348,10 @@ pub fn sw_mm22(
 
     // Translation
 
-    let translate = c.is_some();
-
-    // tmp4; // scaled by a and added to p2
-    // tmp5; // scaled by (a0, a3, a1, a2), added to p2
-    // tmp6; // scaled by (a0, a2, a3, a1), added to p2
-    let (tmp4, tmp5, tmp6) = if let Some(&c) = c {
+    // tmp4 scaled by a and added to p2
+    // tmp5 scaled by (a0, a3, a1, a2), added to p2
+    // tmp6 scaled by (a0, a2, a3, a1), added to p2
+    let translate = c.map(|&c| {
         let czero = shuffle!(c, [0, 0, 0, 0]);
         let c_xzwy = shuffle!(c, [1, 3, 2, 0]);
         let c_xwyz = shuffle!(c, [2, 1, 3, 0]);
@@ -352,30 +366,52 @@ pub fn sw_mm22(
         let tmp6 = (b * c_xzwy + b_xxxx * c_xwyz + b_xzwy * c - b_xwyz * czero) * scale;
 
         (tmp4, tmp5, tmp6)
-    } else {
-        unsafe { core::mem::uninitialized() }
-    };
+    });
+
+    Batch::new(
+        input,
+        SwMM22 {
+            tmp,
+            tmp2,
+            tmp3,
+            translate,
+        },
+    )
+}
+
+impl Kernel for SwMM22 {
+    type Item = (f32x4, f32x4);
 
-    input.map(move |(p1_in, p2_in)| {
+    #[inline(always)]
+    fn apply(&self, (p1_in, p2_in): (f32x4, f32x4)) -> (f32x4, f32x4) {
         let p1_in_xzwy = shuffle!(p1_in, [1, 3, 2, 0]);
         let p1_in_xwyz = shuffle!(p1_in, [2, 1, 3, 0]);
 
         let p2_in_xzwy = shuffle!(p2_in, [1, 3, 2, 0]);
         let p2_in_xwyz = shuffle!(p2_in, [2, 1, 3, 0]);
 
-        let p1_out = tmp * p1_in + tmp2 * p1_in_xzwy + tmp3 * p1_in_xwyz;
-        let p2_out = tmp * p2_in + tmp2 * p2_in_xzwy + tmp3 * p2_in_xwyz;
+        let p1_out = self.tmp * p1_in + self.tmp2 * p1_in_xzwy + self.tmp3 * p1_in_xwyz;
+        let p2_out = self.tmp * p2_in + self.tmp2 * p2_in_xzwy + self.tmp3 * p2_in_xwyz;
 
         // If what is being applied is a rotor, the non-directional
         // components of the line are left untouched
-        let p2_out = if translate {
+        let p2_out = if let Some((tmp4, tmp5, tmp6)) = self.translate {
             p2_out + tmp4 * p1_in + tmp5 * p1_in_xwyz + tmp6 * p1_in_xzwy
         } else {
             p2_out
         };
 
         (p1_out, p2_out)
-    })
+    }
+
+    #[inline(always)]
+    unsafe fn apply2(
+        &self,
+        a: (f32x4, f32x4),
+        b: (f32x4, f32x4),
+    ) -> ((f32x4, f32x4), (f32x4, f32x4)) {
+        avx2::sw_mm22_x2(self, a, b)
+    }
 }
 
 // Apply a motor to a plane
@@ -450,8 +486,7 @@ pub fn sw012(
     // 2a3(b0 c3 + b1 c2 + b3 c0 - b2 c1)
     // by decomposing into four vectors, factoring out the a components
 
-    let translate = c.is_some();
-    let tmp4 = if let Some(&c) = c {
+    let tmp4 = c.map(|&c| {
         let tmp4 = b_xxxx * c;
         let tmp4 = tmp4 + b_xzwy * shuffle!(c, [2, 1, 3, 0]);
         let tmp4 = tmp4 + b * shuffle!(c, [0, 0, 0, 0]);
@@ -459,23 +494,43 @@ pub fn sw012(
         // NOTE: The high component of tmp4 is meaningless here
         let tmp4 = tmp4 - b_xwyz * shuffle!(c, [1, 3, 2, 0]);
         tmp4 * dc_scale
-    } else {
-        unsafe { core::mem::uninitialized() }
-    };
+    });
 
     // The temporaries (tmp1, tmp2, tmp3, tmp4)
     // strictly only have a dependence on b and c.
 
-    a.map(move |a| {
+    Batch::new(
+        a,
+        Sw012 {
+            tmp1,
+            tmp2,
+            tmp3,
+            tmp4,
+        },
+    )
+}
+
+impl Kernel for Sw012 {
+    type Item = f32x4;
+
+    #[inline(always)]
+    fn apply(&self, a: f32x4) -> f32x4 {
         // Compute the lower block for components e1, e2, and e3
-        let p = tmp1 * shuffle!(a, [1, 3, 2, 0]) + tmp2 * shuffle!(a, [2, 1, 3, 0]) + tmp3 * a;
+        let p = self.tmp1 * shuffle!(a, [1, 3, 2, 0])
+            + self.tmp2 * shuffle!(a, [2, 1, 3, 0])
+            + self.tmp3 * a;
 
-        if translate {
+        if let Some(tmp4) = self.tmp4 {
             p + f32x4::hi_dp(tmp4, a)
         } else {
             p
         }
-    })
+    }
+
+    #[inline(always)]
+    unsafe fn apply2(&self, a: f32x4, b: f32x4) -> (f32x4, f32x4) {
+        avx2::sw012_x2(self, a, b)
+    }
 }
 
 // Apply a motor to a point
@@ -535,8 +590,7 @@ pub fn sw312(
     let tmp3 = tmp3 - (tmp4 ^ f32x4::set0(-0.0));
     // tmp3 needs to be scaled by (a0, a1, a2, a3)
 
-    let translate = c.is_some();
-    let tmp4 = if let Some(&c) = c {
+    let tmp4 = c.map(|&c| {
         let tmp4 = b_xzwy * shuffle!(c, [2, 1, 3, 0]);
         let tmp4 = tmp4 - b_xxxx * c;
         let tmp4 = tmp4 - b_xwyz * shuffle!(c, [1, 3, 2, 0]);
@@ -545,19 +599,39 @@ pub fn sw312(
         // Mask low component and scale other components by 2
         // tmp4 needs to be scaled by (_, a0, a0, a0)
         tmp4 * two
-    } else {
-        unsafe { core::mem::uninitialized() }
-    };
+    });
+
+    Batch::new(
+        a,
+        Sw312 {
+            tmp1,
+            tmp2,
+            tmp3,
+            tmp4,
+        },
+    )
+}
+
+impl Kernel for Sw312 {
+    type Item = f32x4;
 
-    a.map(move |a| {
-        let p = tmp1 * shuffle!(a, [2, 1, 3, 0]) + tmp2 * shuffle!(a, [1, 3, 2, 0]) + tmp3 * a;
+    #[inline(always)]
+    fn apply(&self, a: f32x4) -> f32x4 {
+        let p = self.tmp1 * shuffle!(a, [2, 1, 3, 0])
+            + self.tmp2 * shuffle!(a, [1, 3, 2, 0])
+            + self.tmp3 * a;
 
-        if translate {
+        if let Some(tmp4) = self.tmp4 {
             p + tmp4 * shuffle!(a, [0, 0, 0, 0])
         } else {
             p
         }
-    })
+    }
+
+    #[inline(always)]
+    unsafe fn apply2(&self, a: f32x4, b: f32x4) -> (f32x4, f32x4) {
+        avx2::sw312_x2(self, a, b)
+    }
 }
 
 // Conjugate origin with motor. Unlike other operations the motor MUST be
@@ -570,7 +644,7 @@ pub fn swo12(b: f32x4, c: f32x4) -> f32x4 {
     // 2(b1 c2 - b3 c0 - b0 c3 - b2 c1) e021
 
     let tmp = b * shuffle!(c, [0, 0, 0, 0]);
-    let tmp = tmp - shuffle!(b, [0, 0, 0, 0]) * c;
+    let tmp = tmp + shuffle!(b, [0, 0, 0, 0]) * c;
     let tmp = tmp + shuffle!(b, [2, 1, 3, 0]) * shuffle!(c, [1, 3, 2, 0]);
     let tmp = shuffle!(b, [1, 3, 2, 0]) * shuffle!(c, [2, 1, 3, 0]) - tmp;
     let tmp = tmp * f32x4::new(2.0, 2.0, 2.0, 0.0);
@@ -0,0 +1,308 @@
+//! # Pinhole cameras
+//!
+//! A [`Camera`] is a motor taking the camera frame to the world together
+//! with its [`Intrinsics`]. The camera frame follows the usual computer
+//! vision convention: the camera sits at the origin looking down $`+z`$,
+//! with $`+x`$ pointing right and $`+y`$ pointing down the image. Pixel
+//! coordinates are then
+//!
+//! $$u = f_x x_d + c_x, \qquad v = f_y y_d + c_y$$
+//!
+//! where $`(x_d, y_d)`$ is the normalized image point $`(x/z, y/z)`$ after
+//! the (optional) radial distortion
+//! $`1 + k_1 r^2 + k_2 r^4`$ has been applied.
+//!
+//! ```rust
+//! use klein::camera::{Camera, Intrinsics};
+//! use klein::{Motor, Point};
+//!
+//! let camera = Camera::new(Motor::IDENTITY, Intrinsics::new(500.0, 500.0, 320.0, 240.0));
+//! let [u, v] = camera.project(Point::new(0.2, -0.1, 2.0)).unwrap();
+//! assert!((u - 370.0).abs() < 1e-3 && (v - 215.0).abs() < 1e-3);
+//!
+//! // The pixel ray passes back through the point.
+//! let ray = camera.pixel_ray([u, v]);
+//! let j = ray & Point::new(0.2, -0.1, 2.0);
+//! assert!([j.x(), j.y(), j.z(), j.d()].iter().all(|c| c.abs() < 1e-4));
+//! ```
+//!
+//! A [`Frustum`] bounds the visible volume with six planes in the same
+//! camera frame convention, for culling and clipping.
+
+use crate::{
+    polytope::{side, ConvexPolytope},
+    volume::{Aabb, Sphere},
+    Line, Motor, Plane, Point,
+};
+
+/// Iterations of the fixed point undistortion.
+const UNDISTORT_ITERATIONS: usize = 16;
+
+/// Intrinsic parameters of a pinhole camera, in pixels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Intrinsics {
+    /// Focal lengths along `x` and `y`.
+    pub focal: [f32; 2],
+    /// Principal point.
+    pub principal: [f32; 2],
+    /// Radial distortion coefficients $`(k_1, k_2)`$.
+    pub distortion: Option<[f32; 2]>,
+}
+
+impl Intrinsics {
+    /// Distortion free intrinsics.
+    pub fn new(fx: f32, fy: f32, cx: f32, cy: f32) -> Self {
+        Self {
+            focal: [fx, fy],
+            principal: [cx, cy],
+            distortion: None,
+        }
+    }
+
+    /// Add radial distortion with coefficients `k1` and `k2`.
+    pub fn with_distortion(mut self, k1: f32, k2: f32) -> Self {
+        self.distortion = Some([k1, k2]);
+        self
+    }
+
+    /// Map a normalized image point (on the plane $`z = 1`$) to pixels.
+    pub fn project(&self, [x, y]: [f32; 2]) -> [f32; 2] {
+        let k = self.distortion.map_or(1.0, |k| radial(k, x * x + y * y));
+        let ([fx, fy], [cx, cy]) = (self.focal, self.principal);
+        [fx * x * k + cx, fy * y * k + cy]
+    }
+
+    /// Map a pixel back to the normalized image point, removing the
+    /// distortion.
+    pub fn unproject(&self, [u, v]: [f32; 2]) -> [f32; 2] {
+        let ([fx, fy], [cx, cy]) = (self.focal, self.principal);
+        let (xd, yd) = ((u - cx) / fx, (v - cy) / fy);
+        match self.distortion {
+            None => [xd, yd],
+            Some(k) => {
+                // Invert x_d = x * radial(r^2) by fixed point iteration,
+                // which converges quickly for moderate distortion.
+                let (mut x, mut y) = (xd, yd);
+                for _ in 0..UNDISTORT_ITERATIONS {
+                    let s = radial(k, x * x + y * y).recip();
+                    x = xd * s;
+                    y = yd * s;
+                }
+                [x, y]
+            }
+        }
+    }
+}
+
+fn radial([k1, k2]: [f32; 2], r2: f32) -> f32 {
+    1.0 + r2 * (k1 + r2 * k2)
+}
+
+/// A pinhole camera placed in the world.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Camera {
+    /// Camera to world motor.
+    pub pose: Motor,
+    pub intrinsics: Intrinsics,
+}
+
+impl Camera {
+    pub fn new(pose: Motor, intrinsics: Intrinsics) -> Self {
+        Self { pose, intrinsics }
+    }
+
+    /// Location of the camera center in the world.
+    pub fn center(&self) -> Point {
+        self.pose.conj_origin()
+    }
+
+    /// Pixel coordinates of the world point `p`, or `None` if the point is
+    /// not in front of the camera.
+    pub fn project(&self, p: Point) -> Option<[f32; 2]> {
+        let p = self.pose.reversed().conj_point(p);
+        let (z, w) = (p.z(), p.w());
+        // Compare z / w against zero without dividing.
+        if z * w <= 0.0 {
+            return None;
+        }
+        Some(self.intrinsics.project([p.x() / z, p.y() / z]))
+    }
+
+    /// The world space ray through the camera center and pixel `uv`. The
+    /// line is normalized and oriented away from the camera.
+    pub fn pixel_ray(&self, uv: [f32; 2]) -> Line {
+        (self.center() & self.image_point(uv)).normalized()
+    }
+
+    /// The plane through the camera center containing every ray of the
+    /// image line $`au + bv + c = 0`$ given as `[a, b, c]`. With distortion
+    /// enabled, the image line is taken in undistorted pixel coordinates.
+    ///
+    /// Returns the zero plane if `a` and `b` both vanish.
+    pub fn image_line_plane(&self, [a, b, c]: [f32; 3]) -> Plane {
+        let n2 = a * a + b * b;
+        if n2 == 0.0 {
+            return Plane::ZERO;
+        }
+        // Foot of the perpendicular from the principal point and a second
+        // point further along the line.
+        let [cx, cy] = self.intrinsics.principal;
+        let t = -(a * cx + b * cy + c) / n2;
+        let (u, v) = (cx + a * t, cy + b * t);
+        let p = self.undistorted_point([u, v]);
+        let q = self.undistorted_point([u - b, v + a]);
+        (self.center() & p & q).normalized()
+    }
+
+    /// The view frustum of an image of `size` pixels between the `near` and
+    /// `far` depths. Distortion is ignored.
+    pub fn frustum(&self, [width, height]: [f32; 2], near: f32, far: f32) -> Frustum {
+        let ([fx, fy], [cx, cy]) = (self.intrinsics.focal, self.intrinsics.principal);
+        Frustum::from_camera_planes(
+            self.pose,
+            [
+                Plane::new(-fx, 0.0, -cx, 0.0),
+                Plane::new(fx, 0.0, cx - width, 0.0),
+                Plane::new(0.0, -fy, -cy, 0.0),
+                Plane::new(0.0, fy, cy - height, 0.0),
+            ],
+            near,
+            far,
+        )
+    }
+
+    /// World point on the ray through `uv` at unit depth.
+    fn image_point(&self, uv: [f32; 2]) -> Point {
+        let [x, y] = self.intrinsics.unproject(uv);
+        self.pose.conj_point(Point::new(x, y, 1.0))
+    }
+
+    /// World point at unit depth behind an undistorted pixel.
+    fn undistorted_point(&self, [u, v]: [f32; 2]) -> Point {
+        let ([fx, fy], [cx, cy]) = (self.intrinsics.focal, self.intrinsics.principal);
+        let (x, y) = ((u - cx) / fx, (v - cy) / fy);
+        self.pose.conj_point(Point::new(x, y, 1.0))
+    }
+}
+
+/// A view volume bounded by six outward facing planes, ordered left, right,
+/// top, bottom (along $`-x, +x, -y, +y`$ of the camera frame), near and far.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Frustum {
+    /// Normalized bounding planes in world space.
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Perspective frustum of a camera at `pose` looking down its $`+z`$
+    /// axis, with the vertical field of view `fov_y` in radians and the
+    /// `aspect` ratio of width over height.
+    pub fn perspective(pose: Motor, fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let ty = (0.5 * fov_y).tan();
+        let tx = ty * aspect;
+        Self::from_camera_planes(
+            pose,
+            [
+                Plane::new(-1.0, 0.0, -tx, 0.0),
+                Plane::new(1.0, 0.0, -tx, 0.0),
+                Plane::new(0.0, -1.0, -ty, 0.0),
+                Plane::new(0.0, 1.0, -ty, 0.0),
+            ],
+            near,
+            far,
+        )
+    }
+
+    /// Orthographic box of a camera at `pose` spanning `left..right` along
+    /// $`x`$ and `top..bottom` along $`y`$ (pointing down the image) in the
+    /// camera frame.
+    pub fn orthographic(
+        pose: Motor,
+        [left, right]: [f32; 2],
+        [top, bottom]: [f32; 2],
+        near: f32,
+        far: f32,
+    ) -> Self {
+        Self::from_camera_planes(
+            pose,
+            [
+                Plane::new(-1.0, 0.0, 0.0, left),
+                Plane::new(1.0, 0.0, 0.0, -right),
+                Plane::new(0.0, -1.0, 0.0, top),
+                Plane::new(0.0, 1.0, 0.0, -bottom),
+            ],
+            near,
+            far,
+        )
+    }
+
+    /// Move the camera frame side planes and the depth planes to the world.
+    fn from_camera_planes(pose: Motor, sides: [Plane; 4], near: f32, far: f32) -> Self {
+        let [l, r, t, b] = sides;
+        let local = [
+            l,
+            r,
+            t,
+            b,
+            Plane::new(0.0, 0.0, -1.0, near),
+            Plane::new(0.0, 0.0, 1.0, -far),
+        ];
+        let mut planes = [Plane::ZERO; 6];
+        for (out, p) in planes
+            .iter_mut()
+            .zip(pose.conj_planes(local.iter().map(|p| p.normalized())))
+        {
+            *out = p;
+        }
+        Self { planes }
+    }
+
+    pub fn contains_point(&self, p: Point) -> bool {
+        self.planes.iter().all(|&plane| side(plane, p) <= 0.0)
+    }
+
+    /// Whether the sphere may be visible. Spheres near the corners of the
+    /// frustum may be reported visible while lying just outside.
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.planes
+            .iter()
+            .all(|&plane| side(plane, sphere.center) <= sphere.radius)
+    }
+
+    /// Whether the box may be visible: it is culled only when all of its
+    /// corners lie in front of a single plane.
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.planes.iter().all(|&plane| {
+            // The corner furthest behind the plane.
+            let pick = |n: f32, i: usize| if n > 0.0 { aabb.min[i] } else { aabb.max[i] };
+            let corner = Point::new(pick(plane.x(), 0), pick(plane.y(), 1), pick(plane.z(), 2));
+            side(plane, corner) <= 0.0
+        })
+    }
+
+    /// The frustum as a general convex polytope.
+    pub fn polytope(&self) -> ConvexPolytope {
+        ConvexPolytope {
+            planes: self.planes.to_vec(),
+        }
+    }
+
+    /// Clip a polygon to the frustum, see [`crate::polytope::clip_polygon`].
+    pub fn clip_polygon(&self, polygon: &[Point]) -> Vec<Point> {
+        self.polytope().clip_polygon(polygon)
+    }
+}
+
+impl Motor {
+    /// Conjugates the planes of `frustum` with this motor.
+    pub fn conj_frustum(&self, frustum: &Frustum) -> Frustum {
+        let mut planes = frustum.planes;
+        for (out, p) in planes
+            .iter_mut()
+            .zip(self.conj_planes(frustum.planes.iter().copied()))
+        {
+            *out = p;
+        }
+        Frustum { planes }
+    }
+}
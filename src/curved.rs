@@ -0,0 +1,1098 @@
+//! # Elliptic and hyperbolic geometry
+//!
+//! The Euclidean types bake the degenerate metric $`\mathbf{e}_0^2 = 0`$
+//! into every formula. The types here are generic over a [`Metric`] instead,
+//! with $`\mathbf{e}_0^2 = 1`$ for [`Elliptic`] space (the 3-sphere with
+//! antipodal points identified) and $`\mathbf{e}_0^2 = -1`$ for
+//! [`Hyperbolic`] space (the Klein ball model, points inside the unit ball).
+//! Space has unit curvature radius, so distances are angles.
+//!
+//! Entities use the same coordinates as their Euclidean counterparts: a
+//! [`Plane`] is $`a\mathbf{e}_1 + b\mathbf{e}_2 + c\mathbf{e}_3 +
+//! d\mathbf{e}_0`$, a [`Point`] is $`x\mathbf{e}_{032} +
+//! y\mathbf{e}_{013} + z\mathbf{e}_{021} + w\mathbf{e}_{123}`$ and a
+//! [`Line`] has the same six components. The operators match too: `^` is
+//! the meet, `&` the join, `|` the inner product, `*` the geometric product
+//! (and `/` the product with the inverse) and `!` the dual, and entities of
+//! the same type add, subtract and scale. A [`Motor`] is any proper isometry,
+//! the exponential of a line, and a [`Flector`] is an improper one, such as
+//! a motor times a plane.
+//!
+//! Norms are those of the metric, so normalizing places an elliptic point
+//! on the unit 3-sphere and a hyperbolic point on the unit hyperboloid.
+//!
+//! ```rust
+//! use klein::curved::hyperbolic::{Motor, Point};
+//!
+//! // Boost the origin a hyperbolic distance of 2 along x.
+//! let m = Motor::from_translation(2.0, 1.0, 0.0, 0.0);
+//! let p = m.conj_point(Point::new(0.0, 0.0, 0.0));
+//! assert!((p.x() / p.w() - 2f32.tanh()).abs() < 1e-5);
+//!
+//! let l = m.log();
+//! assert!(l.exp().approx_eq(m, 1e-5));
+//! ```
+
+use crate::algebra;
+use core::{
+    iter::Sum,
+    marker::PhantomData,
+    ops::{
+        Add, AddAssign, BitAnd, BitOr, BitXor, Div, DivAssign, Mul, MulAssign, Neg, Not, Sub,
+        SubAssign,
+    },
+};
+
+/// Coefficients indexed by blade bit mask over e0, e1, e2, e3.
+type Mv = [f32; 16];
+
+/// Magnitudes below this are treated as zero by `exp` and `log`.
+const EPSILON: f32 = 1e-6;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The square of $`\mathbf{e}_0`$, which selects the geometry.
+pub trait Metric: sealed::Sealed + Copy + core::fmt::Debug + PartialEq {
+    const E0_SQUARED: f32;
+}
+
+/// Elliptic space, $`\mathbf{e}_0^2 = 1`$.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Elliptic;
+
+/// Hyperbolic space, $`\mathbf{e}_0^2 = -1`$.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hyperbolic;
+
+impl sealed::Sealed for Elliptic {}
+impl sealed::Sealed for Hyperbolic {}
+
+impl Metric for Elliptic {
+    const E0_SQUARED: f32 = 1.0;
+}
+
+impl Metric for Hyperbolic {
+    const E0_SQUARED: f32 = -1.0;
+}
+
+/// The elliptic entities.
+pub mod elliptic {
+    pub type Plane = super::Plane<super::Elliptic>;
+    pub type Line = super::Line<super::Elliptic>;
+    pub type Point = super::Point<super::Elliptic>;
+    pub type Motor = super::Motor<super::Elliptic>;
+    pub type Flector = super::Flector<super::Elliptic>;
+}
+
+/// The hyperbolic entities.
+pub mod hyperbolic {
+    pub type Plane = super::Plane<super::Hyperbolic>;
+    pub type Line = super::Line<super::Hyperbolic>;
+    pub type Point = super::Point<super::Hyperbolic>;
+    pub type Motor = super::Motor<super::Hyperbolic>;
+    pub type Flector = super::Flector<super::Hyperbolic>;
+}
+
+/// The plane $`ax + by + cz + d = 0`$.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane<M> {
+    pub(crate) e: [f32; 4],
+    metric: PhantomData<M>,
+}
+
+/// A line, with components in the order of the Euclidean [`Line`](crate::Line).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Line<M> {
+    pub(crate) e: [f32; 6],
+    metric: PhantomData<M>,
+}
+
+/// A point $`(x/w, y/w, z/w)`$ in homogeneous coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Point<M> {
+    pub(crate) e: [f32; 4],
+    metric: PhantomData<M>,
+}
+
+/// A proper isometry: a scalar, a line and a pseudoscalar.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Motor<M> {
+    pub(crate) e: [f32; 8],
+    metric: PhantomData<M>,
+}
+
+/// An improper isometry: a plane and a point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Flector<M> {
+    pub(crate) e: [f32; 8],
+    metric: PhantomData<M>,
+}
+
+impl<M: Metric> Plane<M> {
+    /// The plane $`ax + by + cz + d = 0`$.
+    pub fn new(a: f32, b: f32, c: f32, d: f32) -> Self {
+        Self::raw([a, b, c, d])
+    }
+
+    fn raw(e: [f32; 4]) -> Self {
+        Self {
+            e,
+            metric: PhantomData,
+        }
+    }
+
+    pub fn x(self) -> f32 {
+        self.e[0]
+    }
+
+    pub fn y(self) -> f32 {
+        self.e[1]
+    }
+
+    pub fn z(self) -> f32 {
+        self.e[2]
+    }
+
+    pub fn d(self) -> f32 {
+        self.e[3]
+    }
+
+    /// $`\sqrt{|a^2 + b^2 + c^2 + \mathbf{e}_0^2 d^2|}`$
+    pub fn norm(self) -> f32 {
+        norm::<M, _>(self)
+    }
+
+    pub fn normalized(self) -> Self {
+        self * self.norm().recip()
+    }
+
+    pub fn inverse(self) -> Self {
+        self * reverse_square::<M, _>(self).recip()
+    }
+
+    pub fn approx_eq(self, other: Self, epsilon: f32) -> bool {
+        approx_eq(&self.e, &other.e, epsilon)
+    }
+
+    /// Reflect a plane through this one, $`p P p^{-1}`$.
+    pub fn reflect_plane(self, p: Plane<M>) -> Plane<M> {
+        sandwich::<M, _>(self.mv(), p.mv())
+    }
+
+    /// Reflect a line through this plane, $`p \ell p^{-1}`$.
+    pub fn reflect_line(self, l: Line<M>) -> Line<M> {
+        sandwich::<M, _>(self.mv(), l.mv())
+    }
+
+    /// Reflect a point through this plane, $`p P p^{-1}`$.
+    pub fn reflect_point(self, p: Point<M>) -> Point<M> {
+        sandwich::<M, _>(self.mv(), p.mv())
+    }
+}
+
+impl<M: Metric> Line<M> {
+    /// The line with ideal part `a, b, c` ($`\mathbf{e}_{01}`$,
+    /// $`\mathbf{e}_{02}`$, $`\mathbf{e}_{03}`$) and Euclidean part `d, e,
+    /// f` ($`\mathbf{e}_{23}`$, $`\mathbf{e}_{31}`$, $`\mathbf{e}_{12}`$).
+    pub fn new(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Self {
+        Self::raw([a, b, c, d, e, f])
+    }
+
+    fn raw(e: [f32; 6]) -> Self {
+        Self {
+            e,
+            metric: PhantomData,
+        }
+    }
+
+    pub fn e01(self) -> f32 {
+        self.e[0]
+    }
+
+    pub fn e02(self) -> f32 {
+        self.e[1]
+    }
+
+    pub fn e03(self) -> f32 {
+        self.e[2]
+    }
+
+    pub fn e23(self) -> f32 {
+        self.e[3]
+    }
+
+    pub fn e31(self) -> f32 {
+        self.e[4]
+    }
+
+    pub fn e12(self) -> f32 {
+        self.e[5]
+    }
+
+    /// $`\sqrt{|\langle \ell \widetilde{\ell} \rangle_0|}`$
+    pub fn norm(self) -> f32 {
+        norm::<M, _>(self)
+    }
+
+    pub fn normalized(self) -> Self {
+        self * self.norm().recip()
+    }
+
+    pub fn reversed(self) -> Self {
+        -self
+    }
+
+    /// $`\widetilde{\ell} / \langle \ell \widetilde{\ell} \rangle_0`$, exact
+    /// for lines that are the join of two points.
+    pub fn inverse(self) -> Self {
+        self.reversed() * reverse_square::<M, _>(self).recip()
+    }
+
+    pub fn approx_eq(self, other: Self, epsilon: f32) -> bool {
+        approx_eq(&self.e, &other.e, epsilon)
+    }
+
+    /// Exponentiate this bivector into a motor.
+    ///
+    /// A bivector $`B`$ squares to $`z = \langle B^2 \rangle_0 + \langle
+    /// B^2 \rangle_4`$, which commutes with everything even, so
+    /// $`e^B = \cosh\sqrt{z} + \frac{\sinh\sqrt{z}}{\sqrt{z}} B`$ with both
+    /// functions evaluated in the algebra of scalars and pseudoscalars.
+    pub fn exp(self) -> Motor<M> {
+        let b = self.mv();
+        let z = channels::<M>(square::<M>(&b));
+        let f = |z: Complex| {
+            if z.abs() < EPSILON {
+                return (
+                    Complex::real(1.0) + z * 0.5,
+                    Complex::real(1.0) + z * (1.0 / 6.0),
+                );
+            }
+            let w = z.sqrt();
+            (w.cosh(), w.sinh() / w)
+        };
+        let (c0, s0) = f(z[0]);
+        let (c1, s1) = f(z[1]);
+        let c = unchannels::<M>([c0, c1]);
+        let s = unchannels::<M>([s0, s1]);
+        let mut out = scale_even::<M>(s, &b);
+        out[0] += c.0;
+        out[15] += c.1;
+        Motor::from_mv(&out)
+    }
+}
+
+impl<M: Metric> Point<M> {
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self::raw([x, y, z, 1.0])
+    }
+
+    fn raw(e: [f32; 4]) -> Self {
+        Self {
+            e,
+            metric: PhantomData,
+        }
+    }
+
+    pub fn x(self) -> f32 {
+        self.e[0]
+    }
+
+    pub fn y(self) -> f32 {
+        self.e[1]
+    }
+
+    pub fn z(self) -> f32 {
+        self.e[2]
+    }
+
+    pub fn w(self) -> f32 {
+        self.e[3]
+    }
+
+    /// $`\sqrt{|w^2 + \mathbf{e}_0^2 (x^2 + y^2 + z^2)|}`$
+    pub fn norm(self) -> f32 {
+        norm::<M, _>(self)
+    }
+
+    /// Scale to unit norm, keeping the sign of `w`.
+    pub fn normalized(self) -> Self {
+        self * self.norm().recip()
+    }
+
+    pub fn reversed(self) -> Self {
+        -self
+    }
+
+    pub fn inverse(self) -> Self {
+        self.reversed() * reverse_square::<M, _>(self).recip()
+    }
+
+    pub fn approx_eq(self, other: Self, epsilon: f32) -> bool {
+        approx_eq(&self.e, &other.e, epsilon)
+    }
+}
+
+impl<M: Metric> Motor<M> {
+    pub const IDENTITY: Self = Self {
+        e: [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        metric: PhantomData,
+    };
+
+    /// The motor $`a + b\mathbf{e}_{23} + c\mathbf{e}_{31} +
+    /// d\mathbf{e}_{12} + e\mathbf{e}_{01} + f\mathbf{e}_{02} +
+    /// g\mathbf{e}_{03} + h\mathbf{e}_{0123}`$.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32, g: f32, h: f32) -> Self {
+        Self {
+            e: [a, b, c, d, e, f, g, h],
+            metric: PhantomData,
+        }
+    }
+
+    /// Rotation by `ang_rad` about the line through the origin along
+    /// `(x, y, z)`, like [`Rotor::new`](crate::Rotor::new).
+    pub fn from_rotation(ang_rad: f32, x: f32, y: f32, z: f32) -> Self {
+        let scale = -0.5 * ang_rad / (x * x + y * y + z * z).sqrt();
+        Line::new(0.0, 0.0, 0.0, scale * x, scale * y, scale * z).exp()
+    }
+
+    /// Translation of the origin by the distance `delta` along `(x, y, z)`,
+    /// like [`Translator::new`](crate::Translator::new).
+    pub fn from_translation(delta: f32, x: f32, y: f32, z: f32) -> Self {
+        let scale = -0.5 * delta / (x * x + y * y + z * z).sqrt();
+        Line::new(scale * x, scale * y, scale * z, 0.0, 0.0, 0.0).exp()
+    }
+
+    pub fn scalar(self) -> f32 {
+        self.e[0]
+    }
+
+    pub fn e23(self) -> f32 {
+        self.e[1]
+    }
+
+    pub fn e31(self) -> f32 {
+        self.e[2]
+    }
+
+    pub fn e12(self) -> f32 {
+        self.e[3]
+    }
+
+    pub fn e01(self) -> f32 {
+        self.e[4]
+    }
+
+    pub fn e02(self) -> f32 {
+        self.e[5]
+    }
+
+    pub fn e03(self) -> f32 {
+        self.e[6]
+    }
+
+    pub fn e0123(self) -> f32 {
+        self.e[7]
+    }
+
+    /// $`\sqrt{|\langle M \widetilde{M} \rangle_0|}`$
+    pub fn norm(self) -> f32 {
+        norm::<M, _>(self)
+    }
+
+    pub fn normalized(self) -> Self {
+        self * self.norm().recip()
+    }
+
+    pub fn reversed(self) -> Self {
+        let [a, b, c, d, e, f, g, h] = self.e;
+        Self::new(a, -b, -c, -d, -e, -f, -g, h)
+    }
+
+    /// $`\widetilde{M} / \langle M \widetilde{M} \rangle_0`$, exact for
+    /// motors that are products of reflections.
+    pub fn inverse(self) -> Self {
+        self.reversed() * reverse_square::<M, _>(self).recip()
+    }
+
+    pub fn approx_eq(self, other: Self, epsilon: f32) -> bool {
+        approx_eq(&self.e, &other.e, epsilon)
+    }
+
+    /// The line whose exponential is this motor, up to the sign of the
+    /// motor. Half turns have no unique logarithm.
+    pub fn log(self) -> Line<M> {
+        let m = self.normalized().mv();
+        let mut y = m;
+        y[0] = 0.0;
+        y[15] = 0.0;
+        // For M = C + Y with C = cosh(u) and Y = sinh(u) / u B, the scale
+        // u / sinh(u) only depends on C and Y^2 = C^2 - 1.
+        let c = channels::<M>((m[0], m[15]));
+        let y2 = channels::<M>(square::<M>(&y));
+        let g = if M::E0_SQUARED > 0.0 {
+            // Real channels: take the angle from both its cosine and sine so
+            // that neither channel flips the sign of the motor.
+            let g = |c: f32, y2: f32| {
+                if y2.abs() < EPSILON {
+                    c.recip()
+                } else if y2 < 0.0 {
+                    let sin = (-y2).sqrt();
+                    sin.atan2(c) / sin
+                } else {
+                    let sinh = y2.sqrt();
+                    (sinh / c).atanh() / sinh
+                }
+            };
+            unchannels::<M>([
+                Complex::real(g(c[0].re, y2[0].re)),
+                Complex::real(g(c[1].re, y2[1].re)),
+            ])
+        } else {
+            let s = y2[0].sqrt();
+            let g = if s.abs() < EPSILON {
+                c[0].recip()
+            } else {
+                (s / c[0]).atanh() / s
+            };
+            unchannels::<M>([g, g])
+        };
+        Line::from_mv(&scale_even::<M>(g, &y))
+    }
+
+    /// The motor halfway between the identity and this one, up to sign.
+    pub fn sqrt(self) -> Self {
+        (self.log() * 0.5).exp()
+    }
+
+    pub fn conj_plane(&self, p: Plane<M>) -> Plane<M> {
+        sandwich::<M, _>(self.mv(), p.mv())
+    }
+
+    pub fn conj_planes<'a>(
+        &'a self,
+        input: impl Iterator<Item = Plane<M>> + 'a,
+    ) -> impl Iterator<Item = Plane<M>> + 'a {
+        input.map(move |p| self.conj_plane(p))
+    }
+
+    pub fn conj_line(&self, l: Line<M>) -> Line<M> {
+        sandwich::<M, _>(self.mv(), l.mv())
+    }
+
+    pub fn conj_lines<'a>(
+        &'a self,
+        input: impl Iterator<Item = Line<M>> + 'a,
+    ) -> impl Iterator<Item = Line<M>> + 'a {
+        input.map(move |l| self.conj_line(l))
+    }
+
+    pub fn conj_point(&self, p: Point<M>) -> Point<M> {
+        sandwich::<M, _>(self.mv(), p.mv())
+    }
+
+    pub fn conj_points<'a>(
+        &'a self,
+        input: impl Iterator<Item = Point<M>> + 'a,
+    ) -> impl Iterator<Item = Point<M>> + 'a {
+        input.map(move |p| self.conj_point(p))
+    }
+}
+
+impl<M: Metric> Flector<M> {
+    /// The sum of a plane and a point.
+    pub fn new(plane: Plane<M>, point: Point<M>) -> Self {
+        let ([a, b, c, d], [x, y, z, w]) = (plane.e, point.e);
+        Self {
+            e: [a, b, c, d, x, y, z, w],
+            metric: PhantomData,
+        }
+    }
+
+    /// The plane (vector) part.
+    pub fn plane(self) -> Plane<M> {
+        let [a, b, c, d, ..] = self.e;
+        Plane::new(a, b, c, d)
+    }
+
+    /// The point (trivector) part.
+    pub fn point(self) -> Point<M> {
+        let [.., x, y, z, w] = self.e;
+        Point::raw([x, y, z, w])
+    }
+
+    /// $`\sqrt{|\langle F \widetilde{F} \rangle_0|}`$
+    pub fn norm(self) -> f32 {
+        norm::<M, _>(self)
+    }
+
+    pub fn normalized(self) -> Self {
+        self * self.norm().recip()
+    }
+
+    pub fn reversed(self) -> Self {
+        Self::new(self.plane(), -self.point())
+    }
+
+    /// $`\widetilde{F} / \langle F \widetilde{F} \rangle_0`$, exact for
+    /// flectors that are products of reflections.
+    pub fn inverse(self) -> Self {
+        self.reversed() * reverse_square::<M, _>(self).recip()
+    }
+
+    pub fn approx_eq(self, other: Self, epsilon: f32) -> bool {
+        approx_eq(&self.e, &other.e, epsilon)
+    }
+
+    pub fn conj_plane(&self, p: Plane<M>) -> Plane<M> {
+        sandwich::<M, _>(self.mv(), p.mv())
+    }
+
+    pub fn conj_planes<'a>(
+        &'a self,
+        input: impl Iterator<Item = Plane<M>> + 'a,
+    ) -> impl Iterator<Item = Plane<M>> + 'a {
+        input.map(move |p| self.conj_plane(p))
+    }
+
+    pub fn conj_line(&self, l: Line<M>) -> Line<M> {
+        sandwich::<M, _>(self.mv(), l.mv())
+    }
+
+    pub fn conj_lines<'a>(
+        &'a self,
+        input: impl Iterator<Item = Line<M>> + 'a,
+    ) -> impl Iterator<Item = Line<M>> + 'a {
+        input.map(move |l| self.conj_line(l))
+    }
+
+    pub fn conj_point(&self, p: Point<M>) -> Point<M> {
+        sandwich::<M, _>(self.mv(), p.mv())
+    }
+
+    pub fn conj_points<'a>(
+        &'a self,
+        input: impl Iterator<Item = Point<M>> + 'a,
+    ) -> impl Iterator<Item = Point<M>> + 'a {
+        input.map(move |p| self.conj_point(p))
+    }
+}
+
+impl<M: Metric> From<Plane<M>> for Flector<M> {
+    fn from(p: Plane<M>) -> Self {
+        Self::new(p, Point::raw([0.0; 4]))
+    }
+}
+
+impl<M: Metric> From<Point<M>> for Flector<M> {
+    fn from(p: Point<M>) -> Self {
+        Self::new(Plane::new(0.0, 0.0, 0.0, 0.0), p)
+    }
+}
+
+/// Conversion to and from dense multivectors. Blades stored against the
+/// canonical order (e31, e032, e021) flip sign.
+trait Blades: Copy {
+    fn mv(self) -> Mv;
+    fn from_mv(mv: &Mv) -> Self;
+}
+
+impl Blades for f32 {
+    fn mv(self) -> Mv {
+        let mut mv = [0.0; 16];
+        mv[0] = self;
+        mv
+    }
+
+    fn from_mv(mv: &Mv) -> Self {
+        mv[0]
+    }
+}
+
+impl<M: Metric> Blades for Plane<M> {
+    fn mv(self) -> Mv {
+        let [a, b, c, d] = self.e;
+        let mut mv = [0.0; 16];
+        mv[0b0001] = d;
+        mv[0b0010] = a;
+        mv[0b0100] = b;
+        mv[0b1000] = c;
+        mv
+    }
+
+    fn from_mv(mv: &Mv) -> Self {
+        Self::raw([mv[0b0010], mv[0b0100], mv[0b1000], mv[0b0001]])
+    }
+}
+
+impl<M: Metric> Blades for Line<M> {
+    fn mv(self) -> Mv {
+        let [e01, e02, e03, e23, e31, e12] = self.e;
+        let mut mv = [0.0; 16];
+        mv[0b0011] = e01;
+        mv[0b0101] = e02;
+        mv[0b1001] = e03;
+        mv[0b1100] = e23;
+        mv[0b1010] = -e31;
+        mv[0b0110] = e12;
+        mv
+    }
+
+    fn from_mv(mv: &Mv) -> Self {
+        Self::raw([
+            mv[0b0011],
+            mv[0b0101],
+            mv[0b1001],
+            mv[0b1100],
+            -mv[0b1010],
+            mv[0b0110],
+        ])
+    }
+}
+
+impl<M: Metric> Blades for Point<M> {
+    fn mv(self) -> Mv {
+        let [x, y, z, w] = self.e;
+        let mut mv = [0.0; 16];
+        mv[0b1101] = -x;
+        mv[0b1011] = y;
+        mv[0b0111] = -z;
+        mv[0b1110] = w;
+        mv
+    }
+
+    fn from_mv(mv: &Mv) -> Self {
+        Self::raw([-mv[0b1101], mv[0b1011], -mv[0b0111], mv[0b1110]])
+    }
+}
+
+impl<M: Metric> Blades for Motor<M> {
+    fn mv(self) -> Mv {
+        let [a, b, c, d, e, f, g, h] = self.e;
+        let mut mv = Line::<M>::new(e, f, g, b, c, d).mv();
+        mv[0] = a;
+        mv[0b1111] = h;
+        mv
+    }
+
+    fn from_mv(mv: &Mv) -> Self {
+        let l = Line::<M>::from_mv(mv);
+        let [e01, e02, e03, e23, e31, e12] = l.e;
+        Self::new(mv[0], e23, e31, e12, e01, e02, e03, mv[0b1111])
+    }
+}
+
+impl<M: Metric> Blades for Flector<M> {
+    fn mv(self) -> Mv {
+        let mut mv = self.plane().mv();
+        for (a, b) in mv.iter_mut().zip(self.point().mv().iter()) {
+            *a += b;
+        }
+        mv
+    }
+
+    fn from_mv(mv: &Mv) -> Self {
+        Self::new(Plane::from_mv(mv), Point::from_mv(mv))
+    }
+}
+
+fn metric<M: Metric>() -> [f32; 4] {
+    [M::E0_SQUARED, 1.0, 1.0, 1.0]
+}
+
+fn gp<M: Metric>(a: &Mv, b: &Mv, out: &mut Mv) {
+    algebra::gp(a, b, &metric::<M>(), out)
+}
+
+fn inner<M: Metric>(a: &Mv, b: &Mv, out: &mut Mv) {
+    algebra::inner(a, b, &metric::<M>(), out)
+}
+
+/// $`\langle X \widetilde{X} \rangle_0`$
+fn reverse_square<M: Metric, T: Blades>(x: T) -> f32 {
+    let a = x.mv();
+    let mut reversed = a;
+    algebra::reverse(&mut reversed);
+    let mut out = [0.0; 16];
+    gp::<M>(&a, &reversed, &mut out);
+    out[0]
+}
+
+fn norm<M: Metric, T: Blades>(x: T) -> f32 {
+    reverse_square::<M, _>(x).abs().sqrt()
+}
+
+/// $`V X V^{-1}`$, with the inverse taken as $`\widetilde{V} / \langle V
+/// \widetilde{V} \rangle_0`$.
+fn sandwich<M: Metric, T: Blades>(v: Mv, x: Mv) -> T {
+    let mut reversed = v;
+    algebra::reverse(&mut reversed);
+    let (mut vx, mut out, mut vv) = ([0.0; 16], [0.0; 16], [0.0; 16]);
+    gp::<M>(&v, &x, &mut vx);
+    gp::<M>(&vx, &reversed, &mut out);
+    gp::<M>(&v, &reversed, &mut vv);
+    let scale = vv[0].recip();
+    for c in out.iter_mut() {
+        *c *= scale;
+    }
+    T::from_mv(&out)
+}
+
+/// The scalar and pseudoscalar parts of the square of an even element.
+fn square<M: Metric>(x: &Mv) -> (f32, f32) {
+    let mut out = [0.0; 16];
+    gp::<M>(x, x, &mut out);
+    (out[0], out[15])
+}
+
+/// The product of `x` with `a + b I`, where the pseudoscalar `I` commutes
+/// with even elements.
+fn scale_even<M: Metric>((a, b): (f32, f32), x: &Mv) -> Mv {
+    let mut pseudoscalar = [0.0; 16];
+    pseudoscalar[15] = b;
+    let mut out = [0.0; 16];
+    gp::<M>(&pseudoscalar, x, &mut out);
+    for (o, &c) in out.iter_mut().zip(x.iter()) {
+        *o += a * c;
+    }
+    out
+}
+
+/// Decompose `a + b I` in the algebra spanned by the scalar and the
+/// pseudoscalar, where functions act independently on each channel. With
+/// $`I^2 = 1`$ (elliptic) the channels are the real coefficients along the
+/// idempotents $`(1 \pm I) / 2`$, with $`I^2 = -1`$ (hyperbolic) the algebra
+/// is the complex numbers and only the first channel is used.
+fn channels<M: Metric>((a, b): (f32, f32)) -> [Complex; 2] {
+    if M::E0_SQUARED > 0.0 {
+        [Complex::real(a + b), Complex::real(a - b)]
+    } else {
+        [Complex::new(a, b), Complex::new(a, -b)]
+    }
+}
+
+fn unchannels<M: Metric>([c0, c1]: [Complex; 2]) -> (f32, f32) {
+    if M::E0_SQUARED > 0.0 {
+        (0.5 * (c0.re + c1.re), 0.5 * (c0.re - c1.re))
+    } else {
+        (c0.re, c0.im)
+    }
+}
+
+fn approx_eq(a: &[f32], b: &[f32], epsilon: f32) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() < epsilon)
+}
+
+macro_rules! binary {
+    ($op:ident, $fn:ident, $product:expr, $($lhs:ident, $rhs:ident => $out:ty;)+) => {
+        $(
+            impl<M: Metric> $op<$rhs<M>> for $lhs<M> {
+                type Output = $out;
+
+                #[inline]
+                fn $fn(self, other: $rhs<M>) -> $out {
+                    let mut out = [0.0; 16];
+                    $product(&self.mv(), &other.mv(), &mut out);
+                    <$out>::from_mv(&out)
+                }
+            }
+        )+
+    };
+}
+
+binary! { BitXor, bitxor, algebra::outer,
+    Plane, Plane => Line<M>;
+    Plane, Line => Point<M>;
+    Line, Plane => Point<M>;
+}
+
+binary! { BitAnd, bitand, algebra::regressive,
+    Point, Point => Line<M>;
+    Line, Point => Plane<M>;
+    Point, Line => Plane<M>;
+}
+
+binary! { BitOr, bitor, inner::<M>,
+    Plane, Plane => f32;
+    Line, Line => f32;
+    Point, Point => f32;
+    Plane, Line => Plane<M>;
+    Line, Plane => Plane<M>;
+    Plane, Point => Line<M>;
+    Point, Plane => Line<M>;
+    Line, Point => Plane<M>;
+    Point, Line => Plane<M>;
+}
+
+/// The geometric product `*`, and `/` as the product with the inverse.
+macro_rules! gp {
+    ($($lhs:ident, $rhs:ident => $out:ident;)+) => {
+        binary! { Mul, mul, gp::<M>, $($lhs, $rhs => $out<M>;)+ }
+
+        $(
+            impl<M: Metric> Div<$rhs<M>> for $lhs<M> {
+                type Output = $out<M>;
+
+                #[inline]
+                #[allow(clippy::suspicious_arithmetic_impl)]
+                fn div(self, other: $rhs<M>) -> $out<M> {
+                    self * other.inverse()
+                }
+            }
+        )+
+    };
+}
+
+gp! {
+    Plane, Plane => Motor;
+    Plane, Point => Motor;
+    Point, Plane => Motor;
+    Line, Line => Motor;
+    Point, Point => Motor;
+    Motor, Motor => Motor;
+    Motor, Line => Motor;
+    Line, Motor => Motor;
+    Motor, Plane => Flector;
+    Plane, Motor => Flector;
+    Motor, Point => Flector;
+    Point, Motor => Flector;
+    Motor, Flector => Flector;
+    Flector, Motor => Flector;
+    Flector, Flector => Motor;
+    Flector, Plane => Motor;
+    Plane, Flector => Motor;
+    Flector, Point => Motor;
+    Point, Flector => Motor;
+}
+
+/// The dual swaps each blade with its complement, as `!` does for the
+/// Euclidean types.
+impl<M: Metric> Not for Plane<M> {
+    type Output = Point<M>;
+
+    #[inline]
+    fn not(self) -> Point<M> {
+        Point::raw(self.e)
+    }
+}
+
+impl<M: Metric> Not for Point<M> {
+    type Output = Plane<M>;
+
+    #[inline]
+    fn not(self) -> Plane<M> {
+        Plane::raw(self.e)
+    }
+}
+
+impl<M: Metric> Not for Line<M> {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        let [a, b, c, d, e, f] = self.e;
+        Self::raw([d, e, f, a, b, c])
+    }
+}
+
+macro_rules! vector {
+    ($($ty:ident)+) => {
+        $(
+            impl<M: Metric> Add for $ty<M> {
+                type Output = Self;
+
+                #[inline]
+                fn add(mut self, other: Self) -> Self {
+                    self += other;
+                    self
+                }
+            }
+
+            impl<M: Metric> Sub for $ty<M> {
+                type Output = Self;
+
+                #[inline]
+                fn sub(mut self, other: Self) -> Self {
+                    self -= other;
+                    self
+                }
+            }
+
+            impl<M: Metric> Mul<f32> for $ty<M> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(mut self, s: f32) -> Self {
+                    self *= s;
+                    self
+                }
+            }
+
+            impl<M: Metric> Div<f32> for $ty<M> {
+                type Output = Self;
+
+                #[inline]
+                #[allow(clippy::suspicious_arithmetic_impl)]
+                fn div(self, s: f32) -> Self {
+                    self * s.recip()
+                }
+            }
+
+            impl<M: Metric> Neg for $ty<M> {
+                type Output = Self;
+
+                #[inline]
+                fn neg(self) -> Self {
+                    self * -1.0
+                }
+            }
+
+            impl<M: Metric> AddAssign for $ty<M> {
+                #[inline]
+                fn add_assign(&mut self, other: Self) {
+                    for (a, b) in self.e.iter_mut().zip(other.e.iter()) {
+                        *a += b;
+                    }
+                }
+            }
+
+            impl<M: Metric> SubAssign for $ty<M> {
+                #[inline]
+                fn sub_assign(&mut self, other: Self) {
+                    for (a, b) in self.e.iter_mut().zip(other.e.iter()) {
+                        *a -= b;
+                    }
+                }
+            }
+
+            impl<M: Metric> MulAssign<f32> for $ty<M> {
+                #[inline]
+                fn mul_assign(&mut self, s: f32) {
+                    for c in self.e.iter_mut() {
+                        *c *= s;
+                    }
+                }
+            }
+
+            impl<M: Metric> DivAssign<f32> for $ty<M> {
+                #[inline]
+                #[allow(clippy::suspicious_op_assign_impl)]
+                fn div_assign(&mut self, s: f32) {
+                    *self *= s.recip();
+                }
+            }
+
+            /// Component-wise sum, starting from all components zero.
+            impl<M: Metric> Sum for $ty<M> {
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    let zero = Self {
+                        e: Default::default(),
+                        metric: PhantomData,
+                    };
+                    iter.fold(zero, |acc, x| acc + x)
+                }
+            }
+        )+
+    };
+}
+
+vector! { Plane Line Point Motor Flector }
+
+/// Just enough complex arithmetic for `exp` and `log` in hyperbolic space.
+#[derive(Clone, Copy, Debug)]
+struct Complex {
+    re: f32,
+    im: f32,
+}
+
+impl Complex {
+    fn new(re: f32, im: f32) -> Self {
+        Self { re, im }
+    }
+
+    fn real(re: f32) -> Self {
+        Self { re, im: 0.0 }
+    }
+
+    fn abs(self) -> f32 {
+        self.re.hypot(self.im)
+    }
+
+    fn recip(self) -> Self {
+        let n = self.re * self.re + self.im * self.im;
+        Self::new(self.re / n, -self.im / n)
+    }
+
+    /// Principal square root.
+    fn sqrt(self) -> Self {
+        let r = self.abs();
+        let re = (0.5 * (r + self.re)).sqrt();
+        let im = (0.5 * (r - self.re)).sqrt();
+        Self::new(re, if self.im < 0.0 { -im } else { im })
+    }
+
+    fn exp(self) -> Self {
+        let (sin, cos) = self.im.sin_cos();
+        let r = self.re.exp();
+        Self::new(r * cos, r * sin)
+    }
+
+    fn ln(self) -> Self {
+        Self::new(self.abs().ln(), self.im.atan2(self.re))
+    }
+
+    fn cosh(self) -> Self {
+        (self.exp() + (self * -1.0).exp()) * 0.5
+    }
+
+    fn sinh(self) -> Self {
+        (self.exp() + (self * -1.0).exp() * -1.0) * 0.5
+    }
+
+    fn atanh(self) -> Self {
+        let one = Self::real(1.0);
+        ((one + self) / (one + self * -1.0)).ln() * 0.5
+    }
+}
+
+impl core::ops::Add for Complex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl Mul<f32> for Complex {
+    type Output = Self;
+
+    fn mul(self, s: f32) -> Self {
+        Self::new(self.re * s, self.im * s)
+    }
+}
+
+impl core::ops::Div for Complex {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let n = other.re * other.re + other.im * other.im;
+        Self::new(
+            (self.re * other.re + self.im * other.im) / n,
+            (self.im * other.re - self.re * other.im) / n,
+        )
+    }
+}
@@ -3,12 +3,19 @@ use crate::arch::f32x4;
 /// Directions in $`\mathbf{P}(\mathbb{R}^3_{3, 0, 1})`$ are represented using
 /// points at infinity (homogeneous coordinate 0). Having a homogeneous
 /// coordinate of zero ensures that directions are translation-invariant.
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Direction {
     pub(crate) p3: f32x4,
 }
 
 impl Direction {
+    /// The zero direction. Unlike directions built with `new`, it is not
+    /// normalized.
+    pub const ZERO: Self = Self {
+        p3: f32x4::from_const([0.0; 4]),
+    };
+
     /// Create a normalized direction
     pub fn new(x: f32, y: f32, z: f32) -> Self {
         Self::from(f32x4::new(z, y, x, 0.0)).normalized()
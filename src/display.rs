@@ -0,0 +1,391 @@
+//! Blade expression formatting and parsing.
+//!
+//! Every entity implements `Display` by printing itself as a sum of blades,
+//! e.g. `1 + 0.5e23 - 2e01` for a motor. Zero terms are omitted and unit
+//! coefficients are elided. The formatting precision is forwarded to the
+//! coefficients, so `format!("{:.2}", m)` works as expected.
+//!
+//! The same syntax is accepted by `FromStr`:
+//!
+//! ```
+//! use klein::Point;
+//!
+//! let p: Point = "2e032 + 3e013 + e123".parse().unwrap();
+//! assert_eq!((p.x(), p.y(), p.z(), p.w()), (2.0, 3.0, 0.0, 1.0));
+//! ```
+//!
+//! Blades may be written with their indices in any order (`e13` is read as
+//! `-e31`), coefficients may optionally be followed by `*`, and repeated
+//! blades are summed. Coefficients do not support exponent notation since
+//! `e` always begins a blade. Blades the target type cannot hold (such as a
+//! bivector when parsing a [`Point`](crate::Point)) are rejected.
+
+use crate::{
+    arch::f32x4, Branch, Direction, Dual, Flector, IdealLine, Line, Motor, Plane, Point, Rotor,
+    Translator,
+};
+use core::{fmt, str::FromStr};
+
+/// Error returned when parsing a blade expression fails.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseError {
+    /// The input contains no terms.
+    Empty,
+    /// The input is malformed at the given byte offset.
+    Syntax(usize),
+    /// The blade does not exist in the algebra (e.g. `e4` or `e11`).
+    UnknownBlade(String),
+    /// The blade exists but cannot be represented by the target type
+    /// (e.g. `e12` when parsing a point).
+    UnexpectedBlade(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => f.write_str("empty blade expression"),
+            ParseError::Syntax(at) => write!(f, "invalid blade expression at offset {}", at),
+            ParseError::UnknownBlade(blade) => write!(f, "unknown blade `{}`", blade),
+            ParseError::UnexpectedBlade(blade) => {
+                write!(f, "blade `{}` is not part of this type", blade)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Maximum number of blades in any single entity (the motor).
+const MAX_BLADES: usize = 8;
+
+// Writes `terms` as a sum of blades. The scalar blade is named "1".
+fn write_expr(f: &mut fmt::Formatter<'_>, terms: &[(&str, f32)], latex: bool) -> fmt::Result {
+    let mut first = true;
+    for &(blade, value) in terms {
+        if value == 0.0 {
+            continue;
+        }
+
+        let negative = value.is_sign_negative();
+        match (first, negative) {
+            (true, false) => {}
+            (true, true) => f.write_str("-")?,
+            (false, false) => f.write_str(" + ")?,
+            (false, true) => f.write_str(" - ")?,
+        }
+        first = false;
+
+        let value = value.abs();
+        let scalar = blade == "1";
+        if scalar || value != 1.0 {
+            match f.precision() {
+                Some(precision) => write!(f, "{:.*}", precision, value)?,
+                None => write!(f, "{}", value)?,
+            }
+        }
+
+        if !scalar {
+            if latex {
+                write!(f, "\\mathbf{{e}}_{{{}}}", &blade[1..])?;
+            } else {
+                f.write_str(blade)?;
+            }
+        }
+    }
+
+    if first {
+        f.write_str("0")?;
+    }
+    Ok(())
+}
+
+// Parses `s` into coefficients for `blades`, in the same order.
+fn parse_expr(s: &str, blades: &[&str]) -> Result<[f32; MAX_BLADES], ParseError> {
+    let mut out = [0.0; MAX_BLADES];
+    let mut parser = Parser { s, pos: 0 };
+
+    parser.skip_ws();
+    if parser.is_done() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut first = true;
+    while !parser.is_done() {
+        // A leading term may omit its sign.
+        let sign = match parser.peek() {
+            Some(b'+') => Some(1.0),
+            Some(b'-') => Some(-1.0),
+            _ if first => None,
+            _ => return Err(ParseError::Syntax(parser.pos)),
+        };
+        if sign.is_some() {
+            parser.pos += 1;
+            parser.skip_ws();
+        }
+        let sign = sign.unwrap_or(1.0);
+        first = false;
+
+        let (value, blade) = parser.term()?;
+        let (index, blade_sign) = find_blade(blade, blades)?;
+        out[index] += sign * blade_sign * value;
+        parser.skip_ws();
+    }
+
+    Ok(out)
+}
+
+// Finds the position of `blade` (given by its indices, empty for the scalar)
+// in `blades`, along with the sign picked up by reordering its indices.
+fn find_blade(blade: &str, blades: &[&str]) -> Result<(usize, f32), ParseError> {
+    if blade.is_empty() {
+        return blades
+            .iter()
+            .position(|name| *name == "1")
+            .map(|index| (index, 1.0))
+            .ok_or_else(|| ParseError::UnexpectedBlade("1".into()));
+    }
+
+    let name = || format!("e{}", blade);
+    let mut seen = [false; 4];
+    for digit in blade.bytes() {
+        let i = (digit - b'0') as usize;
+        if i > 3 || seen[i] {
+            return Err(ParseError::UnknownBlade(name()));
+        }
+        seen[i] = true;
+    }
+
+    for (index, candidate) in blades.iter().enumerate() {
+        let candidate = &candidate[1..];
+        if candidate.len() == blade.len() && blade.bytes().all(|b| candidate.contains(b as char)) {
+            let sign = if parity(blade) == parity(candidate) {
+                1.0
+            } else {
+                -1.0
+            };
+            return Ok((index, sign));
+        }
+    }
+
+    Err(ParseError::UnexpectedBlade(name()))
+}
+
+// Parity of the number of inversions in a sequence of blade indices.
+fn parity(indices: &str) -> bool {
+    let indices = indices.as_bytes();
+    let mut odd = false;
+    for i in 0..indices.len() {
+        for j in i + 1..indices.len() {
+            odd ^= indices[i] > indices[j];
+        }
+    }
+    odd
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn is_done(&self) -> bool {
+        self.pos >= self.s.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.s.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn take_while(&mut self, pred: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if pred(c)) {
+            self.pos += 1;
+        }
+        &self.s[start..self.pos]
+    }
+
+    // A single term: an optional coefficient followed by an optional blade.
+    // Returns the coefficient and the blade indices (empty for the scalar).
+    fn term(&mut self) -> Result<(f32, &'a str), ParseError> {
+        let start = self.pos;
+        let number = self.take_while(|c| c.is_ascii_digit() || c == b'.');
+        let value = if number.is_empty() {
+            1.0
+        } else {
+            number.parse().map_err(|_| ParseError::Syntax(start))?
+        };
+
+        self.skip_ws();
+        if !number.is_empty() && self.peek() == Some(b'*') {
+            self.pos += 1;
+            self.skip_ws();
+            if self.peek() != Some(b'e') {
+                return Err(ParseError::Syntax(self.pos));
+            }
+        }
+
+        if self.peek() != Some(b'e') {
+            return if number.is_empty() {
+                Err(ParseError::Syntax(start))
+            } else {
+                Ok((value, ""))
+            };
+        }
+
+        self.pos += 1;
+        let at = self.pos;
+        let blade = self.take_while(|c| c.is_ascii_digit());
+        if blade.is_empty() {
+            return Err(ParseError::Syntax(at));
+        }
+        Ok((value, blade))
+    }
+}
+
+/// Wrapper returned by the `latex` methods that formats its entity as a
+/// LaTeX blade expression, e.g. `1 + 0.5\mathbf{e}_{23}`.
+struct Latex<T>(T);
+
+macro_rules! impl_display {
+    ($ty:ident [$($part:ident),+] { $($blade:literal: $src:ident[$lane:literal]),+ }) => {
+        impl $ty {
+            fn write_blades(&self, f: &mut fmt::Formatter<'_>, latex: bool) -> fmt::Result {
+                $( let $part = self.$part.into_array(); )+
+                write_expr(f, &[$(($blade, $src[$lane])),+], latex)
+            }
+
+            /// Format this entity as a LaTeX blade expression.
+            pub fn latex(self) -> impl fmt::Display {
+                Latex(self)
+            }
+        }
+
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.write_blades(f, false)
+            }
+        }
+
+        impl fmt::Display for Latex<$ty> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.write_blades(f, true)
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, ParseError> {
+                let coefficients = parse_expr(s, &[$($blade),+])?;
+                let mut coefficients = coefficients.iter();
+                $( let mut $part = [0.0; 4]; )+
+                $( $src[$lane] = *coefficients.next().unwrap(); )+
+                Ok(Self { $( $part: f32x4::from_array($part) ),+ })
+            }
+        }
+    };
+}
+
+// Partition memory layouts
+//     LSB --> MSB
+// p0: (e0, e1, e2, e3)
+// p1: (1, e23, e31, e12)
+// p2: (e0123, e01, e02, e03)
+// p3: (e123, e032, e013, e021)
+
+impl_display!(Plane [p0] { "e1": p0[1], "e2": p0[2], "e3": p0[3], "e0": p0[0] });
+impl_display!(Point [p3] { "e032": p3[1], "e013": p3[2], "e021": p3[3], "e123": p3[0] });
+impl_display!(Direction [p3] { "e032": p3[1], "e013": p3[2], "e021": p3[3] });
+impl_display!(Branch [p1] { "e23": p1[1], "e31": p1[2], "e12": p1[3] });
+impl_display!(IdealLine [p2] { "e01": p2[1], "e02": p2[2], "e03": p2[3] });
+impl_display!(Line [p1, p2] {
+    "e01": p2[1], "e02": p2[2], "e03": p2[3],
+    "e23": p1[1], "e31": p1[2], "e12": p1[3]
+});
+impl_display!(Rotor [p1] { "1": p1[0], "e23": p1[1], "e31": p1[2], "e12": p1[3] });
+impl_display!(Motor [p1, p2] {
+    "1": p1[0], "e23": p1[1], "e31": p1[2], "e12": p1[3],
+    "e01": p2[1], "e02": p2[2], "e03": p2[3], "e0123": p2[0]
+});
+impl_display!(Flector [p0, p3] {
+    "e1": p0[1], "e2": p0[2], "e3": p0[3], "e0": p0[0],
+    "e032": p3[1], "e013": p3[2], "e021": p3[3], "e123": p3[0]
+});
+
+// The scalar part of a translator is not stored and always equals one.
+
+impl Translator {
+    fn write_blades(&self, f: &mut fmt::Formatter<'_>, latex: bool) -> fmt::Result {
+        let p2 = self.p2.into_array();
+        let terms = [("1", 1.0), ("e01", p2[1]), ("e02", p2[2]), ("e03", p2[3])];
+        write_expr(f, &terms, latex)
+    }
+
+    /// Format this translator as a LaTeX blade expression.
+    pub fn latex(self) -> impl fmt::Display {
+        Latex(self)
+    }
+}
+
+impl fmt::Display for Translator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_blades(f, false)
+    }
+}
+
+impl fmt::Display for Latex<Translator> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_blades(f, true)
+    }
+}
+
+/// The scalar term may be omitted, but must equal one if present.
+impl FromStr for Translator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let c = parse_expr(s, &["1", "e01", "e02", "e03"])?;
+        if c[0] != 0.0 && c[0] != 1.0 {
+            return Err(ParseError::UnexpectedBlade("1".into()));
+        }
+        Ok(Self::from(f32x4::new(c[3], c[2], c[1], 0.0)))
+    }
+}
+
+impl Dual {
+    fn write_blades(&self, f: &mut fmt::Formatter<'_>, latex: bool) -> fmt::Result {
+        write_expr(f, &[("1", self.p), ("e0123", self.q)], latex)
+    }
+
+    /// Format this dual number as a LaTeX blade expression.
+    pub fn latex(self) -> impl fmt::Display {
+        Latex(self)
+    }
+}
+
+impl fmt::Display for Dual {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_blades(f, false)
+    }
+}
+
+impl fmt::Display for Latex<Dual> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_blades(f, true)
+    }
+}
+
+impl FromStr for Dual {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let c = parse_expr(s, &["1", "e0123"])?;
+        Ok(Self::new(c[0], c[1]))
+    }
+}
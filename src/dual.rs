@@ -1,11 +1,15 @@
 /// A dual number is a multivector of the form $`p + q\mathbf{e}_{0123}`$.
-#[derive(Clone, Copy)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct Dual {
     pub(crate) p: f32,
     pub(crate) q: f32,
 }
 
 impl Dual {
+    /// The zero dual number.
+    pub const ZERO: Self = Self { p: 0.0, q: 0.0 };
+
     #[inline]
     pub fn new(p: f32, q: f32) -> Self {
         Self { p, q }
@@ -76,3 +80,37 @@ impl core::ops::Neg for Dual {
         }
     }
 }
+
+impl core::ops::AddAssign for Dual {
+    fn add_assign(&mut self, rhs: Self) {
+        self.p += rhs.p;
+        self.q += rhs.q;
+    }
+}
+
+impl core::ops::SubAssign for Dual {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.p -= rhs.p;
+        self.q -= rhs.q;
+    }
+}
+
+impl core::ops::MulAssign<f32> for Dual {
+    fn mul_assign(&mut self, s: f32) {
+        self.p *= s;
+        self.q *= s;
+    }
+}
+
+impl core::ops::DivAssign<f32> for Dual {
+    fn div_assign(&mut self, s: f32) {
+        self.p /= s;
+        self.q /= s;
+    }
+}
+
+impl core::iter::Sum for Dual {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
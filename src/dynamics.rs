@@ -0,0 +1,204 @@
+//! # Rigid body dynamics
+//!
+//! The state of a rigid body is a motor $`M`$ taking the body frame (centered
+//! at the center of mass and aligned with the principal axes) to the world,
+//! together with its velocity $`B`$ in the body frame. The velocity is a line
+//! (a *rate*): its Euclidean part $`\omega_1\mathbf{e}_{23} +\
+//! \omega_2\mathbf{e}_{31} + \omega_3\mathbf{e}_{12}`$ holds the angular
+//! velocity and its ideal part $`v_1\mathbf{e}_{01} + v_2\mathbf{e}_{02} +\
+//! v_3\mathbf{e}_{03}`$ the linear velocity.
+//!
+//! Forces and torques combine into a single line as well (a *forque*): a
+//! force is a Euclidean line along which it acts, a pure torque is an ideal
+//! line. The equations of motion are then
+//!
+//! $$\dot{M} = -\frac{1}{2} M B, \qquad\
+//! \dot{B} = A^{-1}\left[F - A[B] \times B\right]$$
+//!
+//! where $`A`$ is the [`Inertia`] map taking rates to momenta, $`F`$ is the
+//! body frame forque and $`\times`$ is the [commutator
+//! product](crate::Line::commutator).
+//!
+//! ```rust
+//! use klein::dynamics::{Inertia, Integrator, RigidBody};
+//! use klein::{Line, Motor};
+//!
+//! let mut body = RigidBody::new(Motor::IDENTITY, Inertia::cuboid(2.0, [1.0, 1.0, 1.0]));
+//! body.gravity = Line::new(0.0, -9.81, 0.0, 0.0, 0.0, 0.0);
+//! for _ in 0..100 {
+//!     body.step(0.01, Line::ZERO, Integrator::RungeKutta4);
+//! }
+//! assert!((body.motor.conj_origin().y() + 4.905).abs() < 1e-2);
+//! ```
+
+use crate::{arch::f32x4, Line, Motor, Point};
+
+/// The inertia map of a rigid body about its center of mass, in the frame of
+/// its principal axes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Inertia {
+    pub mass: f32,
+    /// Principal moments of inertia about the `x`, `y` and `z` axes.
+    pub moments: [f32; 3],
+}
+
+impl Inertia {
+    pub fn new(mass: f32, moments: [f32; 3]) -> Self {
+        Self { mass, moments }
+    }
+
+    /// Solid sphere of the given `radius`.
+    pub fn sphere(mass: f32, radius: f32) -> Self {
+        let i = 0.4 * mass * radius * radius;
+        Self::new(mass, [i, i, i])
+    }
+
+    /// Solid box with the given edge lengths along `x`, `y` and `z`.
+    pub fn cuboid(mass: f32, [x, y, z]: [f32; 3]) -> Self {
+        let k = mass / 12.0;
+        Self::new(
+            mass,
+            [
+                k * (y * y + z * z),
+                k * (x * x + z * z),
+                k * (x * x + y * y),
+            ],
+        )
+    }
+
+    /// Map a body frame rate to the body frame momentum. The Euclidean and
+    /// ideal parts swap places: linear velocity becomes the (Euclidean)
+    /// linear momentum line and angular velocity the (ideal) angular
+    /// momentum.
+    pub fn apply(&self, rate: Line) -> Line {
+        let [i1, i2, i3] = self.moments;
+        Line {
+            p1: rate.p2 * self.mass,
+            p2: rate.p1 * f32x4::new(i3, i2, i1, 0.0),
+        }
+    }
+
+    /// The inverse map, taking a momentum (or forque) to a rate (or rate of
+    /// change of the rate).
+    pub fn apply_inverse(&self, momentum: Line) -> Line {
+        let [i1, i2, i3] = self.moments;
+        Line {
+            p1: momentum.p2 * f32x4::new(i3.recip(), i2.recip(), i1.recip(), 0.0),
+            p2: momentum.p1 * self.mass.recip(),
+        }
+    }
+}
+
+/// Numerical integration scheme used by [`RigidBody::step`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Integrator {
+    /// Semi-implicit Euler: update the rate first, then advance the motor
+    /// exactly along the new rate with [`Line::exp`]. Cheap, and the energy
+    /// stays bounded over long runs.
+    SymplecticEuler,
+    /// Classic fourth order Runge-Kutta on the motor and rate.
+    RungeKutta4,
+}
+
+/// A rigid body with its state and mass properties.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RigidBody {
+    /// Body to world motor.
+    pub motor: Motor,
+    /// Velocity in the body frame.
+    pub rate: Line,
+    pub inertia: Inertia,
+    /// Gravitational acceleration in the world frame, as an ideal line (for
+    /// example $`-9.81\mathbf{e}_{02}`$ pulls along $`-y`$).
+    pub gravity: Line,
+}
+
+impl RigidBody {
+    /// A body at rest without gravity.
+    pub fn new(motor: Motor, inertia: Inertia) -> Self {
+        Self {
+            motor,
+            rate: Line::ZERO,
+            inertia,
+            gravity: Line::ZERO,
+        }
+    }
+
+    /// Momentum in the body frame.
+    pub fn momentum(&self) -> Line {
+        self.inertia.apply(self.rate)
+    }
+
+    /// Momentum in the world frame. Conserved in the absence of forques.
+    pub fn world_momentum(&self) -> Line {
+        self.motor.conj_line(self.momentum())
+    }
+
+    /// Velocity in the world frame.
+    pub fn world_rate(&self) -> Line {
+        self.motor.conj_line(self.rate)
+    }
+
+    pub fn kinetic_energy(&self) -> f32 {
+        // B and A[B] pair Euclidean with ideal components.
+        let p = self.momentum();
+        let e = f32x4::hi_dp(self.rate.p1, p.p2) + f32x4::hi_dp(self.rate.p2, p.p1);
+        0.5 * e.extract0()
+    }
+
+    /// Time derivatives of the motor and rate for the given state under the
+    /// world frame forque `forque` and gravity.
+    pub fn derivative(&self, motor: Motor, rate: Line, forque: Line) -> (Motor, Line) {
+        let to_body = motor.reversed();
+
+        // Gravity acts along a line through the center of mass, which is the
+        // body frame origin.
+        let gravity = !to_body.conj_line(self.gravity) * self.inertia.mass;
+        let forque = to_body.conj_line(forque) + gravity;
+
+        let momentum = self.inertia.apply(rate);
+        let d_rate = self
+            .inertia
+            .apply_inverse(forque - momentum.commutator(rate));
+        let d_motor = motor * Motor::from((rate.p1, rate.p2)) * -0.5;
+        (d_motor, d_rate)
+    }
+
+    /// Advance the state by `dt` under the world frame forque `forque`
+    /// (held constant over the step), re-normalizing the motor.
+    pub fn step(&mut self, dt: f32, forque: Line, integrator: Integrator) {
+        match integrator {
+            Integrator::SymplecticEuler => {
+                let (_, d_rate) = self.derivative(self.motor, self.rate, forque);
+                self.rate += d_rate * dt;
+                self.motor *= (self.rate * (-0.5 * dt)).exp();
+            }
+            Integrator::RungeKutta4 => {
+                let (m, b) = (self.motor, self.rate);
+                let (m1, b1) = self.derivative(m, b, forque);
+                let (m2, b2) = self.derivative(m + m1 * (0.5 * dt), b + b1 * (0.5 * dt), forque);
+                let (m3, b3) = self.derivative(m + m2 * (0.5 * dt), b + b2 * (0.5 * dt), forque);
+                let (m4, b4) = self.derivative(m + m3 * dt, b + b3 * dt, forque);
+
+                let k = dt / 6.0;
+                self.motor = m + (m1 + m2 * 2.0 + m3 * 2.0 + m4) * k;
+                self.rate = b + (b1 + b2 * 2.0 + b3 * 2.0 + b4) * k;
+            }
+        }
+        self.motor.normalize();
+    }
+}
+
+/// The forque of a force with components `(x, y, z)` applied at `point`: the
+/// line through `point` along the force, scaled by its magnitude.
+pub fn force(point: Point, x: f32, y: f32, z: f32) -> Line {
+    let w = point.w();
+    let p = Point::new(point.x() / w, point.y() / w, point.z() / w);
+    p & Point::new(p.x() + x, p.y() + y, p.z() + z)
+}
+
+/// The forque of a pure torque with components `(x, y, z)`, positive in the
+/// sense of [`Rotor::new`](crate::Rotor::new).
+pub fn torque(x: f32, y: f32, z: f32) -> Line {
+    Line::new(x, y, z, 0.0, 0.0, 0.0)
+}
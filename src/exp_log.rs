@@ -107,6 +107,9 @@ impl Motor {
 
 // Provide routines for taking bivector/motor exponentials and logarithms.
 
+// Squared rotation angles below this are treated as zero by `exp` and `log`.
+const SMALL_ANGLE2: f32 = 1e-12;
+
 // Partition memory layouts
 //     LSB --> MSB
 // p0: (e0, e1, e2, e3)
@@ -140,6 +143,14 @@ pub fn exp(a: f32x4, b: f32x4) -> (f32x4, f32x4) {
     // Broadcast dot(a, a) ignoring the scalar component to all components of a2
 
     let a2 = f32x4::hi_dp_bc(a, a);
+
+    // Without a (noticeable) rotational part the bivector is ideal and the
+    // exponential is a translator. Higher order terms vanish to working
+    // precision, and the general path below would divide by zero.
+    if a2.extract0() < SMALL_ANGLE2 {
+        return (f32x4::set0(1.0) + a, b);
+    }
+
     let ab = f32x4::hi_dp_bc(a, b);
 
     // Next, we need the sqrt of that quantity. Since e0123 squares to 0,
@@ -225,7 +236,14 @@ pub fn log(p1: f32x4, p2: f32x4) -> (f32x4, f32x4) {
 
     // Next, we need to compute the norm as in the exponential.
     let a2 = f32x4::hi_dp_bc(a, a);
-    // TODO: handle case when a2 is 0
+
+    // A translator (or a motor with a vanishing rotation): the logarithm is
+    // the bivector part divided by the scalar, matching `exp` above.
+    if a2.extract0() < SMALL_ANGLE2 * p1.extract0() * p1.extract0() {
+        let inv = f32x4::all(p1.extract0().recip());
+        return (a * inv, b * inv);
+    }
+
     let ab = f32x4::hi_dp_bc(a, b);
     let a2_sqrt_rcp = a2.rsqrt_nr1();
     let s_scalar = (a2 * a2_sqrt_rcp).extract0();
@@ -0,0 +1,185 @@
+// Total least squares fitting of planes and lines to points, and the point
+// nearest a set of lines.
+//
+// Planes and lines are fitted through the centroid of the points along the
+// principal axes of their covariance. Residuals are the root mean square of
+// the Euclidean distances to the fitted entity.
+
+use crate::{
+    linalg::{self, point, Vec3},
+    Line, Plane, Point,
+};
+
+impl Plane {
+    /// The normalized plane minimizing the sum of squared distances to
+    /// `points`, and the root mean square distance. The sign of the normal is
+    /// arbitrary.
+    ///
+    /// Returns `None` for fewer than three points or collinear points.
+    ///
+    /// ```rust
+    /// use klein::{Plane, Point};
+    ///
+    /// let points = [
+    ///     Point::new(0.0, 0.0, 1.0),
+    ///     Point::new(1.0, 0.0, 1.0),
+    ///     Point::new(0.0, 1.0, 1.0),
+    ///     Point::new(1.0, 1.0, 1.0),
+    /// ];
+    /// let (plane, residual) = Plane::fit(&points).unwrap();
+    /// assert!(residual < 1e-6);
+    /// assert!((plane.z().abs() - 1.0).abs() < 1e-6);
+    /// assert!((plane.d() + plane.z()).abs() < 1e-6);
+    /// ```
+    pub fn fit(points: &[Point]) -> Option<(Self, f32)> {
+        if points.len() < 3 {
+            return None;
+        }
+        let (c, values, axes) = principal_axes(points);
+        if values[1] <= 1e-12 * values[0].max(1e-30) {
+            return None;
+        }
+
+        let n = axes[2];
+        let plane = Plane::new(
+            n[0] as f32,
+            n[1] as f32,
+            n[2] as f32,
+            -linalg::dot(n, c) as f32,
+        );
+        Some((plane, rms(values[2], points.len())))
+    }
+}
+
+impl Line {
+    /// The normalized line minimizing the sum of squared distances to
+    /// `points`, and the root mean square distance. The line is oriented
+    /// from the first point towards the last.
+    ///
+    /// Returns `None` for fewer than two points or coincident points.
+    pub fn fit(points: &[Point]) -> Option<(Self, f32)> {
+        if points.len() < 2 {
+            return None;
+        }
+        let (c, values, axes) = principal_axes(points);
+        if values[0] <= 1e-12 {
+            return None;
+        }
+
+        let first = linalg::vec3(points[0]);
+        let last = linalg::vec3(points[points.len() - 1]);
+        let d = axes[0];
+        let d = if linalg::dot(d, linalg::sub(last, first)) < 0.0 {
+            linalg::scale(d, -1.0)
+        } else {
+            d
+        };
+
+        let line = point(c) & point(linalg::add(c, d));
+        Some((line.normalized(), rms(values[1] + values[2], points.len())))
+    }
+}
+
+impl Point {
+    /// The point minimizing the sum of squared distances to `lines`, and the
+    /// root mean square distance. Lines at infinity are ignored.
+    ///
+    /// Returns `None` for fewer than two finite lines or when the lines are
+    /// all parallel.
+    ///
+    /// ```rust
+    /// use klein::{Line, Point};
+    ///
+    /// let target = Point::new(1.0, 2.0, 3.0);
+    /// let lines = [
+    ///     Point::new(0.0, 0.0, 0.0) & target,
+    ///     Point::new(4.0, 0.0, 1.0) & target,
+    ///     Point::new(0.0, -3.0, 5.0) & target,
+    /// ];
+    /// let (p, residual) = Point::closest_to_lines(&lines).unwrap();
+    /// assert!(residual < 1e-4);
+    /// assert!((p.x() - 1.0).abs() < 1e-4 && (p.y() - 2.0).abs() < 1e-4);
+    /// ```
+    pub fn closest_to_lines(lines: &[Line]) -> Option<(Self, f32)> {
+        // Each line contributes (I - d d^T) (x - p) = 0 for its unit direction
+        // d and any point p on it.
+        let lines: Vec<(Vec3, Vec3)> = lines
+            .iter()
+            .filter_map(|&l| direction_and_point(l))
+            .collect();
+        if lines.len() < 2 {
+            return None;
+        }
+
+        let mut a = [[0.0; 6]; 6];
+        let mut b = [0.0; 6];
+        for &(d, p) in &lines {
+            let projected = reject(p, d);
+            for i in 0..3 {
+                for j in 0..3 {
+                    let identity = if i == j { 1.0 } else { 0.0 };
+                    a[i][j] += identity - d[i] * d[j];
+                }
+                b[i] += projected[i];
+            }
+        }
+        let x = linalg::solve(a, b, 3)?;
+        let x = [x[0], x[1], x[2]];
+
+        let sum: f64 = lines
+            .iter()
+            .map(|&(d, p)| {
+                let e = reject(linalg::sub(x, p), d);
+                linalg::dot(e, e)
+            })
+            .sum();
+        Some((point(x), rms(sum, lines.len())))
+    }
+}
+
+/// Centroid of `points`, eigenvalues of their scatter matrix in descending
+/// order and the matching unit eigenvectors.
+fn principal_axes(points: &[Point]) -> (Vec3, [f64; 3], [Vec3; 3]) {
+    let xs: Vec<Vec3> = points.iter().map(|&p| linalg::vec3(p)).collect();
+    let c = xs.iter().fold([0.0; 3], |c, &x| linalg::add(c, x));
+    let c = linalg::scale(c, 1.0 / xs.len() as f64);
+
+    let mut scatter = [[0.0; 4]; 4];
+    for &x in &xs {
+        let d = linalg::sub(x, c);
+        for i in 0..3 {
+            for j in 0..3 {
+                scatter[i][j] += d[i] * d[j];
+            }
+        }
+    }
+
+    let (values, vectors) = linalg::sym_eigen(scatter, 3);
+    let axis = |k: usize| [vectors[0][k], vectors[1][k], vectors[2][k]];
+    (
+        c,
+        [values[0].max(0.0), values[1].max(0.0), values[2].max(0.0)],
+        [axis(0), axis(1), axis(2)],
+    )
+}
+
+/// Unit direction of a finite line and its point closest to the origin.
+fn direction_and_point(l: Line) -> Option<(Vec3, Vec3)> {
+    let (x, y, z) = (l.e23(), l.e31(), l.e12());
+    let d = [f64::from(x), f64::from(y), f64::from(z)];
+    let length = linalg::norm(d);
+    if length <= 1e-12 {
+        return None;
+    }
+    let p = Plane::new(x, y, z, 0.0) ^ l;
+    Some((linalg::scale(d, 1.0 / length), linalg::vec3(p)))
+}
+
+/// The component of `v` orthogonal to the unit vector `d`.
+fn reject(v: Vec3, d: Vec3) -> Vec3 {
+    linalg::sub(v, linalg::scale(d, linalg::dot(v, d)))
+}
+
+fn rms(sum: f64, count: usize) -> f32 {
+    (sum / count as f64).sqrt() as f32
+}
@@ -0,0 +1,236 @@
+//! # Flectors
+//!
+//! A flector is an odd versor, the sum of a plane and a point
+//! $`a\mathbf{e}_1 + b\mathbf{e}_2 + c\mathbf{e}_3 + d\mathbf{e}_0 +
+//! x\mathbf{e}_{032} + y\mathbf{e}_{013} + z\mathbf{e}_{021} +
+//! w\mathbf{e}_{123}`$. Where motors hold the proper (orientation preserving)
+//! isometries, flectors hold the improper ones: reflections in planes,
+//! inversions in points, glide reflections and rotoreflections.
+//!
+//! Flectors compose with motors, planes and points with `*`. The product of a
+//! flector with a motor is a flector and the product of two flectors is a
+//! motor.
+//!
+//! ```rust
+//! use klein::{Flector, Motor, Plane, Point, Translator};
+//!
+//! // Mirror in the plane x = 0, then move one unit along y: a glide
+//! // reflection.
+//! let t = Translator::new(1.0, 0.0, 1.0, 0.0);
+//! let f: Flector = Motor::from_translator(t) * Plane::new(1.0, 0.0, 0.0, 0.0);
+//!
+//! let p = f.conj_point(Point::new(2.0, 3.0, 4.0));
+//! assert!((p.x() + 2.0).abs() < 1e-6);
+//! assert!((p.y() - 4.0).abs() < 1e-6);
+//! ```
+
+use crate::{arch::f32x4, Branch, Direction, IdealLine, Line, Mat4x4, Motor, Plane, Point};
+
+/// # Memory layout
+///
+/// Two 16-byte aligned registers (32 bytes in total), lowest address first:
+///
+/// - `p0`: $`(\mathbf{e}_0, \mathbf{e}_1, \mathbf{e}_2, \mathbf{e}_3)`$
+/// - `p3`: $`(\mathbf{e}_{123}, \mathbf{e}_{032}, \mathbf{e}_{013},\
+///   \mathbf{e}_{021})`$
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Flector {
+    pub(crate) p0: f32x4,
+    pub(crate) p3: f32x4,
+}
+
+/// The plane $`\mathbf{e}_1`$ ($`x = 0`$). Every flector factors as this
+/// plane times a motor, which lets sandwiches reuse the motor routines.
+const MIRROR: Plane = Plane {
+    p0: f32x4::from_const([0.0, 1.0, 0.0, 0.0]),
+};
+
+impl Flector {
+    /// The sum of a plane and a point.
+    pub fn new(plane: Plane, point: Point) -> Self {
+        Self {
+            p0: plane.p0,
+            p3: point.p3,
+        }
+    }
+
+    /// The plane (vector) part.
+    pub fn plane(self) -> Plane {
+        Plane { p0: self.p0 }
+    }
+
+    /// The point (trivector) part.
+    pub fn point(self) -> Point {
+        Point { p3: self.p3 }
+    }
+
+    /// Normalizes this flector $f$ such that $f\widetilde{f} = 1$.
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    pub fn normalized(self) -> Self {
+        MIRROR * self.motor().normalized()
+    }
+
+    /// Reverse, negating the point part.
+    pub fn reverse(&mut self) {
+        self.p3 ^= f32x4::all(-0.0);
+    }
+
+    pub fn reversed(mut self) -> Self {
+        self.reverse();
+        self
+    }
+
+    pub fn invert(&mut self) {
+        *self = self.inverse();
+    }
+
+    pub fn inverse(self) -> Self {
+        self.motor().inverse() * MIRROR
+    }
+
+    pub fn flector_eq(self, other: Self) -> bool {
+        f32x4::bit_eq_pair(self.into(), other.into())
+    }
+
+    pub fn approx_eq(self, other: Self, epsilon: f32) -> bool {
+        f32x4::approx_eq_pair(self.into(), other.into(), epsilon)
+    }
+
+    /// Convert this flector to a 4x4 column-major matrix representing its
+    /// action as a linear transformation. The matrix has determinant $-1$
+    /// for normalized flectors.
+    pub fn as_mat4x4(self) -> Mat4x4 {
+        let mut cols = self.motor().as_mat4x4().to_cols();
+        for col in cols.iter_mut() {
+            col[0] = -col[0];
+        }
+        Mat4x4::from_cols(cols)
+    }
+
+    /// Conjugates a plane $p$ with this flector and returns the result
+    /// $fp\widetilde{f}$.
+    pub fn conj_plane(&self, p: Plane) -> Plane {
+        MIRROR.reflect_plane(self.motor().conj_plane(p))
+    }
+
+    /// Conjugates an array of planes with this flector.
+    pub fn conj_planes(&self, input: impl Iterator<Item = Plane>) -> impl Iterator<Item = Plane> {
+        let m = self.motor();
+        input.map(move |p| MIRROR.reflect_plane(m.conj_plane(p)))
+    }
+
+    /// Conjugates a line $\ell$ with this flector and returns the result
+    /// $f\ell\widetilde{f}$.
+    pub fn conj_line(&self, l: Line) -> Line {
+        MIRROR.reflect_line(self.motor().conj_line(l))
+    }
+
+    /// Conjugates an array of lines with this flector.
+    pub fn conj_lines(&self, input: impl Iterator<Item = Line>) -> impl Iterator<Item = Line> {
+        let m = self.motor();
+        input.map(move |l| MIRROR.reflect_line(m.conj_line(l)))
+    }
+
+    /// Conjugates a branch $b$ with this flector and returns the result
+    /// $`fb\widetilde{f}`$.
+    ///
+    /// The translational part of the flector moves the branch off the
+    /// origin, so the result is a general line.
+    pub fn conj_branch(&self, b: Branch) -> Line {
+        MIRROR.reflect_line(self.motor().conj_branch(b))
+    }
+
+    /// Conjugates an array of branches with this flector. The results are
+    /// general lines (see `conj_branch`).
+    pub fn conj_branches(&self, input: impl Iterator<Item = Branch>) -> impl Iterator<Item = Line> {
+        let m = self.motor();
+        input.map(move |b| MIRROR.reflect_line(m.conj_branch(b)))
+    }
+
+    /// Conjugates an ideal line $\ell$ with this flector and returns the
+    /// result $`f\ell\widetilde{f}`$.
+    pub fn conj_ideal_line(&self, l: IdealLine) -> IdealLine {
+        reflect_ideal_line(self.motor().conj_ideal_line(l))
+    }
+
+    /// Conjugates an array of ideal lines with this flector.
+    pub fn conj_ideal_lines(
+        &self,
+        input: impl Iterator<Item = IdealLine>,
+    ) -> impl Iterator<Item = IdealLine> {
+        let m = self.motor();
+        input.map(move |l| reflect_ideal_line(m.conj_ideal_line(l)))
+    }
+
+    /// Conjugates a point $P$ with this flector and returns the result
+    /// $fP\widetilde{f}$.
+    pub fn conj_point(&self, p: Point) -> Point {
+        MIRROR.reflect_point(self.motor().conj_point(p))
+    }
+
+    /// Conjugates an array of points with this flector.
+    pub fn conj_points(&self, input: impl Iterator<Item = Point>) -> impl Iterator<Item = Point> {
+        let m = self.motor();
+        input.map(move |p| MIRROR.reflect_point(m.conj_point(p)))
+    }
+
+    /// Conjugates the origin $`O`$ with this flector and returns the result
+    /// $`fO\widetilde{f}`$.
+    pub fn conj_origin(&self) -> Point {
+        MIRROR.reflect_point(self.motor().conj_origin())
+    }
+
+    /// Conjugates a direction $d$ with this flector, ignoring its
+    /// translational part.
+    pub fn conj_dir(&self, d: Direction) -> Direction {
+        reflect_dir(self.motor().conj_dir(d))
+    }
+
+    /// Conjugates an array of directions with this flector.
+    pub fn conj_dirs(
+        &self,
+        input: impl Iterator<Item = Direction>,
+    ) -> impl Iterator<Item = Direction> {
+        let m = self.motor();
+        input.map(move |d| reflect_dir(m.conj_dir(d)))
+    }
+
+    /// The motor $`m`$ with $`f = \mathbf{e}_1 m`$.
+    fn motor(self) -> Motor {
+        MIRROR * self
+    }
+}
+
+fn reflect_ideal_line(l: IdealLine) -> IdealLine {
+    IdealLine {
+        p2: crate::arch::sw20(MIRROR.p0, l.p2),
+    }
+}
+
+fn reflect_dir(d: Direction) -> Direction {
+    Direction {
+        p3: crate::arch::sw30(MIRROR.p0, d.p3),
+    }
+}
+
+impl From<Plane> for Flector {
+    fn from(p: Plane) -> Self {
+        Self {
+            p0: p.p0,
+            p3: f32x4::zero(),
+        }
+    }
+}
+
+impl From<Point> for Flector {
+    fn from(p: Point) -> Self {
+        Self {
+            p0: f32x4::zero(),
+            p3: p.p3,
+        }
+    }
+}
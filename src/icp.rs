@@ -0,0 +1,243 @@
+//! # Iterative closest point
+//!
+//! Align a `source` point cloud to a `target` cloud without known
+//! correspondences. Every iteration moves the source with the current motor
+//! (using the batch [`Motor::conj_points`] kernel), pairs each moved point
+//! with its nearest target point, solves for the incremental motor best
+//! aligning the pairs and composes it onto the current motor with `*`.
+//!
+//! Two error metrics are available:
+//!
+//! - [`Metric::PointToPoint`] minimizes the distance between paired points
+//!   and solves each step exactly with [`register_points`].
+//! - [`Metric::PointToPlane`] minimizes the distance to the tangent planes of
+//!   the target, given its normals. Each step solves the problem linearized
+//!   about the current motor as a twist and exponentiates it. It converges
+//!   in far fewer iterations on smooth surfaces.
+//!
+//! ```rust
+//! use klein::icp::{icp, Metric, Settings};
+//! use klein::{Motor, Point, Translator};
+//!
+//! let target: Vec<Point> = (0..64)
+//!     .map(|i| {
+//!         let (x, y) = ((i % 8) as f32 * 0.25, (i / 8) as f32 * 0.25);
+//!         Point::new(x, y, 0.2 * x * x - 0.1 * y)
+//!     })
+//!     .collect();
+//! let offset = Translator::new(0.05, 1.0, 0.5, 0.0);
+//! let source: Vec<Point> = offset.conj_points(target.iter().copied()).collect();
+//!
+//! let solution = icp(&source, &target, Metric::PointToPoint, Motor::IDENTITY, &Settings::default());
+//! assert!(solution.residual < 1e-3);
+//! ```
+//!
+//! [`register_points`]: crate::registration::register_points
+
+use crate::{
+    linalg::{self, xyz},
+    registration::register_points,
+    Direction, Line, Motor, Point,
+};
+
+/// Error metric minimized by [`icp`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Metric<'a> {
+    PointToPoint,
+    /// Point to plane distance, with one unit normal per target point.
+    PointToPlane(&'a [Direction]),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Settings {
+    pub max_iterations: usize,
+    /// Change of the residual between iterations below which the alignment
+    /// counts as converged.
+    pub tolerance: f32,
+    /// Pairs further apart than this are rejected as outliers.
+    pub max_distance: f32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_iterations: 32,
+            tolerance: 1e-6,
+            max_distance: f32::INFINITY,
+        }
+    }
+}
+
+/// Outcome of an alignment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Solution {
+    /// Motor taking the source cloud onto the target.
+    pub motor: Motor,
+    /// Number of iterations performed.
+    pub iterations: usize,
+    /// Root mean square of the metric over the final pairs.
+    pub residual: f32,
+    /// Number of pairs within `max_distance` in the final iteration.
+    pub pairs: usize,
+    pub converged: bool,
+}
+
+/// Align `source` to `target`, starting from the motor `initial`.
+///
+/// Nearest neighbours are found by brute force, so each iteration is
+/// quadratic in the cloud size.
+///
+/// # Panics
+///
+/// Panics if point to plane normals are not given for every target point.
+pub fn icp(
+    source: &[Point],
+    target: &[Point],
+    metric: Metric,
+    initial: Motor,
+    settings: &Settings,
+) -> Solution {
+    if let Metric::PointToPlane(normals) = metric {
+        assert_eq!(normals.len(), target.len(), "one normal per target point");
+    }
+    let target: Vec<[f32; 3]> = target.iter().map(|&p| xyz(p)).collect();
+
+    let mut motor = initial.normalized();
+    let (mut pairs, mut residual) = evaluate(source, &target, metric, motor, settings);
+    let mut iterations = 0;
+    let mut converged = false;
+
+    while iterations < settings.max_iterations && !pairs.is_empty() {
+        iterations += 1;
+        let step = match step(&pairs, &target, metric) {
+            Some(step) => step,
+            None => break,
+        };
+        motor = (step * motor).normalized();
+
+        let (next, error) = evaluate(source, &target, metric, motor, settings);
+        let change = (residual - error).abs();
+        pairs = next;
+        residual = error;
+        if change <= settings.tolerance {
+            converged = true;
+            break;
+        }
+    }
+
+    Solution {
+        motor,
+        iterations,
+        residual,
+        pairs: pairs.len(),
+        converged,
+    }
+}
+
+/// Moved source points paired with the index of their nearest target point.
+type Pairs = Vec<(Point, usize)>;
+
+fn evaluate(
+    source: &[Point],
+    target: &[[f32; 3]],
+    metric: Metric,
+    motor: Motor,
+    settings: &Settings,
+) -> (Pairs, f32) {
+    let max2 = settings.max_distance * settings.max_distance;
+    let mut pairs = Vec::with_capacity(source.len());
+    let mut sum = 0.0;
+
+    for p in motor.conj_points(source.iter().copied()) {
+        let x = xyz(p);
+        let nearest = target
+            .iter()
+            .map(|q| distance2(x, *q))
+            .enumerate()
+            // Non-finite coordinates in either cloud never pair up. What is
+            // left is totally ordered.
+            .filter(|&(_, d2)| d2.is_finite())
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        let (i, d2) = match nearest {
+            Some(nearest) if nearest.1 <= max2 => nearest,
+            _ => continue,
+        };
+
+        sum += match metric {
+            Metric::PointToPoint => d2,
+            Metric::PointToPlane(normals) => {
+                let d = plane_distance(x, target[i], normals[i]);
+                d * d
+            }
+        };
+        pairs.push((p, i));
+    }
+
+    let residual = if pairs.is_empty() {
+        0.0
+    } else {
+        (sum / pairs.len() as f32).sqrt()
+    };
+    (pairs, residual)
+}
+
+/// The incremental motor best aligning the current pairs.
+fn step(pairs: &[(Point, usize)], target: &[[f32; 3]], metric: Metric) -> Option<Motor> {
+    match metric {
+        Metric::PointToPoint => {
+            let (from, to): (Vec<Point>, Vec<Point>) = pairs
+                .iter()
+                .map(|&(p, i)| (p, Point::new(target[i][0], target[i][1], target[i][2])))
+                .unzip();
+            register_points(&from, &to).map(|fit| fit.motor)
+        }
+        Metric::PointToPlane(normals) => {
+            // Linearize about the identity: a twist moves x by w * x + v, so
+            // the distance to the plane changes by (x * n).w + n.v.
+            let mut a = [[0.0; 6]; 6];
+            let mut b = [0.0; 6];
+            for &(p, i) in pairs {
+                let x = linalg::vec3(p);
+                let n = normal(normals[i]);
+                let q = target[i];
+                let r = linalg::dot(linalg::sub(x, f64_3(q)), n);
+                let c = linalg::cross(x, n);
+                let j = [c[0], c[1], c[2], n[0], n[1], n[2]];
+                for row in 0..6 {
+                    for col in 0..6 {
+                        a[row][col] += j[row] * j[col];
+                    }
+                    b[row] -= j[row] * r;
+                }
+            }
+
+            let x = linalg::solve(a, b, 6)?;
+            let twist = Line::new(
+                x[3] as f32,
+                x[4] as f32,
+                x[5] as f32,
+                x[0] as f32,
+                x[1] as f32,
+                x[2] as f32,
+            );
+            Some(Motor::IDENTITY.integrate(twist, 1.0))
+        }
+    }
+}
+
+fn f64_3(a: [f32; 3]) -> linalg::Vec3 {
+    [f64::from(a[0]), f64::from(a[1]), f64::from(a[2])]
+}
+
+fn normal(d: Direction) -> linalg::Vec3 {
+    [f64::from(d.x()), f64::from(d.y()), f64::from(d.z())]
+}
+
+fn distance2(a: [f32; 3], b: [f32; 3]) -> f32 {
+    let d = [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+    d[0] * d[0] + d[1] * d[1] + d[2] * d[2]
+}
+
+fn plane_distance(x: [f32; 3], q: [f32; 3], n: Direction) -> f32 {
+    (x[0] - q[0]) * n.x() + (x[1] - q[1]) * n.y() + (x[2] - q[2]) * n.z()
+}
@@ -0,0 +1,406 @@
+//! # Inverse kinematics
+//!
+//! A [`Chain`] is a serial sequence of joints. Joint `i` sits at the end of a
+//! fixed `offset` motor relative to the previous joint (or the chain `base`)
+//! and rotates its subtree about its own origin by a variable [`Rotor`]:
+//!
+//! $$ W_i = W_{i-1}\, O_i R_i, \qquad W_{-1} = B $$
+//!
+//! The end effector is `W_{n-1} * tip`. The local joint motors
+//! $`O_i R_i`$ are what a skeleton pose stores (see [`crate::anim`]).
+//!
+//! Two solvers are provided: cyclic coordinate descent ([`ccd`]) and
+//! forward and backward reaching ([`fabrik`]). Both update the joint rotors
+//! in place, honour the per-joint [`Constraint`]s and accept either a
+//! position ([`Target::Point`]) or a full pose ([`Target::Motor`]) goal.
+//!
+//! ```rust
+//! use klein::ik::{ccd, Chain, Constraint, Settings, Target};
+//! use klein::{Motor, Point, Translator};
+//!
+//! let bone = Motor::from_translator(Translator::new(1.0, 1.0, 0.0, 0.0));
+//! let mut chain = Chain::new(Motor::IDENTITY, bone);
+//! chain.push(Motor::IDENTITY, Constraint::Free);
+//! chain.push(bone, Constraint::Free);
+//!
+//! let solution = ccd(&mut chain, Target::Point(Point::new(1.0, 1.0, 0.0)), &Settings::default());
+//! assert!(solution.converged);
+//! ```
+
+use crate::{
+    linalg::{best_rotation, point, shortest_arc, vec3, Vec3},
+    Direction, Motor, Point, Rotor,
+};
+
+/// Limits on the rotation of a single joint, expressed in the joint frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Constraint {
+    /// Any rotation.
+    Free,
+    /// Rotation about `axis` only, by an angle (as in [`Rotor::new`]) in
+    /// `min..=max` radians.
+    Hinge { axis: Direction, min: f32, max: f32 },
+    /// Swing of `axis` by at most `cone` radians combined with a twist about
+    /// `axis` of at most `twist` radians either way.
+    ConeTwist {
+        axis: Direction,
+        cone: f32,
+        twist: f32,
+    },
+}
+
+impl Constraint {
+    /// The closest rotation satisfying this constraint.
+    pub fn apply(&self, r: Rotor) -> Rotor {
+        match *self {
+            Constraint::Free => r,
+            Constraint::Hinge { axis, min, max } => {
+                let (angle, n) = twist_about(r, axis);
+                rotor_about(angle.max(min).min(max), n)
+            }
+            Constraint::ConeTwist { axis, cone, twist } => {
+                // r = swing * twist, twist about the axis is applied first.
+                let (twist_angle, n) = twist_about(r, axis);
+                let t = rotor_about(twist_angle, n);
+                let swing = r * t.reversed();
+
+                let [s, b1, b2, b3] = swing.p1.into_array();
+                let (s, b) = if s < 0.0 {
+                    (-s, [-b1, -b2, -b3])
+                } else {
+                    (s, [b1, b2, b3])
+                };
+                let sin = (b[0] * b[0] + b[1] * b[1] + b[2] * b[2]).sqrt();
+                let swing_angle = 2.0 * sin.atan2(s);
+
+                let swing = if swing_angle > cone && sin > 0.0 {
+                    let half = 0.5 * cone;
+                    let k = half.sin() / sin;
+                    Rotor::load_normalized([half.cos(), b[0] * k, b[1] * k, b[2] * k])
+                } else {
+                    swing
+                };
+                swing * rotor_about(twist_angle.max(-twist).min(twist), n)
+            }
+        }
+    }
+}
+
+// Angle of the twist of `r` about the (unit) `axis`, and the axis itself.
+fn twist_about(r: Rotor, axis: Direction) -> (f32, [f32; 3]) {
+    let n = [axis.x(), axis.y(), axis.z()];
+    let [s, b1, b2, b3] = r.p1.into_array();
+    // Rotor::new(angle, n) = cos(angle/2) - sin(angle/2) n
+    let proj = -(b1 * n[0] + b2 * n[1] + b3 * n[2]);
+    let (s, proj) = if s < 0.0 { (-s, -proj) } else { (s, proj) };
+    (2.0 * proj.atan2(s), n)
+}
+
+fn rotor_about(angle: f32, n: [f32; 3]) -> Rotor {
+    let (sin, cos) = (0.5 * angle).sin_cos();
+    Rotor::load_normalized([cos, -sin * n[0], -sin * n[1], -sin * n[2]])
+}
+
+/// A single joint of a [`Chain`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChainJoint {
+    /// Fixed placement relative to the previous joint.
+    pub offset: Motor,
+    /// Variable rotation about the joint origin.
+    pub rotation: Rotor,
+    pub constraint: Constraint,
+}
+
+/// A serial chain of rotational joints.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Chain {
+    /// Placement of the chain root.
+    pub base: Motor,
+    pub joints: Vec<ChainJoint>,
+    /// End effector relative to the last joint.
+    pub tip: Motor,
+}
+
+impl Chain {
+    pub fn new(base: Motor, tip: Motor) -> Self {
+        Self {
+            base,
+            joints: Vec::new(),
+            tip,
+        }
+    }
+
+    /// Append a joint in its rest rotation.
+    pub fn push(&mut self, offset: Motor, constraint: Constraint) {
+        self.joints.push(ChainJoint {
+            offset,
+            rotation: Rotor::IDENTITY,
+            constraint,
+        });
+    }
+
+    /// Local joint motors $`O_i R_i`$.
+    pub fn joint_motors(&self) -> Vec<Motor> {
+        self.joints.iter().map(|j| j.offset * j.rotation).collect()
+    }
+
+    /// World motors $`W_i`$ of every joint.
+    pub fn world_motors(&self) -> Vec<Motor> {
+        self.joints
+            .iter()
+            .scan(self.base, |world, j| {
+                *world = *world * j.offset * j.rotation;
+                Some(*world)
+            })
+            .collect()
+    }
+
+    /// World motor of the end effector.
+    pub fn end_effector(&self) -> Motor {
+        self.joint_motors()
+            .into_iter()
+            .fold(self.base, |world, m| world * m)
+            * self.tip
+    }
+
+    // Frames J_i = W_{i-1} O_i of every joint before its rotation.
+    fn frames(&self) -> Vec<Motor> {
+        let mut world = self.base;
+        self.joints
+            .iter()
+            .map(|j| {
+                let frame = world * j.offset;
+                world = frame * j.rotation;
+                frame
+            })
+            .collect()
+    }
+}
+
+/// Goal for the end effector.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Target {
+    /// Reach a position, in any orientation.
+    Point(Point),
+    /// Reach a position and orientation.
+    Motor(Motor),
+}
+
+/// Solver parameters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Settings {
+    pub max_iterations: usize,
+    /// Distance (and for motor goals also angle, in radians) below which the
+    /// goal counts as reached.
+    pub tolerance: f32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_iterations: 64,
+            tolerance: 1e-4,
+        }
+    }
+}
+
+/// Outcome of a solve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Solution {
+    /// The updated local joint motors, as returned by [`Chain::joint_motors`].
+    pub joint_motors: Vec<Motor>,
+    /// Number of iterations performed.
+    pub iterations: usize,
+    /// Distance between the end effector and the goal.
+    pub position_error: f32,
+    /// Rotation angle between the end effector and a motor goal, zero for
+    /// point goals.
+    pub orientation_error: f32,
+    pub converged: bool,
+}
+
+impl Target {
+    fn position(&self) -> Point {
+        match *self {
+            Target::Point(p) => p,
+            Target::Motor(m) => m.conj_origin(),
+        }
+    }
+
+    // Points attached to the end effector (given as `frame`) that should
+    // coincide with the matching points of the goal.
+    fn handles(&self, frame: Motor) -> Vec<(Point, Point)> {
+        match *self {
+            Target::Point(p) => vec![(frame.conj_origin(), p)],
+            Target::Motor(m) => [
+                Point::new(0.0, 0.0, 0.0),
+                Point::new(1.0, 0.0, 0.0),
+                Point::new(0.0, 1.0, 0.0),
+            ]
+            .iter()
+            .map(|&q| (frame.conj_point(q), m.conj_point(q)))
+            .collect(),
+        }
+    }
+
+    fn errors(&self, end: Motor) -> (f32, f32) {
+        let d = distance(end.conj_origin(), self.position());
+        let angle = match *self {
+            Target::Point(_) => 0.0,
+            Target::Motor(m) => {
+                // Rotational part of m * ~end.
+                let [s, ..] = (m * end.reversed()).p1.into_array();
+                2.0 * s.abs().min(1.0).acos()
+            }
+        };
+        (d, angle)
+    }
+}
+
+fn distance(a: Point, b: Point) -> f32 {
+    let (a, b) = (vec3(a), vec3(b));
+    crate::linalg::norm(crate::linalg::sub(a, b)) as f32
+}
+
+fn solution(chain: &Chain, target: &Target, settings: &Settings, iterations: usize) -> Solution {
+    let (position_error, orientation_error) = target.errors(chain.end_effector());
+    Solution {
+        joint_motors: chain.joint_motors(),
+        iterations,
+        position_error,
+        orientation_error,
+        converged: position_error <= settings.tolerance && orientation_error <= settings.tolerance,
+    }
+}
+
+/// Cyclic coordinate descent: sweep the joints from the end effector to the
+/// root, rotating each one to best bring the end effector onto the goal.
+pub fn ccd(chain: &mut Chain, target: Target, settings: &Settings) -> Solution {
+    for iteration in 0..settings.max_iterations {
+        let s = solution(chain, &target, settings, iteration);
+        if s.converged {
+            return s;
+        }
+
+        // The frames before each rotation do not depend on the joints after
+        // it, so they stay valid for the whole backward sweep.
+        let frames = chain.frames();
+        let mut suffix = chain.tip;
+        for i in (0..chain.joints.len()).rev() {
+            let joint = &mut chain.joints[i];
+            let to_local = frames[i].reversed();
+
+            let end = joint.rotation * suffix;
+            let pairs = target
+                .handles(end)
+                .into_iter()
+                .map(|(e, g)| (vec3(e), vec3(to_local.conj_point(g))));
+
+            let delta = align(pairs, &target);
+            joint.rotation = joint.constraint.apply(delta * joint.rotation);
+            suffix = joint.offset * joint.rotation * suffix;
+        }
+    }
+    solution(chain, &target, settings, settings.max_iterations)
+}
+
+// Rotation about the local origin best carrying the end effector handles
+// onto the goal handles.
+fn align(mut pairs: impl Iterator<Item = (Vec3, Vec3)>, target: &Target) -> Rotor {
+    match target {
+        Target::Point(_) => {
+            let (e, g) = pairs.next().unwrap();
+            shortest_arc(e, g)
+        }
+        Target::Motor(_) => best_rotation(pairs.map(|(e, g)| (e, g, 1.0))),
+    }
+}
+
+/// Forward and backward reaching: move the joint positions along the bone
+/// directions toward the goal and back to the base, then re-derive the
+/// joint rotations from the new positions.
+pub fn fabrik(chain: &mut Chain, target: Target, settings: &Settings) -> Solution {
+    let n = chain.joints.len();
+    if n == 0 {
+        return solution(chain, &target, settings, 0);
+    }
+    // For a motor goal the orientation of the last joint is fixed by the
+    // goal, so the positions are solved for the last joint origin instead of
+    // the end effector.
+    let (goal, bones) = match target {
+        Target::Point(p) => (vec3(p), n),
+        Target::Motor(m) => (vec3((m * chain.tip.reversed()).conj_origin()), n - 1),
+    };
+
+    for iteration in 0..settings.max_iterations {
+        let s = solution(chain, &target, settings, iteration);
+        if s.converged {
+            return s;
+        }
+
+        // Joint positions followed by the end effector.
+        let mut points: Vec<Vec3> = chain
+            .frames()
+            .iter()
+            .map(|f| vec3(f.conj_origin()))
+            .collect();
+        points.push(vec3(chain.end_effector().conj_origin()));
+        let lengths: Vec<f64> = points
+            .windows(2)
+            .map(|w| crate::linalg::norm(crate::linalg::sub(w[1], w[0])))
+            .collect();
+        let root = points[0];
+
+        // Backward pass from the goal, then forward pass from the root.
+        points[bones] = goal;
+        for i in (0..bones).rev() {
+            points[i] = toward(points[i + 1], points[i], lengths[i]);
+        }
+        points[0] = root;
+        for i in 0..bones {
+            points[i + 1] = toward(points[i], points[i + 1], lengths[i]);
+        }
+
+        // Rotate every joint so that its child lands on the new position.
+        for i in 0..bones {
+            let frames = chain.frames();
+            let to_local = frames[i].reversed();
+            let joint = chain.joints[i];
+            let child = if i + 1 < n {
+                chain.joints[i + 1].offset
+            } else {
+                chain.tip
+            };
+
+            let current = vec3((joint.rotation * child).conj_origin());
+            let wanted = vec3(to_local.conj_point(point(points[i + 1])));
+            let delta = shortest_arc(current, wanted);
+            chain.joints[i].rotation = joint.constraint.apply(delta * joint.rotation);
+        }
+
+        // The last joint takes on the goal orientation.
+        if let Target::Motor(m) = target {
+            let frame = chain.frames()[n - 1];
+            let r = rotor(frame).reversed() * rotor(m) * rotor(chain.tip).reversed();
+            let last = &mut chain.joints[n - 1];
+            last.rotation = last.constraint.apply(r);
+        }
+    }
+    solution(chain, &target, settings, settings.max_iterations)
+}
+
+// The point at distance `length` from `from` in the direction of `to`.
+fn toward(from: Vec3, to: Vec3, length: f64) -> Vec3 {
+    let d = crate::linalg::sub(to, from);
+    let l = crate::linalg::norm(d);
+    if l < 1e-12 {
+        return from;
+    }
+    let k = length / l;
+    [from[0] + d[0] * k, from[1] + d[1] * k, from[2] + d[2] * k]
+}
+
+// Rotational part of a motor.
+fn rotor(m: Motor) -> Rotor {
+    Rotor::from(m.p1).normalized()
+}
@@ -0,0 +1,69 @@
+use super::{quat_from_rotor, rotor_from_quat};
+use crate::{arch::f32x4, linalg::xyz, Direction, Mat4x4, Motor, Point, Rotor};
+use glam::{Mat4, Quat, Vec3A};
+
+impl From<Vec3A> for Point {
+    fn from(v: Vec3A) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Point> for Vec3A {
+    fn from(p: Point) -> Self {
+        let [x, y, z] = xyz(p);
+        Self::new(x, y, z)
+    }
+}
+
+/// The direction is not normalized.
+impl From<Vec3A> for Direction {
+    fn from(v: Vec3A) -> Self {
+        Self::from(f32x4::new(v.z, v.y, v.x, 0.0))
+    }
+}
+
+impl From<Direction> for Vec3A {
+    fn from(d: Direction) -> Self {
+        Self::new(d.x(), d.y(), d.z())
+    }
+}
+
+impl From<Quat> for Rotor {
+    fn from(q: Quat) -> Self {
+        rotor_from_quat([q.x, q.y, q.z, q.w])
+    }
+}
+
+impl From<Rotor> for Quat {
+    fn from(r: Rotor) -> Self {
+        let [x, y, z, w] = quat_from_rotor(r);
+        Self::from_xyzw(x, y, z, w)
+    }
+}
+
+impl From<Mat4> for Mat4x4 {
+    fn from(m: Mat4) -> Self {
+        let m = m.to_cols_array();
+        let col = |i: usize| [m[4 * i], m[4 * i + 1], m[4 * i + 2], m[4 * i + 3]];
+        Self::from_cols([col(0), col(1), col(2), col(3)])
+    }
+}
+
+impl From<Mat4x4> for Mat4 {
+    fn from(m: Mat4x4) -> Self {
+        let [x, y, z, w] = m.to_cols();
+        Self::from_cols(x.into(), y.into(), z.into(), w.into())
+    }
+}
+
+impl From<Rotor> for Mat4 {
+    fn from(r: Rotor) -> Self {
+        r.as_mat4x4().into()
+    }
+}
+
+impl From<Motor> for Mat4 {
+    fn from(m: Motor) -> Self {
+        m.as_mat4x4().into()
+    }
+}
@@ -0,0 +1,74 @@
+use super::{quat_from_rotor, rotor_from_quat};
+use crate::{arch::f32x4, linalg::xyz, Direction, Mat4x4, Motor, Point, Rotor};
+use mint::{ColumnMatrix4, Point3, Quaternion, Vector3, Vector4};
+
+impl From<Point3<f32>> for Point {
+    fn from(p: Point3<f32>) -> Self {
+        Self::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<Point> for Point3<f32> {
+    fn from(p: Point) -> Self {
+        xyz(p).into()
+    }
+}
+
+/// The direction is not normalized.
+impl From<Vector3<f32>> for Direction {
+    fn from(v: Vector3<f32>) -> Self {
+        Self::from(f32x4::new(v.z, v.y, v.x, 0.0))
+    }
+}
+
+impl From<Direction> for Vector3<f32> {
+    fn from(d: Direction) -> Self {
+        [d.x(), d.y(), d.z()].into()
+    }
+}
+
+impl From<Quaternion<f32>> for Rotor {
+    fn from(q: Quaternion<f32>) -> Self {
+        rotor_from_quat([q.v.x, q.v.y, q.v.z, q.s])
+    }
+}
+
+impl From<Rotor> for Quaternion<f32> {
+    fn from(r: Rotor) -> Self {
+        let [x, y, z, w] = quat_from_rotor(r);
+        Self {
+            v: [x, y, z].into(),
+            s: w,
+        }
+    }
+}
+
+impl From<ColumnMatrix4<f32>> for Mat4x4 {
+    fn from(m: ColumnMatrix4<f32>) -> Self {
+        Self::from_cols([m.x.into(), m.y.into(), m.z.into(), m.w.into()])
+    }
+}
+
+impl From<Mat4x4> for ColumnMatrix4<f32> {
+    fn from(m: Mat4x4) -> Self {
+        let [x, y, z, w] = m.to_cols();
+        Self {
+            x: Vector4::from(x),
+            y: Vector4::from(y),
+            z: Vector4::from(z),
+            w: Vector4::from(w),
+        }
+    }
+}
+
+impl From<Rotor> for ColumnMatrix4<f32> {
+    fn from(r: Rotor) -> Self {
+        r.as_mat4x4().into()
+    }
+}
+
+impl From<Motor> for ColumnMatrix4<f32> {
+    fn from(m: Motor) -> Self {
+        m.as_mat4x4().into()
+    }
+}
@@ -0,0 +1,33 @@
+//! Conversions to and from the math types of other crates.
+//!
+//! Each integration is enabled by the feature of the same name. The sign
+//! conventions are the same everywhere:
+//!
+//! - A [`Point`](crate::Point) converts to its Euclidean coordinates
+//!   `(x/w, y/w, z/w)`, and a 3D point converts back with `w = 1`.
+//! - A [`Rotor`](crate::Rotor) $`a + b\mathbf{e}_{23} + c\mathbf{e}_{31} +\
+//!   d\mathbf{e}_{12}`$ corresponds to the quaternion $`a - bi - cj - dk`$, so
+//!   both rotate vectors in the same direction.
+//! - A [`Motor`](crate::Motor) is split into a rotation followed by a
+//!   translation, and a [`Mat4x4`](crate::Mat4x4) is column-major, acting on
+//!   column vectors.
+
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+use crate::{arch::f32x4, Rotor};
+
+// Quaternion `(x, y, z, w)` to rotor.
+fn rotor_from_quat([x, y, z, w]: [f32; 4]) -> Rotor {
+    Rotor::from(f32x4::new(-z, -y, -x, w))
+}
+
+// Rotor to quaternion `(x, y, z, w)`.
+fn quat_from_rotor(r: Rotor) -> [f32; 4] {
+    let [a, b, c, d] = r.p1.into_array();
+    [-b, -c, -d, a]
+}
@@ -0,0 +1,75 @@
+use super::{quat_from_rotor, rotor_from_quat};
+use crate::{arch::f32x4, linalg::xyz, Direction, Mat4x4, Motor, Point, Rotor, Translator};
+use nalgebra::{
+    Isometry3, Matrix4, Point3, Quaternion, Translation3, Unit, UnitQuaternion, Vector3,
+};
+
+impl From<Point3<f32>> for Point {
+    fn from(p: Point3<f32>) -> Self {
+        Self::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<Point> for Point3<f32> {
+    fn from(p: Point) -> Self {
+        let [x, y, z] = xyz(p);
+        Self::new(x, y, z)
+    }
+}
+
+/// The direction is not normalized.
+impl From<Vector3<f32>> for Direction {
+    fn from(v: Vector3<f32>) -> Self {
+        Self::from(f32x4::new(v.z, v.y, v.x, 0.0))
+    }
+}
+
+impl From<Direction> for Vector3<f32> {
+    fn from(d: Direction) -> Self {
+        Self::new(d.x(), d.y(), d.z())
+    }
+}
+
+impl From<UnitQuaternion<f32>> for Rotor {
+    fn from(q: UnitQuaternion<f32>) -> Self {
+        rotor_from_quat([q.i, q.j, q.k, q.w])
+    }
+}
+
+/// The rotor must be normalized.
+impl From<Rotor> for UnitQuaternion<f32> {
+    fn from(r: Rotor) -> Self {
+        let [x, y, z, w] = quat_from_rotor(r);
+        Unit::new_unchecked(Quaternion::new(w, x, y, z))
+    }
+}
+
+impl From<Isometry3<f32>> for Motor {
+    fn from(iso: Isometry3<f32>) -> Self {
+        let v = iso.translation.vector;
+        let t = Translator::from_offset([v.x, v.y, v.z]);
+        t * Rotor::from(iso.rotation)
+    }
+}
+
+/// The motor must be normalized.
+impl From<Motor> for Isometry3<f32> {
+    fn from(m: Motor) -> Self {
+        let [x, y, z] = xyz(m.conj_origin());
+        Self::from_parts(Translation3::new(x, y, z), Rotor::from(m.p1).into())
+    }
+}
+
+impl From<Matrix4<f32>> for Mat4x4 {
+    fn from(m: Matrix4<f32>) -> Self {
+        let col = |i: usize| [m[(0, i)], m[(1, i)], m[(2, i)], m[(3, i)]];
+        Self::from_cols([col(0), col(1), col(2), col(3)])
+    }
+}
+
+impl From<Mat4x4> for Matrix4<f32> {
+    fn from(m: Mat4x4) -> Self {
+        let [x, y, z, w] = m.to_cols();
+        Self::from_columns(&[x.into(), y.into(), z.into(), w.into()])
+    }
+}
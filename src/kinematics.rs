@@ -0,0 +1,62 @@
+// Kinematics of motors driven by twists.
+//
+// A twist is a line holding the angular velocity in its Euclidean part
+// (e23, e31, e12) and the linear velocity of the point at the origin in its
+// ideal part (e01, e02, e03). Over a time step `dt` a constant twist `B`
+// produces the motor `exp(-B dt / 2)`, the same convention `Rotor::new` and
+// `Translator::new` use for positive angles and distances.
+
+use crate::{Direction, Line, Motor, Point};
+
+impl Line {
+    /// Velocity of `point` moving under this twist: $`v + \omega \times x`$,
+    /// where $`\omega`$ is the Euclidean part, $`v`$ the ideal part and
+    /// $`x`$ the (normalized) position of the point.
+    pub fn velocity_at(self, point: Point) -> Direction {
+        let w = point.w();
+        let (x, y, z) = (point.x() / w, point.y() / w, point.z() / w);
+        let (wx, wy, wz) = (self.e23(), self.e31(), self.e12());
+        Direction::from_array([
+            0.0,
+            self.e01() + wy * z - wz * y,
+            self.e02() + wz * x - wx * z,
+            self.e03() + wx * y - wy * x,
+        ])
+    }
+}
+
+impl Motor {
+    /// Advance this motor by the world frame `twist` held constant over
+    /// `dt`. Twists expressed in the body frame should be passed through
+    /// [`twist_to_world`](Self::twist_to_world) first.
+    pub fn integrate(self, twist: Line, dt: f32) -> Self {
+        (twist * (-0.5 * dt)).exp() * self
+    }
+
+    /// The constant world frame twist taking `a` to `b` in time `dt`, so
+    /// that `a.integrate(Motor::twist_between(a, b, dt), dt)` is `b`. Of the
+    /// two screw motions connecting the motors, the shorter one is used.
+    pub fn twist_between(a: Self, b: Self, dt: f32) -> Line {
+        let step = b * a.reversed();
+        // `m` and `-m` are the same motion, pick the one with the smaller
+        // rotation angle.
+        let step = if step.p1.extract0() < 0.0 {
+            -step
+        } else {
+            step
+        };
+        step.log() * (-2.0 / dt)
+    }
+
+    /// Express a twist given in the frame of this (body to world) motor in
+    /// the world frame.
+    pub fn twist_to_world(&self, body: Line) -> Line {
+        self.conj_line(body)
+    }
+
+    /// Express a world frame twist in the frame of this (body to world)
+    /// motor.
+    pub fn twist_to_body(&self, world: Line) -> Line {
+        self.reversed().conj_line(world)
+    }
+}
@@ -451,7 +451,10 @@
 pub mod arch;
 
 mod exp_log; // f32x4
+mod fit;
+mod flector;
 mod join; // f32x4
+mod kinematics;
 mod multivector_ep;
 mod multivector_gp; // f32x4
 mod multivector_ip; // f32x4
@@ -468,9 +471,38 @@ mod translator; // done f32x4
 
 mod macros;
 
+pub mod anim;
+pub mod camera;
+pub mod curved;
+pub mod dynamics;
+pub mod icp;
+pub mod ik;
+pub mod pga2d;
+pub mod polytope;
+pub mod ray;
+pub mod registration;
+pub mod volume;
+mod algebra;
+mod linalg;
+
+mod display;
+
+#[cfg(feature = "approx")]
+mod approximate;
+#[cfg(any(feature = "glam", feature = "mint", feature = "nalgebra"))]
+mod interop;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "serde")]
+mod serialize;
+
 pub use self::{
     direction::Direction,
+    display::ParseError,
     dual::Dual,
+    flector::Flector,
     line::{Branch, IdealLine, Line},
     matrix::{Mat3x4, Mat4x4},
     motor::Motor,
@@ -480,6 +512,13 @@ pub use self::{
     translator::Translator,
 };
 
+#[cfg(feature = "approx")]
+pub use self::approximate::Projective;
+#[cfg(feature = "rand")]
+pub use self::random::Region;
+#[cfg(feature = "serde")]
+pub use self::serialize::serde_array;
+
 /*
 pub fn direction(x: f32, y: f32, z: f32) -> Direction {
     Direction::new(x, y, z)
@@ -0,0 +1,220 @@
+// Small dense linear algebra used by the solvers and fitting routines. Not
+// SIMD friendly and not on any hot path, so everything is plain f64.
+
+use crate::Rotor;
+
+pub(crate) type Vec3 = [f64; 3];
+
+#[inline]
+pub(crate) fn dot(a: Vec3, b: Vec3) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline]
+pub(crate) fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+#[inline]
+pub(crate) fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+#[inline]
+pub(crate) fn sub(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+#[inline]
+pub(crate) fn scale(a: Vec3, s: f64) -> Vec3 {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+#[inline]
+pub(crate) fn norm(a: Vec3) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// Eigen decomposition of the leading `n x n` block (`n <= 4`) of a
+/// symmetric matrix with cyclic Jacobi rotations. Returns the eigenvalues in
+/// descending order and the matching unit eigenvectors as *columns*.
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn sym_eigen(mut a: [[f64; 4]; 4], n: usize) -> ([f64; 4], [[f64; 4]; 4]) {
+    let mut v = [[0.0; 4]; 4];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+
+    for _ in 0..32 {
+        let mut off = 0.0;
+        for i in 0..n {
+            for j in i + 1..n {
+                off += a[i][j] * a[i][j];
+            }
+        }
+        if off < 1e-30 {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q].abs() < 1e-300 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for k in 0..n {
+                    let (akp, akq) = (a[k][p], a[k][q]);
+                    a[k][p] = c * akp - s * akq;
+                    a[k][q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p][k], a[q][k]);
+                    a[p][k] = c * apk - s * aqk;
+                    a[q][k] = s * apk + c * aqk;
+                }
+                for row in v.iter_mut().take(n) {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    // Sort by descending eigenvalue.
+    let mut order = [0, 1, 2, 3];
+    order[..n].sort_by(|&i, &j| a[j][j].partial_cmp(&a[i][i]).unwrap());
+
+    let mut values = [0.0; 4];
+    let mut vectors = [[0.0; 4]; 4];
+    for (col, &k) in order[..n].iter().enumerate() {
+        values[col] = a[k][k];
+        for row in 0..n {
+            vectors[row][col] = v[row][k];
+        }
+    }
+    (values, vectors)
+}
+
+/// Solve the `n x n` system `a x = b` (`n <= 6`) by Gaussian elimination
+/// with partial pivoting. Returns `None` for (numerically) singular systems.
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn solve(mut a: [[f64; 6]; 6], mut b: [f64; 6], n: usize) -> Option<[f64; 6]> {
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&i, &j| a[i][col].abs().partial_cmp(&a[j][col].abs()).unwrap())
+            .unwrap();
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        for row in col + 1..n {
+            let f = a[row][col] / a[col][col];
+            for k in col..n {
+                a[row][k] -= f * a[col][k];
+            }
+            b[row] -= f * b[col];
+        }
+    }
+
+    let mut x = [0.0; 6];
+    for row in (0..n).rev() {
+        let s: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - s) / a[row][row];
+    }
+    Some(x)
+}
+
+/// The rotor best rotating each `a` onto the matching `b` in the weighted
+/// least-squares sense (Horn's quaternion method).
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn best_rotation(pairs: impl Iterator<Item = (Vec3, Vec3, f64)>) -> Rotor {
+    let mut m = [[0.0; 3]; 3];
+    for (a, b, w) in pairs {
+        for i in 0..3 {
+            for j in 0..3 {
+                m[i][j] += w * a[i] * b[j];
+            }
+        }
+    }
+
+    let [[sxx, sxy, sxz], [syx, syy, syz], [szx, szy, szz]] = m;
+    let k = [
+        [sxx + syy + szz, syz - szy, szx - sxz, sxy - syx],
+        [syz - szy, sxx - syy - szz, sxy + syx, szx + sxz],
+        [szx - sxz, sxy + syx, -sxx + syy - szz, syz + szy],
+        [sxy - syx, szx + sxz, syz + szy, -sxx - syy + szz],
+    ];
+
+    let (_, vectors) = sym_eigen(k, 4);
+    let q = [vectors[0][0], vectors[1][0], vectors[2][0], vectors[3][0]];
+    rotor_from_quat(q)
+}
+
+/// The shortest-arc rotor taking the direction of `a` to the direction of
+/// `b`. Returns the identity when either vector vanishes.
+pub(crate) fn shortest_arc(a: Vec3, b: Vec3) -> Rotor {
+    let (na, nb) = (norm(a), norm(b));
+    if na < 1e-12 || nb < 1e-12 {
+        return Rotor::IDENTITY;
+    }
+    let a = scale(a, 1.0 / na);
+    let b = scale(b, 1.0 / nb);
+
+    let c = dot(a, b);
+    if c < -1.0 + 1e-9 {
+        // Opposite directions: rotate by pi about any perpendicular axis.
+        let axis = perpendicular(a);
+        return rotor_from_quat([0.0, axis[0], axis[1], axis[2]]);
+    }
+
+    let v = cross(a, b);
+    rotor_from_quat([1.0 + c, v[0], v[1], v[2]])
+}
+
+/// A unit vector perpendicular to the nonzero vector `a`.
+pub(crate) fn perpendicular(a: Vec3) -> Vec3 {
+    let p = if a[0].abs() < 0.9 * norm(a) {
+        cross(a, [1.0, 0.0, 0.0])
+    } else {
+        cross(a, [0.0, 1.0, 0.0])
+    };
+    scale(p, 1.0 / norm(p))
+}
+
+/// Rotor for the unit quaternion `(w, x, y, z)`, normalizing the input.
+pub(crate) fn rotor_from_quat(q: [f64; 4]) -> Rotor {
+    let n = (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt();
+    let [w, x, y, z] = [q[0] / n, q[1] / n, q[2] / n, q[3] / n];
+    Rotor::load_normalized([w as f32, -x as f32, -y as f32, -z as f32])
+}
+
+pub(crate) fn vec3(p: crate::Point) -> Vec3 {
+    let w = f64::from(p.w());
+    [
+        f64::from(p.x()) / w,
+        f64::from(p.y()) / w,
+        f64::from(p.z()) / w,
+    ]
+}
+
+pub(crate) fn point(x: Vec3) -> crate::Point {
+    crate::Point::new(x[0] as f32, x[1] as f32, x[2] as f32)
+}
+
+/// Euclidean coordinates of a (possibly unnormalized) point in single
+/// precision.
+pub(crate) fn xyz(p: crate::Point) -> [f32; 3] {
+    let w = p.w();
+    [p.x() / w, p.y() / w, p.z() / w]
+}
@@ -3,12 +3,18 @@ use crate::arch::f32x4;
 /// An ideal line represents a line at infinity and corresponds to the
 /// multivector:
 /// _a_**e**&#x2080;&#x2081; + _b_**e**&#x2080;&#x2082; + _c_**e**&#x2080;&#x2083;
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct IdealLine {
     pub(crate) p2: f32x4,
 }
 
 impl IdealLine {
+    /// The zero ideal line.
+    pub const ZERO: Self = Self {
+        p2: f32x4::from_const([0.0; 4]),
+    };
+
     pub fn new(a: f32, b: f32, c: f32) -> Self {
         Self::from(f32x4::new(c, b, a, 0.0))
     }
@@ -56,12 +62,18 @@ impl IdealLine {
 ///  The branch of a rotor is technically a `line`, but because there are
 ///  no translational components, the branch is given its own type for
 ///  efficiency.
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Branch {
     pub(crate) p1: f32x4,
 }
 
 impl Branch {
+    /// The zero branch.
+    pub const ZERO: Self = Self {
+        p1: f32x4::from_const([0.0; 4]),
+    };
+
     /// Construct the branch as the following multivector:
     ///
     /// $$a \mathbf{e}_{23} + b\mathbf{e}_{31} + c\mathbf{e}_{23}$$
@@ -123,15 +135,27 @@ impl Branch {
 /// A general line in `PGA` is given as a 6-coordinate bivector with a direct
 /// correspondence to Plücker coordinates. All lines can be exponentiated using
 /// the `exp` method to generate a motor.
+///
+/// # Memory layout
+///
+/// Two 16-byte aligned registers (32 bytes in total), laid out like a
+/// [`Motor`](crate::Motor) whose scalar and pseudoscalar lanes are zero.
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Line {
-    // p1: (1, e12, e31, e23)
+    // p1: (1, e23, e31, e12)
     pub(crate) p1: f32x4,
     // p2: (e0123, e01, e02, e03)
     pub(crate) p2: f32x4,
 }
 
 impl Line {
+    /// The zero line.
+    pub const ZERO: Self = Self {
+        p1: f32x4::from_const([0.0; 4]),
+        p2: f32x4::from_const([0.0; 4]),
+    };
+
     /// A line is specifed by 6 coordinates which correspond to the line's
     /// [Plücker coordinates](https://en.wikipedia.org/wiki/Pl%C3%BCcker_coordinates).
     /// The coordinates specified in this way correspond to the following
@@ -235,6 +259,21 @@ impl Line {
         self
     }
 
+    /// The commutator product $`a \times b = \frac{1}{2}(ab - ba)`$. For two
+    /// lines the scalar and pseudoscalar parts of the geometric products
+    /// cancel, leaving a line. This is the product appearing in the rigid
+    /// body equations of motion (see [`crate::dynamics`]).
+    pub fn commutator(self, other: Self) -> Self {
+        let a = crate::Motor::from((self.p1, self.p2));
+        let b = crate::Motor::from((other.p1, other.p2));
+        let (ab, ba) = (a * b, b * a);
+        let half = f32x4::new(0.5, 0.5, 0.5, 0.0);
+        Self {
+            p1: (ab.p1 - ba.p1) * half,
+            p2: (ab.p2 - ba.p2) * half,
+        }
+    }
+
     pub fn line_eq(self, other: Self) -> bool {
         f32x4::bit_eq_pair(self.into(), other.into())
     }
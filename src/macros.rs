@@ -121,6 +121,15 @@ macro_rules! derive_eq {
             }
         }
     };
+
+    ($ty:ty { $field:ident }) => {
+        impl core::cmp::PartialEq for $ty {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.$field.bit_eq(other.$field)
+            }
+        }
+    };
 }
 
 macro_rules! derive_f32x4 {
@@ -171,6 +180,48 @@ macro_rules! derive_f32x4 {
                 Self { $($field: self.$field / s),+ }
             }
         }
+
+        impl core::ops::AddAssign for $ty {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field = self.$field + rhs.$field;)+
+            }
+        }
+
+        impl core::ops::SubAssign for $ty {
+            #[inline(always)]
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field = self.$field - rhs.$field;)+
+            }
+        }
+
+        impl core::ops::MulAssign<f32> for $ty {
+            #[inline(always)]
+            fn mul_assign(&mut self, s: f32) {
+                $(self.$field = self.$field * s;)+
+            }
+        }
+
+        impl core::ops::DivAssign<f32> for $ty {
+            #[inline(always)]
+            fn div_assign(&mut self, s: f32) {
+                $(self.$field = self.$field / s;)+
+            }
+        }
+
+        /// Component-wise sum, starting from all components zero.
+        impl core::iter::Sum for $ty {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                let zero = Self { $($field: f32x4::zero()),+ };
+                iter.fold(zero, |acc, x| acc + x)
+            }
+        }
+
+        impl<'a> core::iter::Sum<&'a $ty> for $ty {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
     };
 
     (vector flip_w for $ty:ident { $($field:ident),+ }) => {
@@ -198,8 +249,67 @@ macro_rules! derive_f32x4 {
     };
 }
 
+macro_rules! derive_default {
+    ($($ty:ident => $value:ident),+) => {
+        $(
+            impl Default for $ty {
+                #[inline]
+                fn default() -> Self {
+                    Self::$value
+                }
+            }
+        )+
+    };
+}
+
+/// Compound assignment for geometric products whose result has the type of
+/// the left operand, e.g. `motor *= rotor`.
+macro_rules! derive_gp_assign {
+    ($ty:ident *= $($rhs:ident),+) => {
+        $(
+            impl core::ops::MulAssign<$rhs> for $ty {
+                #[inline]
+                fn mul_assign(&mut self, rhs: $rhs) {
+                    *self = *self * rhs;
+                }
+            }
+
+            impl core::ops::DivAssign<$rhs> for $ty {
+                #[inline]
+                fn div_assign(&mut self, rhs: $rhs) {
+                    *self = *self / rhs;
+                }
+            }
+        )+
+    };
+}
+
+/// The product of an iterator of transformations is their composition,
+/// folded from the left starting at the identity. As with `a * b`, the
+/// result applies the *last* item first: `[a, b, c].iter().product()` is
+/// `a * b * c`.
+macro_rules! derive_product {
+    ($($ty:ident),+) => {
+        $(
+            impl core::iter::Product for $ty {
+                fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(Self::IDENTITY, |acc, x| acc * x)
+                }
+            }
+
+            impl<'a> core::iter::Product<&'a $ty> for $ty {
+                fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                    iter.copied().product()
+                }
+            }
+        )+
+    };
+}
+
 use crate::arch::f32x4;
-use crate::{Branch, Direction, IdealLine, Line, Motor, Plane, Point, Rotor, Translator};
+use crate::{
+    Branch, Direction, Flector, IdealLine, Line, Mat4x4, Motor, Plane, Point, Rotor, Translator,
+};
 
 derive_f32x4!(vector add/sub/scale/flip_w for Direction { p3: f32x4 });
 derive_f32x4!(vector add/sub/scale for Translator { p2: f32x4 });
@@ -210,11 +320,38 @@ derive_f32x4!(vector add/sub/scale/flip_xyz for Plane { p0: f32x4 });
 derive_f32x4!(vector add/sub/scale/flip_xyz for Point { p3: f32x4 });
 derive_f32x4!(vector add/sub/scale/flip_w for Motor { p1: f32x4, p2: f32x4 });
 derive_f32x4!(vector add/sub/scale/flip_w for Rotor { p1: f32x4 });
+derive_f32x4!(vector add/sub/scale/flip_w for Flector { p0: f32x4, p3: f32x4 });
 
 derive_eq!(Motor => motor_eq);
 derive_eq!(Rotor => rotor_eq);
+derive_eq!(Flector => flector_eq);
 derive_eq!(Line => line_eq);
 derive_eq!(Plane => plane_eq);
+derive_eq!(Point { p3 });
+derive_eq!(Direction { p3 });
+derive_eq!(Branch { p1 });
+derive_eq!(IdealLine { p2 });
+derive_eq!(Translator { p2 });
+
+derive_default!(
+    Motor => IDENTITY,
+    Rotor => IDENTITY,
+    Translator => IDENTITY,
+    Mat4x4 => IDENTITY,
+    Point => ORIGIN,
+    Direction => ZERO,
+    Plane => ZERO,
+    Line => ZERO,
+    Branch => ZERO,
+    IdealLine => ZERO
+);
+
+derive_gp_assign!(Motor *= Motor, Rotor, Translator);
+derive_gp_assign!(Rotor *= Rotor);
+derive_gp_assign!(Translator *= Translator);
+derive_gp_assign!(Flector *= Motor);
+
+derive_product!(Motor, Rotor, Translator);
 
 derive_attrs!(struct Direction {
     p3: {1: x, 2: y, 3: z}
@@ -241,6 +378,10 @@ derive_attrs!(struct Translator {
 derive_attrs!(struct Rotor {
     p1: {0: scalar, 1: e23, 2: e13, 3: e12}
 });
+derive_attrs!(struct Flector {
+    p0: {0: e0, 1: e1, 2: e2, 3: e3},
+    p3: {0: e123, 1: e032, 2: e013, 3: e021}
+});
 derive_attrs!(struct Motor {
     p1: {0: scalar, 1: e23, 2: e31, 3: e12},
     p2: {0: e0123, 1: e01, 2: e02, 3: e03}
@@ -31,7 +31,6 @@ impl Mat3x4 {
 */
 
 /// 4x4 column-major matrix (used for converting rotors/motors to matrix form to upload to shaders).
-#[doc(hidden)]
 #[repr(C, align(16))]
 #[derive(Clone, Copy)]
 pub struct Mat4x4 {
@@ -42,15 +41,104 @@ pub struct Mat4x4 {
 }
 
 impl Mat4x4 {
+    /// The identity matrix.
+    pub const IDENTITY: Self = Self {
+        x: f32x4::from_const([1.0, 0.0, 0.0, 0.0]),
+        y: f32x4::from_const([0.0, 1.0, 0.0, 0.0]),
+        z: f32x4::from_const([0.0, 0.0, 1.0, 0.0]),
+        w: f32x4::from_const([0.0, 0.0, 0.0, 1.0]),
+    };
+
+    /// Construct a matrix from its four columns, each given as `(x, y, z, w)`.
+    pub fn from_cols(cols: [[f32; 4]; 4]) -> Self {
+        Self {
+            x: f32x4::from_array(cols[0]),
+            y: f32x4::from_array(cols[1]),
+            z: f32x4::from_array(cols[2]),
+            w: f32x4::from_array(cols[3]),
+        }
+    }
+
+    /// The four columns of this matrix, each given as `(x, y, z, w)`.
+    pub fn to_cols(&self) -> [[f32; 4]; 4] {
+        [
+            self.x.into_array(),
+            self.y.into_array(),
+            self.z.into_array(),
+            self.w.into_array(),
+        ]
+    }
+
     /// Apply the linear transformation represented by this matrix to a point
-    /// packed with the layout (x, y, z, 1.f)
-    pub fn apply(&self, xyzw: Point) -> Point {
-        let x = self.x * shuffle!(xyzw.p3, [0, 0, 0, 0]);
-        let y = self.y * shuffle!(xyzw.p3, [1, 1, 1, 1]);
-        let z = self.z * shuffle!(xyzw.p3, [2, 2, 2, 2]);
-        let w = self.w * shuffle!(xyzw.p3, [3, 3, 3, 3]);
-        Point::from(x + y + z + w)
+    pub fn apply(&self, p: Point) -> Point {
+        // The point is stored as (w, x, y, z) while the matrix columns are
+        // laid out as (x, y, z, w).
+        let x = self.x * shuffle!(p.p3, [1, 1, 1, 1]);
+        let y = self.y * shuffle!(p.p3, [2, 2, 2, 2]);
+        let z = self.z * shuffle!(p.p3, [3, 3, 3, 3]);
+        let w = self.w * shuffle!(p.p3, [0, 0, 0, 0]);
+        Point::from(shuffle!(x + y + z + w, [2, 1, 0, 3]))
     }
 
     // TODO: provide a transpose function
 }
+
+impl PartialEq for Mat4x4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_cols() == other.to_cols()
+    }
+}
+
+impl core::fmt::Debug for Mat4x4 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Mat4x4").field(&self.to_cols()).finish()
+    }
+}
+
+// Builds the matrix form of the conjugation `r p ~r` (with `p2` given,
+// `m p ~m`) from the rotor (motor) partitions. With the rotor
+// `b0 + b1 e23 + b2 e31 + b3 e12`, the rotational block coincides with the
+// usual quaternion matrix for `(w, x, y, z) = (b0, -b1, -b2, -b3)`. The
+// matrix is not normalized, so the homogeneous coordinate of the result is
+// scaled by the squared norm of the rotor as with the sandwich itself.
+pub(crate) fn mat4x4_12(p1: f32x4, p2: Option<f32x4>) -> Mat4x4 {
+    let [b0, b1, b2, b3] = p1.into_array();
+
+    let b0b0 = b0 * b0;
+    let b1b1 = b1 * b1;
+    let b2b2 = b2 * b2;
+    let b3b3 = b3 * b3;
+
+    let x = [
+        b0b0 + b1b1 - b2b2 - b3b3,
+        2.0 * (b1 * b2 - b0 * b3),
+        2.0 * (b1 * b3 + b0 * b2),
+        0.0,
+    ];
+    let y = [
+        2.0 * (b1 * b2 + b0 * b3),
+        b0b0 - b1b1 + b2b2 - b3b3,
+        2.0 * (b2 * b3 - b0 * b1),
+        0.0,
+    ];
+    let z = [
+        2.0 * (b1 * b3 - b0 * b2),
+        2.0 * (b2 * b3 + b0 * b1),
+        b0b0 - b1b1 - b2b2 + b3b3,
+        0.0,
+    ];
+
+    let w = if let Some(p2) = p2 {
+        let [c0, c1, c2, c3] = p2.into_array();
+        [
+            2.0 * (b2 * c3 - b0 * c1 - b3 * c2 - b1 * c0),
+            2.0 * (b3 * c1 - b0 * c2 - b1 * c3 - b2 * c0),
+            2.0 * (b1 * c2 - b0 * c3 - b2 * c1 - b3 * c0),
+            b0b0 + b1b1 + b2b2 + b3b3,
+        ]
+    } else {
+        [0.0, 0.0, 0.0, b0b0 + b1b1 + b2b2 + b3b3]
+    };
+
+    Mat4x4::from_cols([x, y, z, w])
+}
@@ -1,5 +1,15 @@
-use crate::{arch::f32x4, Direction, Dual, Line, Plane, Point, Rotor, Translator};
-
+use crate::{
+    arch::f32x4, Branch, Direction, Dual, IdealLine, Line, Mat4x4, Plane, Point, Rotor, Translator,
+};
+
+/// # Memory layout
+///
+/// Two 16-byte aligned registers (32 bytes in total), lowest address first:
+///
+/// - `p1`: $`(1, \mathbf{e}_{23}, \mathbf{e}_{31}, \mathbf{e}_{12})`$
+/// - `p2`: $`(\mathbf{e}_{0123}, \mathbf{e}_{01}, \mathbf{e}_{02},\
+///   \mathbf{e}_{03})`$
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Motor {
     pub(crate) p1: f32x4,
@@ -7,6 +17,12 @@ pub struct Motor {
 }
 
 impl Motor {
+    /// The identity motor $`1`$, leaving every entity unchanged.
+    pub const IDENTITY: Self = Self {
+        p1: f32x4::from_const([1.0, 0.0, 0.0, 0.0]),
+        p2: f32x4::from_const([0.0; 4]),
+    };
+
     /// Direct initialization from components. A more common way of creating a
     /// motor is to take a product between a rotor and a translator.
     /// The arguments coorespond to the multivector
@@ -168,16 +184,13 @@ impl Motor {
 
         return out;
     }
+    */
 
     /// Convert this motor to a 4x4 column-major matrix representing this
     /// motor's action as a linear transformation.
-    [[nodiscard]] mat4x4 as_mat4x4() const noexcept
-    {
-        mat4x4 out;
-        mat4x4_12<true, false>(p1_, &p2_, out.cols);
-        return out;
+    pub fn as_mat4x4(self) -> Mat4x4 {
+        crate::matrix::mat4x4_12(self.p1, Some(self.p2))
     }
-    */
 
     /// Conjugates a plane $p$ with this motor and returns the result
     /// $mp\widetilde{m}$.
@@ -198,8 +211,8 @@ impl Motor {
     /// When applying a motor to a list of tightly packed planes, this
     /// routine will be *significantly faster* than applying the motor to
     /// each plane individually.
-    pub fn conj_planes(&self, input: impl Iterator<Item = Point>) -> impl Iterator<Item = Point> {
-        crate::arch::sw012(input.map(|d| d.p3), self.p1, Some(&self.p2)).map(|p3| Point { p3 })
+    pub fn conj_planes(&self, input: impl Iterator<Item = Plane>) -> impl Iterator<Item = Plane> {
+        crate::arch::sw012(input.map(|p| p.p0), self.p1, Some(&self.p2)).map(|p0| Plane { p0 })
     }
 
     /// Conjugates a line $`\ell`$ with this motor and returns the result
@@ -212,21 +225,61 @@ impl Motor {
             .unwrap()
     }
 
-    /*
     /// Conjugates an array of lines with this motor in the input array and
     /// stores the result in the output array. Aliasing is only permitted when
     /// `in == out` (in place motor application).
     ///
-    /// !!! tip
+    /// # tip
     ///
-    ///     When applying a motor to a list of tightly packed lines, this
-    ///     routine will be *significantly faster* than applying the motor to
-    ///     each line individually.
-    void KLN_VEC_CALL operator()(line* in, line* out, size_t count) const noexcept
-    {
-        detail::swMM<true, true, true>(&in->p1_, p1_, &p2_, &out->p1_, count);
+    /// When applying a motor to a list of tightly packed lines, this
+    /// routine will be *significantly faster* than applying the motor to
+    /// each line individually.
+    pub fn conj_lines(&self, input: impl Iterator<Item = Line>) -> impl Iterator<Item = Line> {
+        crate::arch::sw_mm22(input.map(|l| (l.p1, l.p2)), self.p1, Some(&self.p2))
+            .map(|(p1, p2)| Line { p1, p2 })
+    }
+
+    /// Conjugates a branch $b$ with this motor and returns the result
+    /// $`mb\widetilde{m}`$.
+    ///
+    /// The translational part of the motor moves the branch off the origin,
+    /// so the result is a general line.
+    pub fn conj_branch(&self, b: Branch) -> Line {
+        self.conj_line(Line::from_branch(b))
+    }
+
+    /// Conjugates an array of branches with this motor. The results are
+    /// general lines (see `conj_branch`).
+    pub fn conj_branches(&self, input: impl Iterator<Item = Branch>) -> impl Iterator<Item = Line> {
+        crate::arch::sw_mm22(
+            input.map(|b| (b.p1, f32x4::zero())),
+            self.p1,
+            Some(&self.p2),
+        )
+        .map(|(p1, p2)| Line { p1, p2 })
+    }
+
+    /// Conjugates an ideal line $\ell$ with this motor and returns the result
+    /// $`m\ell\widetilde{m}`$.
+    ///
+    /// The cost of this operation is the same as the application of a rotor due
+    /// to the translational invariance of lines at infinity.
+    pub fn conj_ideal_line(&self, l: IdealLine) -> IdealLine {
+        use core::iter::once;
+        let p2 = crate::arch::sw_mm11(once(l.p2), self.p1).next().unwrap();
+        IdealLine { p2 }
+    }
+
+    /// Conjugates an array of ideal lines with this motor.
+    ///
+    /// The cost of this operation is the same as the application of a rotor due
+    /// to the translational invariance of lines at infinity.
+    pub fn conj_ideal_lines(
+        &self,
+        input: impl Iterator<Item = IdealLine>,
+    ) -> impl Iterator<Item = IdealLine> {
+        crate::arch::sw_mm11(input.map(|l| l.p2), self.p1).map(|p2| IdealLine { p2 })
     }
-    */
 
     /// Conjugates a point $p$ with this motor and returns the result
     /// $mp\widetilde{m}$.
@@ -1,4 +1,4 @@
-use crate::{arch::f32x4, Branch, Dual, Line, Motor, Plane, Point, Rotor, Translator};
+use crate::{arch::f32x4, Branch, Dual, Flector, Line, Motor, Plane, Point, Rotor, Translator};
 
 macro_rules! impl_gp {
     (|$a:ident: $a_ty:ty, $b:ident: $b_ty:ty| -> $output:ty $body:block) => {
@@ -141,7 +141,66 @@ impl_gp!(|b: Motor, a: Translator| -> Motor {
 });
 
 /// Compose the action of two motors (`b` will be applied, then `a`)
-impl_gp!(|a: Motor, b: Motor| -> Motor { Motor::from(gp_mm(a.p1, a.p2, b.p1, b.p2)) });
+///
+/// The fused kernel is only used when the crate is built with AVX2 and FMA
+/// enabled, so the choice is made at compile time rather than on every product.
+impl_gp!(|a: Motor, b: Motor| -> Motor {
+    if cfg!(all(target_feature = "avx2", target_feature = "fma")) {
+        Motor::from(unsafe { crate::arch::gp_mm_fma(a.p1, a.p2, b.p1, b.p2) })
+    } else {
+        Motor::from(gp_mm(a.p1, a.p2, b.p1, b.p2))
+    }
+});
+
+// Odd versors. A plane or point times a motor is a flector and two flectors
+// compose into a motor.
+
+impl_gp!(|a: Plane, b: Motor| -> Flector {
+    let (p0, p3) = gp01(a.p0, b.p1);
+    let (q0, q3) = gp02(a.p0, b.p2);
+    Flector {
+        p0: p0 + q0,
+        p3: p3 + q3,
+    }
+});
+
+impl_gp!(|a: Motor, b: Plane| -> Flector {
+    let (p0, p3) = gp10(a.p1, b.p0);
+    let (q0, q3) = gp20(a.p2, b.p0);
+    Flector {
+        p0: p0 + q0,
+        p3: p3 + q3,
+    }
+});
+
+impl_gp!(|a: Point, b: Motor| -> Flector {
+    // a0 b0 e0 + a0 b1 e032 + a0 b2 e013 + a0 b3 e021 from the ideal part
+    let (p3, p0) = gp01(a.p3, b.p1);
+    let q = shuffle!(a.p3, [0, 0, 0, 0]) * b.p2;
+    Flector {
+        p0: p0.add0(q),
+        p3: p3 + q.blend_and(),
+    }
+});
+
+impl_gp!(|a: Motor, b: Point| -> Flector {
+    // -a0 b0 e0 - a1 b0 e032 - a2 b0 e013 - a3 b0 e021 from the ideal part
+    let (p3, p0) = gp10(a.p1, b.p3);
+    let q = a.p2 * shuffle!(b.p3, [0, 0, 0, 0]);
+    Flector {
+        p0: p0.sub0(q),
+        p3: p3 - q.blend_and(),
+    }
+});
+
+impl_gp!(|a: Flector, b: Motor| -> Flector { a.plane() * b + a.point() * b });
+impl_gp!(|a: Motor, b: Flector| -> Flector { a * b.plane() + a * b.point() });
+
+impl_gp!(|a: Flector, b: Plane| -> Motor { a.plane() * b + a.point() * b });
+impl_gp!(|a: Plane, b: Flector| -> Motor { a * b.plane() + a * b.point() });
+impl_gp!(|a: Flector, b: Point| -> Motor { a.plane() * b + Motor::from(gp33_full(a.p3, b.p3)) });
+impl_gp!(|a: Point, b: Flector| -> Motor { a * b.plane() + Motor::from(gp33_full(a.p3, b.p3)) });
+impl_gp!(|a: Flector, b: Flector| -> Motor { a * b.plane() + a * b.point() });
 
 // Define functions of the form gpAB where A and B are partition indices.
 // Each function so-defined computes the geometric product using vector intrinsics.
@@ -236,6 +295,87 @@ pub fn gp03_false(a: f32x4, b: f32x4) -> (f32x4, f32x4) {
     (p1, p2)
 }
 
+// (0, a3 b2 - a2 b3, a1 b3 - a3 b1, a2 b1 - a1 b2), the cross product term
+// shared by the products of vectors or trivectors with bivectors. The lowest
+// component cancels.
+fn cross_hi(a: f32x4, b: f32x4) -> f32x4 {
+    shuffle!(a, [2, 1, 3, 0]) * shuffle!(b, [1, 3, 2, 0])
+        - shuffle!(a, [1, 3, 2, 0]) * shuffle!(b, [2, 1, 3, 0])
+}
+
+// p0: (e0, e1, e2, e3)
+// p1: (1, e23, e31, e12)
+// p3: (e123, e032, e013, e021)
+//
+// Returns the partition of `a` first. A point in p3 times p1 has the same
+// coefficients with the two partitions exchanged.
+pub fn gp01(a: f32x4, b: f32x4) -> (f32x4, f32x4) {
+    // a0 b0 e0 +
+    // (a1 b0 + a3 b2 - a2 b3) e1 +
+    // (a2 b0 + a1 b3 - a3 b1) e2 +
+    // (a3 b0 + a2 b1 - a1 b2) e3 +
+    //
+    // (a1 b1 + a2 b2 + a3 b3) e123 +
+    // -a0 b1 e032 +
+    // -a0 b2 e013 +
+    // -a0 b3 e021
+
+    let p0 = a * shuffle!(b, [0, 0, 0, 0]) + cross_hi(a, b);
+    let p3 = f32x4::hi_dp(a, b) - (shuffle!(a, [0, 0, 0, 0]) * b).blend_and();
+    (p0, p3)
+}
+
+// p1: (1, e23, e31, e12)
+// p0: (e0, e1, e2, e3)
+// p3: (e123, e032, e013, e021)
+//
+// Returns the partition of `b` first, as with `gp01`.
+pub fn gp10(a: f32x4, b: f32x4) -> (f32x4, f32x4) {
+    // a0 b0 e0 +
+    // (a0 b1 + a3 b2 - a2 b3) e1 +
+    // (a0 b2 + a1 b3 - a3 b1) e2 +
+    // (a0 b3 + a2 b1 - a1 b2) e3 +
+    //
+    // (a1 b1 + a2 b2 + a3 b3) e123 +
+    // -a1 b0 e032 +
+    // -a2 b0 e013 +
+    // -a3 b0 e021
+
+    let p0 = b * shuffle!(a, [0, 0, 0, 0]) + cross_hi(a, b);
+    let p3 = f32x4::hi_dp(a, b) - (a * shuffle!(b, [0, 0, 0, 0])).blend_and();
+    (p0, p3)
+}
+
+// p0: (e0, e1, e2, e3)
+// p2: (e0123, e01, e02, e03)
+// p3: (e123, e032, e013, e021)
+pub fn gp02(a: f32x4, b: f32x4) -> (f32x4, f32x4) {
+    // -(a1 b1 + a2 b2 + a3 b3) e0 +
+    //
+    // (a1 b0 + a2 b3 - a3 b2) e032 +
+    // (a2 b0 + a3 b1 - a1 b3) e013 +
+    // (a3 b0 + a1 b2 - a2 b1) e021
+
+    let p0 = f32x4::hi_dp(a, b) ^ f32x4::set0(-0.0);
+    let p3 = (a * shuffle!(b, [0, 0, 0, 0])).blend_and() - cross_hi(a, b);
+    (p0, p3)
+}
+
+// p2: (e0123, e01, e02, e03)
+// p0: (e0, e1, e2, e3)
+// p3: (e123, e032, e013, e021)
+pub fn gp20(a: f32x4, b: f32x4) -> (f32x4, f32x4) {
+    // (a1 b1 + a2 b2 + a3 b3) e0 +
+    //
+    // (-a0 b1 + a3 b2 - a2 b3) e032 +
+    // (-a0 b2 + a1 b3 - a3 b1) e013 +
+    // (-a0 b3 + a2 b1 - a1 b2) e021
+
+    let p0 = f32x4::hi_dp(a, b);
+    let p3 = cross_hi(a, b) - (shuffle!(a, [0, 0, 0, 0]) * b).blend_and();
+    (p0, p3)
+}
+
 // p1: (1, e23, e31, e12)
 pub fn gp11(a: f32x4, b: f32x4) -> f32x4 {
     // (a0 b0 - a1 b1 - a2 b2 - a3 b3) +
@@ -278,6 +418,24 @@ pub fn gp33(a: f32x4, b: f32x4) -> f32x4 {
     (tmp * ss.copy_low_high(ss).rcp_nr1()).blend_and()
 }
 
+// p3: (e123, e032, e013, e021)
+// p1: (1, e23, e31, e12)
+// p2: (e0123, e01, e02, e03)
+//
+// Unlike `gp33` the product is not divided through to a translator.
+pub fn gp33_full(a: f32x4, b: f32x4) -> (f32x4, f32x4) {
+    // -a0 b0 +
+    // (a1 b0 - a0 b1) e01 +
+    // (a2 b0 - a0 b2) e02 +
+    // (a3 b0 - a0 b3) e03
+
+    let p1 = f32x4::zero().sub0(a * b);
+
+    // The lowest component cancels
+    let p2 = a * shuffle!(b, [0, 0, 0, 0]) - shuffle!(a, [0, 0, 0, 0]) * b;
+    (p1, p2)
+}
+
 pub fn gp_dl(u: f32, v: f32, b: f32x4, c: f32x4) -> (f32x4, f32x4) {
     // b1 u e23 +
     // b2 u e31 +
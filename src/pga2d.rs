@@ -0,0 +1,648 @@
+//! # Plane geometry
+//!
+//! The projective geometric algebra $`\mathbf{P}(\mathbb{R}^*_{2,0,1})`$ of
+//! the Euclidean plane, with the same conventions as the 3D types. The basis
+//! vectors $`\mathbf{e}_0`$, $`\mathbf{e}_1`$ and $`\mathbf{e}_2`$ square to
+//! $`0`$, $`1`$ and $`1`$.
+//!
+//! - A [`Line2`] $`a\mathbf{e}_1 + b\mathbf{e}_2 + c\mathbf{e}_0`$ is the
+//!   line $`ax + by + c = 0`$.
+//! - A [`Point2`] $`x\mathbf{e}_{20} + y\mathbf{e}_{01} + w\mathbf{e}_{12}`$
+//!   is the point $`(x/w, y/w)`$, or a direction when $`w = 0`$.
+//! - A [`Rotor2`] rotates about the origin, a [`Translator2`] translates and
+//!   a [`Motor2`] is any rigid motion of the plane.
+//!
+//! Points are bivectors, so they are also the generators of motions:
+//! [`Point2::exp`] gives the rotation about a point (or the translation
+//! orthogonal to a direction) and [`Motor2::log`] recovers it. The
+//! operators match the 3D types: `^` is the meet, `&` the join, `|` the
+//! inner product and `*` the geometric product.
+//!
+//! ```rust
+//! use klein::pga2d::{Line2, Motor2, Point2};
+//! use std::f32::consts::FRAC_PI_2;
+//!
+//! let a = Point2::new(1.0, 0.0);
+//! let b = Point2::new(0.0, 1.0);
+//! let line: Line2 = a & b;
+//! let x_axis = Line2::new(0.0, 1.0, 0.0);
+//! let p: Point2 = (line ^ x_axis).normalized();
+//! assert!((p.x() - 1.0).abs() < 1e-6 && p.y().abs() < 1e-6);
+//!
+//! // A quarter turn about (1, 1) takes (2, 1) to (1, 2).
+//! let m = Motor2::from_point(FRAC_PI_2, Point2::new(1.0, 1.0));
+//! let q = m.conj_point(Point2::new(2.0, 1.0));
+//! assert!((q.x() - 1.0).abs() < 1e-5 && (q.y() - 2.0).abs() < 1e-5);
+//! ```
+
+use crate::algebra;
+use core::ops::{BitAnd, BitOr, BitXor, Mul, Neg};
+
+/// Coefficients indexed by blade: 1, e0, e1, e01, e2, e02, e12, e012.
+type Mv = [f32; 8];
+
+const METRIC: [f32; 3] = [0.0, 1.0, 1.0];
+
+/// Rotation angles below this are treated as zero by `log`.
+const EPSILON: f32 = 1e-6;
+
+/// The line $`ax + by + c = 0`$.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Line2 {
+    pub(crate) e0: f32,
+    pub(crate) e1: f32,
+    pub(crate) e2: f32,
+}
+
+/// A point $`(x/w, y/w)`$ in homogeneous coordinates.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Point2 {
+    pub(crate) e20: f32,
+    pub(crate) e01: f32,
+    pub(crate) e12: f32,
+}
+
+/// A rotation about the origin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rotor2 {
+    pub(crate) scalar: f32,
+    pub(crate) e12: f32,
+}
+
+/// A translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Translator2 {
+    pub(crate) e01: f32,
+    pub(crate) e02: f32,
+}
+
+/// A rigid motion of the plane.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Motor2 {
+    pub(crate) scalar: f32,
+    pub(crate) e12: f32,
+    pub(crate) e01: f32,
+    pub(crate) e02: f32,
+}
+
+impl Line2 {
+    /// The line $`ax + by + c = 0`$.
+    pub fn new(a: f32, b: f32, c: f32) -> Self {
+        Self {
+            e0: c,
+            e1: a,
+            e2: b,
+        }
+    }
+
+    pub fn x(self) -> f32 {
+        self.e1
+    }
+
+    pub fn y(self) -> f32 {
+        self.e2
+    }
+
+    pub fn d(self) -> f32 {
+        self.e0
+    }
+
+    pub fn e0(self) -> f32 {
+        self.e0
+    }
+
+    pub fn e1(self) -> f32 {
+        self.e1
+    }
+
+    pub fn e2(self) -> f32 {
+        self.e2
+    }
+
+    /// The Euclidean norm $`\sqrt{a^2 + b^2}`$.
+    pub fn norm(self) -> f32 {
+        self.e1.hypot(self.e2)
+    }
+
+    /// Scale to unit norm, after which `d` is the signed distance of the
+    /// origin to the line.
+    pub fn normalized(self) -> Self {
+        self * self.norm().recip()
+    }
+
+    pub fn approx_eq(self, other: Self, epsilon: f32) -> bool {
+        (self.e0 - other.e0).abs() < epsilon
+            && (self.e1 - other.e1).abs() < epsilon
+            && (self.e2 - other.e2).abs() < epsilon
+    }
+
+    /// Reflect a line across this (normalized) line.
+    pub fn reflect_line(self, l: Line2) -> Line2 {
+        sandwich(self.mv(), l.mv(), false)
+    }
+
+    /// Reflect a point across this (normalized) line.
+    pub fn reflect_point(self, p: Point2) -> Point2 {
+        sandwich(self.mv(), p.mv(), true)
+    }
+}
+
+impl Point2 {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            e20: x,
+            e01: y,
+            e12: 1.0,
+        }
+    }
+
+    /// The point at infinity in the direction `(x, y)`.
+    pub fn direction(x: f32, y: f32) -> Self {
+        Self {
+            e20: x,
+            e01: y,
+            e12: 0.0,
+        }
+    }
+
+    pub fn x(self) -> f32 {
+        self.e20
+    }
+
+    pub fn y(self) -> f32 {
+        self.e01
+    }
+
+    pub fn w(self) -> f32 {
+        self.e12
+    }
+
+    pub fn e20(self) -> f32 {
+        self.e20
+    }
+
+    pub fn e01(self) -> f32 {
+        self.e01
+    }
+
+    pub fn e12(self) -> f32 {
+        self.e12
+    }
+
+    /// Divide through by the homogeneous weight so that `w` is one.
+    pub fn normalized(self) -> Self {
+        self * self.e12.recip()
+    }
+
+    pub fn approx_eq(self, other: Self, epsilon: f32) -> bool {
+        (self.e20 - other.e20).abs() < epsilon
+            && (self.e01 - other.e01).abs() < epsilon
+            && (self.e12 - other.e12).abs() < epsilon
+    }
+
+    /// Exponentiate this bivector. For a point of weight $`w`$ the result
+    /// rotates about the point by $`-2w`$ radians, for a direction it
+    /// translates orthogonally to it.
+    pub fn exp(self) -> Motor2 {
+        // The square of the bivector is the scalar -w^2.
+        let angle = self.e12.abs();
+        let (sin, cos) = angle.sin_cos();
+        let scale = if angle < EPSILON { 1.0 } else { sin / angle };
+        Motor2 {
+            scalar: cos,
+            e12: scale * self.e12,
+            e01: scale * self.e01,
+            e02: -scale * self.e20,
+        }
+    }
+}
+
+impl Rotor2 {
+    pub const IDENTITY: Self = Self {
+        scalar: 1.0,
+        e12: 0.0,
+    };
+
+    /// Counterclockwise rotation about the origin by `ang_rad`.
+    pub fn new(ang_rad: f32) -> Self {
+        let (sin, cos) = (-0.5 * ang_rad).sin_cos();
+        Self {
+            scalar: cos,
+            e12: sin,
+        }
+    }
+
+    pub fn scalar(self) -> f32 {
+        self.scalar
+    }
+
+    pub fn e12(self) -> f32 {
+        self.e12
+    }
+
+    pub fn reversed(self) -> Self {
+        Self {
+            scalar: self.scalar,
+            e12: -self.e12,
+        }
+    }
+
+    pub fn approx_eq(self, other: Self, epsilon: f32) -> bool {
+        Motor2::from(self).approx_eq(Motor2::from(other), epsilon)
+    }
+}
+
+impl Translator2 {
+    pub const IDENTITY: Self = Self { e01: 0.0, e02: 0.0 };
+
+    /// Translation by `delta` along the direction `(x, y)`. A zero direction
+    /// gives the identity.
+    pub fn new(delta: f32, x: f32, y: f32) -> Self {
+        let norm = x.hypot(y);
+        if norm == 0.0 {
+            return Self::IDENTITY;
+        }
+        let scale = -0.5 * delta / norm;
+        Self {
+            e01: scale * x,
+            e02: scale * y,
+        }
+    }
+
+    pub fn e01(self) -> f32 {
+        self.e01
+    }
+
+    pub fn e02(self) -> f32 {
+        self.e02
+    }
+
+    pub fn reversed(self) -> Self {
+        Self {
+            e01: -self.e01,
+            e02: -self.e02,
+        }
+    }
+
+    pub fn approx_eq(self, other: Self, epsilon: f32) -> bool {
+        Motor2::from(self).approx_eq(Motor2::from(other), epsilon)
+    }
+}
+
+impl Motor2 {
+    pub const IDENTITY: Self = Self {
+        scalar: 1.0,
+        e12: 0.0,
+        e01: 0.0,
+        e02: 0.0,
+    };
+
+    /// The motor $`a + b\mathbf{e}_{12} + c\mathbf{e}_{01} + d\mathbf{e}_{02}`$.
+    pub fn new(a: f32, b: f32, c: f32, d: f32) -> Self {
+        Self {
+            scalar: a,
+            e12: b,
+            e01: c,
+            e02: d,
+        }
+    }
+
+    /// Counterclockwise rotation by `ang_rad` about the point `p`.
+    pub fn from_point(ang_rad: f32, p: Point2) -> Self {
+        let p = p.normalized();
+        let (x, y) = (p.x(), p.y());
+        let t = Translator2::new(x.hypot(y), x, y);
+        t * Rotor2::new(ang_rad) * t.reversed()
+    }
+
+    pub fn from_rotor(r: Rotor2) -> Self {
+        r.into()
+    }
+
+    pub fn from_translator(t: Translator2) -> Self {
+        t.into()
+    }
+
+    pub fn scalar(self) -> f32 {
+        self.scalar
+    }
+
+    pub fn e12(self) -> f32 {
+        self.e12
+    }
+
+    pub fn e01(self) -> f32 {
+        self.e01
+    }
+
+    pub fn e02(self) -> f32 {
+        self.e02
+    }
+
+    /// The norm $`\sqrt{M\widetilde{M}}`$, which only depends on the
+    /// rotational part.
+    pub fn norm(self) -> f32 {
+        self.scalar.hypot(self.e12)
+    }
+
+    pub fn normalized(self) -> Self {
+        self * self.norm().recip()
+    }
+
+    pub fn reversed(self) -> Self {
+        Self {
+            scalar: self.scalar,
+            e12: -self.e12,
+            e01: -self.e01,
+            e02: -self.e02,
+        }
+    }
+
+    pub fn inverse(self) -> Self {
+        let norm = self.norm();
+        self.reversed() * (norm * norm).recip()
+    }
+
+    pub fn approx_eq(self, other: Self, epsilon: f32) -> bool {
+        (self.scalar - other.scalar).abs() < epsilon
+            && (self.e12 - other.e12).abs() < epsilon
+            && (self.e01 - other.e01).abs() < epsilon
+            && (self.e02 - other.e02).abs() < epsilon
+    }
+
+    /// The bivector whose exponential is this motor, see [`Point2::exp`].
+    pub fn log(self) -> Point2 {
+        let m = self.normalized();
+        let angle = m.e12.atan2(m.scalar);
+        let scale = if m.e12.abs() < EPSILON {
+            m.scalar.recip()
+        } else {
+            angle / m.e12
+        };
+        Point2 {
+            e20: -scale * m.e02,
+            e01: scale * m.e01,
+            e12: scale * m.e12,
+        }
+    }
+
+    /// The motor halfway between the identity and this one.
+    pub fn sqrt(self) -> Self {
+        let m = self.normalized();
+        Motor2 {
+            scalar: m.scalar + 1.0,
+            ..m
+        }
+        .normalized()
+    }
+}
+
+impl From<Rotor2> for Motor2 {
+    fn from(r: Rotor2) -> Self {
+        Self {
+            scalar: r.scalar,
+            e12: r.e12,
+            e01: 0.0,
+            e02: 0.0,
+        }
+    }
+}
+
+impl From<Translator2> for Motor2 {
+    fn from(t: Translator2) -> Self {
+        Self {
+            scalar: 1.0,
+            e12: 0.0,
+            e01: t.e01,
+            e02: t.e02,
+        }
+    }
+}
+
+/// Conversion to and from dense multivectors.
+trait Blades: Copy {
+    fn mv(self) -> Mv;
+    fn from_mv(mv: &Mv) -> Self;
+}
+
+impl Blades for f32 {
+    fn mv(self) -> Mv {
+        [self, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]
+    }
+
+    fn from_mv(mv: &Mv) -> Self {
+        mv[0]
+    }
+}
+
+impl Blades for Line2 {
+    fn mv(self) -> Mv {
+        [0.0, self.e0, self.e1, 0.0, self.e2, 0.0, 0.0, 0.0]
+    }
+
+    fn from_mv(mv: &Mv) -> Self {
+        Self {
+            e0: mv[1],
+            e1: mv[2],
+            e2: mv[4],
+        }
+    }
+}
+
+impl Blades for Point2 {
+    fn mv(self) -> Mv {
+        [0.0, 0.0, 0.0, self.e01, 0.0, -self.e20, self.e12, 0.0]
+    }
+
+    fn from_mv(mv: &Mv) -> Self {
+        Self {
+            e20: -mv[5],
+            e01: mv[3],
+            e12: mv[6],
+        }
+    }
+}
+
+impl Blades for Rotor2 {
+    fn mv(self) -> Mv {
+        Motor2::from(self).mv()
+    }
+
+    fn from_mv(mv: &Mv) -> Self {
+        Self {
+            scalar: mv[0],
+            e12: mv[6],
+        }
+    }
+}
+
+impl Blades for Translator2 {
+    fn mv(self) -> Mv {
+        Motor2::from(self).mv()
+    }
+
+    fn from_mv(mv: &Mv) -> Self {
+        Self {
+            e01: mv[3],
+            e02: mv[5],
+        }
+    }
+}
+
+impl Blades for Motor2 {
+    fn mv(self) -> Mv {
+        [
+            self.scalar,
+            0.0,
+            0.0,
+            self.e01,
+            0.0,
+            self.e02,
+            self.e12,
+            0.0,
+        ]
+    }
+
+    fn from_mv(mv: &Mv) -> Self {
+        Self {
+            scalar: mv[0],
+            e12: mv[6],
+            e01: mv[3],
+            e02: mv[5],
+        }
+    }
+}
+
+/// $`A X \widetilde{A}`$, negated when `odd` so that reflecting a point in a
+/// line keeps its weight.
+fn sandwich<T: Blades>(a: Mv, x: Mv, odd: bool) -> T {
+    let mut reversed = a;
+    algebra::reverse(&mut reversed);
+    let (mut ax, mut out) = ([0.0; 8], [0.0; 8]);
+    algebra::gp(&a, &x, &METRIC, &mut ax);
+    algebra::gp(&ax, &reversed, &METRIC, &mut out);
+    if odd {
+        for c in out.iter_mut() {
+            *c = -*c;
+        }
+    }
+    T::from_mv(&out)
+}
+
+macro_rules! binary {
+    ($op:ident, $fn:ident, $product:expr, $($lhs:ident, $rhs:ident => $out:ident;)+) => {
+        $(
+            impl $op<$rhs> for $lhs {
+                type Output = $out;
+
+                #[inline]
+                fn $fn(self, other: $rhs) -> $out {
+                    let mut out = [0.0; 8];
+                    $product(&self.mv(), &other.mv(), &mut out);
+                    $out::from_mv(&out)
+                }
+            }
+        )+
+    };
+}
+
+fn gp(a: &Mv, b: &Mv, out: &mut Mv) {
+    algebra::gp(a, b, &METRIC, out)
+}
+
+fn inner(a: &Mv, b: &Mv, out: &mut Mv) {
+    algebra::inner(a, b, &METRIC, out)
+}
+
+binary! { BitXor, bitxor, algebra::outer,
+    Line2, Line2 => Point2;
+}
+
+binary! { BitAnd, bitand, algebra::regressive,
+    Point2, Point2 => Line2;
+}
+
+binary! { BitOr, bitor, inner,
+    Line2, Line2 => f32;
+    Point2, Point2 => f32;
+    Point2, Line2 => Line2;
+    Line2, Point2 => Line2;
+}
+
+binary! { Mul, mul, gp,
+    Line2, Line2 => Motor2;
+    Point2, Point2 => Motor2;
+    Rotor2, Rotor2 => Rotor2;
+    Translator2, Translator2 => Translator2;
+    Rotor2, Translator2 => Motor2;
+    Translator2, Rotor2 => Motor2;
+    Motor2, Motor2 => Motor2;
+    Motor2, Rotor2 => Motor2;
+    Rotor2, Motor2 => Motor2;
+    Motor2, Translator2 => Motor2;
+    Translator2, Motor2 => Motor2;
+}
+
+macro_rules! scale {
+    ($($ty:ident { $($field:ident),+ })+) => {
+        $(
+            impl Mul<f32> for $ty {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, s: f32) -> Self {
+                    Self { $($field: self.$field * s),+ }
+                }
+            }
+
+            impl Neg for $ty {
+                type Output = Self;
+
+                #[inline]
+                fn neg(self) -> Self {
+                    self * -1.0
+                }
+            }
+        )+
+    };
+}
+
+scale! {
+    Line2 { e0, e1, e2 }
+    Point2 { e20, e01, e12 }
+    Motor2 { scalar, e12, e01, e02 }
+}
+
+macro_rules! conj {
+    ($($ty:ident)+) => {
+        $(
+            impl $ty {
+                /// Apply to a line, assuming this is normalized.
+                pub fn conj_line(&self, l: Line2) -> Line2 {
+                    sandwich(self.mv(), l.mv(), false)
+                }
+
+                pub fn conj_lines<'a>(
+                    &'a self,
+                    input: impl Iterator<Item = Line2> + 'a,
+                ) -> impl Iterator<Item = Line2> + 'a {
+                    input.map(move |l| self.conj_line(l))
+                }
+
+                /// Apply to a point, assuming this is normalized.
+                pub fn conj_point(&self, p: Point2) -> Point2 {
+                    sandwich(self.mv(), p.mv(), false)
+                }
+
+                pub fn conj_points<'a>(
+                    &'a self,
+                    input: impl Iterator<Item = Point2> + 'a,
+                ) -> impl Iterator<Item = Point2> + 'a {
+                    input.map(move |p| self.conj_point(p))
+                }
+            }
+        )+
+    };
+}
+
+conj! { Rotor2 Translator2 Motor2 }
@@ -11,12 +11,22 @@
 
 use super::{arch::f32x4, Line, Point};
 
+/// # Memory layout
+///
+/// A single 16-byte aligned register holding $`(\mathbf{e}_0, \mathbf{e}_1,\
+/// \mathbf{e}_2, \mathbf{e}_3)`$, i.e. `(d, a, b, c)`, lowest address first.
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Plane {
     pub(crate) p0: f32x4,
 }
 
 impl Plane {
+    /// The zero plane.
+    pub const ZERO: Self = Self {
+        p0: f32x4::from_const([0.0; 4]),
+    };
+
     pub fn new(a: f32, b: f32, c: f32, d: f32) -> Self {
         Self::from(f32x4::new(c, b, a, d))
     }
@@ -41,10 +51,9 @@ impl Plane {
     /// normalized rotor between two planes with the geometric product `*` also
     /// requires that the planes are normalized.
     pub fn normalize(&mut self) {
-        let inv_norm = f32x4::hi_dp_bc(self.p0, self.p0)
-            .rsqrt_nr1()
-            .blend1(f32x4::set0(1.0));
-
+        // The distance `d` is scaled along with the normal, so the plane
+        // itself is unchanged.
+        let inv_norm = f32x4::hi_dp_bc(self.p0, self.p0).rsqrt_nr1();
         self.p0 *= inv_norm;
     }
 
@@ -100,10 +109,20 @@ impl Plane {
         Line::from((p1, p2))
     }
 
+    /// Reflect an array of lines through this plane.
+    pub fn reflect_lines(self, input: impl Iterator<Item = Line>) -> impl Iterator<Item = Line> {
+        input.map(move |l| self.reflect_line(l))
+    }
+
     /// Reflect the point $P$ through this plane $p$. The operation
     /// performed via this call operator is an optimized routine equivalent to
     /// the expression $p P p$.
     pub fn reflect_point(self, p: Point) -> Point {
         Point::from(crate::arch::sw30(self.p0, p.p3))
     }
+
+    /// Reflect an array of points through this plane.
+    pub fn reflect_points(self, input: impl Iterator<Item = Point>) -> impl Iterator<Item = Point> {
+        input.map(move |p| self.reflect_point(p))
+    }
 }
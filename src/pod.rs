@@ -0,0 +1,89 @@
+//! `bytemuck` support (enabled with the `bytemuck` feature).
+//!
+//! All entities are `#[repr(C)]` wrappers around one or two 16-byte aligned
+//! SSE registers, so they can be cast to and from raw floats and bytes without
+//! copying. The float order within each register follows the partition
+//! layouts documented on each type:
+//!
+//! ```text
+//!     LSB --> MSB
+//! p0: (e0, e1, e2, e3)            Plane
+//! p1: (1, e23, e31, e12)          Rotor, Branch, Motor/Line (first half)
+//! p2: (e0123, e01, e02, e03)      Translator, IdealLine, Motor/Line (second half)
+//! p3: (e123, e032, e013, e021)    Point, Direction
+//! ```
+//!
+//! An array of motors can therefore be uploaded directly to a storage buffer
+//! declared in a shader as an array of `vec4` pairs:
+//!
+//! ```ignore
+//! let motors: Vec<Motor> = ...;
+//! queue.write_buffer(&buffer, 0, Motor::as_bytes(&motors));
+//! ```
+
+use crate::{
+    arch::f32x4, Branch, Direction, Dual, Flector, IdealLine, Line, Mat3x4, Mat4x4, Motor, Plane,
+    Point, Rotor, Translator,
+};
+use bytemuck::{Pod, PodCastError, Zeroable};
+
+unsafe impl Zeroable for f32x4 {}
+unsafe impl Pod for f32x4 {}
+
+unsafe impl Zeroable for Dual {}
+unsafe impl Pod for Dual {}
+
+unsafe impl Zeroable for Mat3x4 {}
+unsafe impl Pod for Mat3x4 {}
+
+unsafe impl Zeroable for Mat4x4 {}
+unsafe impl Pod for Mat4x4 {}
+
+macro_rules! impl_pod {
+    ($($ty:ident),+) => {$(
+        unsafe impl Zeroable for $ty {}
+        unsafe impl Pod for $ty {}
+
+        impl $ty {
+            /// Reinterpret a slice of entities as their raw float components.
+            #[inline]
+            pub fn as_floats(slice: &[Self]) -> &[f32] {
+                bytemuck::cast_slice(slice)
+            }
+
+            /// Reinterpret a mutable slice of entities as their raw float
+            /// components.
+            #[inline]
+            pub fn as_floats_mut(slice: &mut [Self]) -> &mut [f32] {
+                bytemuck::cast_slice_mut(slice)
+            }
+
+            /// Reinterpret a slice of entities as raw bytes, e.g. for upload
+            /// to a GPU buffer.
+            #[inline]
+            pub fn as_bytes(slice: &[Self]) -> &[u8] {
+                bytemuck::cast_slice(slice)
+            }
+
+            /// Reinterpret raw floats as a slice of entities.
+            ///
+            /// Fails if `floats` is not 16-byte aligned or its length is not
+            /// a multiple of the entity size.
+            #[inline]
+            pub fn from_floats(floats: &[f32]) -> Result<&[Self], PodCastError> {
+                bytemuck::try_cast_slice(floats)
+            }
+
+            /// Reinterpret raw bytes as a slice of entities.
+            ///
+            /// Fails if `bytes` is not 16-byte aligned or its length is not
+            /// a multiple of the entity size.
+            #[inline]
+            pub fn from_bytes(bytes: &[u8]) -> Result<&[Self], PodCastError> {
+                bytemuck::try_cast_slice(bytes)
+            }
+        }
+    )+};
+}
+
+impl_pod!(Plane, Point, Direction, Branch, IdealLine, Line, Rotor, Translator, Motor, Flector);
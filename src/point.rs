@@ -3,7 +3,7 @@ use crate::arch::f32x4;
 /// The origin is a convenience type that occupies no memory but is castable to
 /// a point entity. Several operations like conjugation of the origin by a motor
 /// is optimized.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 pub struct Origin(pub(crate) Point);
 
 impl Origin {
@@ -16,12 +16,23 @@ impl Origin {
     }
 }
 
+/// # Memory layout
+///
+/// A single 16-byte aligned register holding $`(\mathbf{e}_{123},\
+/// \mathbf{e}_{032}, \mathbf{e}_{013}, \mathbf{e}_{021})`$, i.e.
+/// `(w, x, y, z)`, lowest address first.
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Point {
     pub(crate) p3: f32x4,
 }
 
 impl Point {
+    /// The origin $`\mathbf{e}_{123}`$.
+    pub const ORIGIN: Self = Self {
+        p3: f32x4::from_const([1.0, 0.0, 0.0, 0.0]),
+    };
+
     /// Component-wise constructor (homogeneous coordinate is automatically
     /// initialized to 1)
     #[inline]
@@ -0,0 +1,265 @@
+//! # Convex polytopes
+//!
+//! A [`ConvexPolytope`] is the intersection of the half spaces behind a set
+//! of planes. Plane normals point *outwards*: a point is inside when
+//! $`d + ax + by + cz \le 0`$ for every plane, which is the sign of the
+//! pseudoscalar $`p \wedge P`$ for a normalized point $`P`$.
+//!
+//! Vertices are the meets $`p_i \wedge p_j \wedge p_k`$ of plane triples
+//! that lie on the polytope, and edges are the meets of plane pairs clipped
+//! to it. Polygons are clipped plane by plane with [`clip_polygon`].
+//!
+//! ```rust
+//! use klein::polytope::ConvexPolytope;
+//! use klein::volume::Aabb;
+//! use klein::Point;
+//!
+//! let cube = ConvexPolytope::from_aabb(&Aabb::new([-1.0; 3], [1.0; 3]));
+//! assert!(cube.contains_point(Point::new(0.5, 0.5, -0.5)));
+//! assert_eq!(cube.vertices().len(), 8);
+//! assert_eq!(cube.edges().len(), 12);
+//! ```
+
+use crate::{volume::Aabb, Line, Motor, Plane, Point};
+
+/// Distance within which points count as lying on a plane.
+const EPSILON: f32 = 1e-4;
+
+/// Rate of change of the distance below which a line is parallel to a plane.
+const PARALLEL: f32 = 1e-6;
+
+/// A convex region bounded by outward facing planes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConvexPolytope {
+    /// Normalized bounding planes.
+    pub planes: Vec<Plane>,
+}
+
+impl ConvexPolytope {
+    /// The polytope bounded by `planes`, which are normalized.
+    pub fn new(planes: impl IntoIterator<Item = Plane>) -> Self {
+        Self {
+            planes: planes.into_iter().map(Plane::normalized).collect(),
+        }
+    }
+
+    pub fn from_aabb(aabb: &Aabb) -> Self {
+        let ([x0, y0, z0], [x1, y1, z1]) = (aabb.min, aabb.max);
+        Self::new(vec![
+            Plane::new(-1.0, 0.0, 0.0, x0),
+            Plane::new(1.0, 0.0, 0.0, -x1),
+            Plane::new(0.0, -1.0, 0.0, y0),
+            Plane::new(0.0, 1.0, 0.0, -y1),
+            Plane::new(0.0, 0.0, -1.0, z0),
+            Plane::new(0.0, 0.0, 1.0, -z1),
+        ])
+    }
+
+    /// Signed distance from `p` to each plane, positive outside.
+    pub fn distances(&self, p: Point) -> impl Iterator<Item = f32> + '_ {
+        self.planes.iter().map(move |&plane| side(plane, p))
+    }
+
+    /// Whether `p` lies inside or on the boundary.
+    pub fn contains_point(&self, p: Point) -> bool {
+        self.distances(p).all(|d| d <= EPSILON)
+    }
+
+    /// The part of the segment `ab` inside the polytope.
+    pub fn clip_segment(&self, a: Point, b: Point) -> Option<(Point, Point)> {
+        let (a, b) = (a.normalized(), b.normalized());
+        let (t0, t1) = self.clip_interval(|plane| (side(plane, a), side(plane, b)), 0.0, 1.0)?;
+        Some((lerp(a, b, t0), lerp(a, b, t1)))
+    }
+
+    /// The part of the line `l` inside the polytope, ordered along the
+    /// line. Returns `None` if the line misses the polytope or the polytope
+    /// is unbounded along it.
+    pub fn clip_line(&self, l: Line) -> Option<(Point, Point)> {
+        let (x, y, z) = (l.e23(), l.e31(), l.e12());
+        if x == 0.0 && y == 0.0 && z == 0.0 {
+            return None;
+        }
+        // Two points a unit of the line's direction apart.
+        let a = (Plane::new(x, y, z, 0.0) ^ l).normalized();
+        let b = Point::new(a.x() + x, a.y() + y, a.z() + z);
+        let (t0, t1) = self.clip_interval(
+            |plane| (side(plane, a), side(plane, b)),
+            f32::NEG_INFINITY,
+            f32::INFINITY,
+        )?;
+        if !(t0.is_finite() && t1.is_finite()) {
+            return None;
+        }
+        Some((lerp(a, b, t0), lerp(a, b, t1)))
+    }
+
+    /// Vertices of the polytope, found as the meets of every triple of
+    /// planes that lie on the polytope. Vertices shared by more than three
+    /// planes are reported once.
+    pub fn vertices(&self) -> Vec<Point> {
+        let n = self.planes.len();
+        let mut out: Vec<Point> = Vec::new();
+        for i in 0..n {
+            for j in i + 1..n {
+                let line = self.planes[i] ^ self.planes[j];
+                for k in j + 1..n {
+                    let p = line ^ self.planes[k];
+                    if p.w().abs() < EPSILON {
+                        continue;
+                    }
+                    let p = p.normalized();
+                    if self.contains_point(p) && !out.iter().any(|&q| close(p, q)) {
+                        out.push(p);
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// Edges of the polytope as pairs of end points, found by clipping the
+    /// meet of every pair of planes to the polytope.
+    pub fn edges(&self) -> Vec<(Point, Point)> {
+        let n = self.planes.len();
+        let mut out: Vec<(Point, Point)> = Vec::new();
+        for i in 0..n {
+            for j in i + 1..n {
+                let line = self.planes[i] ^ self.planes[j];
+                if let Some((a, b)) = self.clip_line(line) {
+                    let duplicate = out.iter().any(|&(c, d)| {
+                        (close(a, c) && close(b, d)) || (close(a, d) && close(b, c))
+                    });
+                    if !close(a, b) && !duplicate {
+                        out.push((a, b));
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// Clip the polygon with vertices `polygon` against every bounding plane
+    /// in turn, see [`clip_polygon`].
+    pub fn clip_polygon(&self, polygon: &[Point]) -> Vec<Point> {
+        let mut out = polygon.to_vec();
+        for &plane in &self.planes {
+            if out.is_empty() {
+                break;
+            }
+            out = clip_polygon(&out, plane);
+        }
+        out
+    }
+
+    /// Parameter range `[t0, t1]` within `[lo, hi]` of the points
+    /// `a + t (b - a)` inside the polytope, given the signed distances of `a`
+    /// and `b` to each plane.
+    fn clip_interval(
+        &self,
+        distances: impl Fn(Plane) -> (f32, f32),
+        lo: f32,
+        hi: f32,
+    ) -> Option<(f32, f32)> {
+        let (mut t0, mut t1) = (lo, hi);
+        for &plane in &self.planes {
+            let (da, db) = distances(plane);
+            let slope = db - da;
+            // Parallel to the plane, including lines lying on it.
+            if slope.abs() < PARALLEL {
+                if da > EPSILON {
+                    return None;
+                }
+                continue;
+            }
+            let t = -da / slope;
+            if slope > 0.0 {
+                t1 = t1.min(t);
+            } else {
+                t0 = t0.max(t);
+            }
+            if t0 > t1 {
+                return None;
+            }
+        }
+        Some((t0, t1))
+    }
+}
+
+/// Clip a polygon to the half space behind `plane` (where
+/// $`d + ax + by + cz \le 0`$) with the Sutherland–Hodgman algorithm.
+///
+/// Each edge crossing the plane is replaced by the meet of the plane with the
+/// edge line $`P \vee Q`$. Vertices may carry any nonzero homogeneous weight,
+/// including negative ones; the returned vertices are normalized. An empty
+/// vector means the polygon lies entirely in front of the plane.
+///
+/// ```rust
+/// use klein::polytope::clip_polygon;
+/// use klein::{Plane, Point};
+///
+/// let square = [
+///     Point::new(0.0, 0.0, 0.0),
+///     Point::new(2.0, 0.0, 0.0),
+///     Point::new(2.0, 2.0, 0.0),
+///     Point::new(0.0, 2.0, 0.0),
+/// ];
+/// // Keep the part with x <= 1.
+/// let clipped = clip_polygon(&square, Plane::new(1.0, 0.0, 0.0, -1.0));
+/// assert_eq!(clipped.len(), 4);
+/// assert!(clipped.iter().all(|p| p.x() <= 1.0 + 1e-6));
+/// ```
+pub fn clip_polygon(polygon: &[Point], plane: Plane) -> Vec<Point> {
+    let mut out = Vec::with_capacity(polygon.len() + 1);
+    let previous = match polygon.last() {
+        Some(&p) => p,
+        None => return out,
+    };
+
+    let (mut p, mut dp) = (previous, side(plane, previous));
+    for &q in polygon {
+        let dq = side(plane, q);
+        let (p_in, q_in) = (dp <= 0.0, dq <= 0.0);
+        if p_in != q_in {
+            // The endpoints are on opposite sides, so the edge line is not
+            // parallel to the plane and the meet is a finite point.
+            out.push((plane ^ (p & q)).normalized());
+        }
+        if q_in {
+            out.push(q.normalized());
+        }
+        p = q;
+        dp = dq;
+    }
+    out
+}
+
+impl Motor {
+    /// Conjugates every bounding plane of `polytope` with this motor.
+    pub fn conj_polytope(&self, polytope: &ConvexPolytope) -> ConvexPolytope {
+        ConvexPolytope {
+            planes: self.conj_planes(polytope.planes.iter().copied()).collect(),
+        }
+    }
+}
+
+/// Signed distance from `p` to `plane` (scaled by the norm of the plane),
+/// from the pseudoscalar of their meet.
+pub(crate) fn side(plane: Plane, p: Point) -> f32 {
+    (plane ^ p).e0123() / p.w()
+}
+
+fn lerp(a: Point, b: Point, t: f32) -> Point {
+    let s = 1.0 - t;
+    Point::new(
+        s * a.x() + t * b.x(),
+        s * a.y() + t * b.y(),
+        s * a.z() + t * b.z(),
+    )
+}
+
+fn close(a: Point, b: Point) -> bool {
+    (a.x() - b.x()).abs() < EPSILON
+        && (a.y() - b.y()).abs() < EPSILON
+        && (a.z() - b.z()).abs() < EPSILON
+}
@@ -0,0 +1,119 @@
+//! Random sampling (enabled with the `rand` feature).
+//!
+//! The [`Standard`] distribution produces uniformly distributed rotors and
+//! directions:
+//!
+//! ```ignore
+//! use rand::Rng;
+//! use klein::{Direction, Rotor};
+//!
+//! let mut rng = rand::thread_rng();
+//! let r: Rotor = rng.gen();
+//! let d: Direction = rng.gen();
+//! ```
+//!
+//! Entities with a position (points, planes, lines and motors) are sampled
+//! from a [`Region`] instead. Every sampled entity is normalized.
+
+use crate::{arch::f32x4, Branch, Direction, Line, Motor, Plane, Point, Rotor, Translator};
+use core::f32::consts::PI;
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+/// Uniform rotation on SO(3), using Shoemake's method of sampling a unit
+/// quaternion uniformly on the 3-sphere.
+impl Distribution<Rotor> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rotor {
+        let u1: f32 = rng.gen();
+        let (s2, c2) = (2.0 * PI * rng.gen::<f32>()).sin_cos();
+        let (s3, c3) = (2.0 * PI * rng.gen::<f32>()).sin_cos();
+
+        let a = (1.0 - u1).sqrt();
+        let b = u1.sqrt();
+        Rotor::load_normalized([a * s2, a * c2, b * s3, b * c3])
+    }
+}
+
+/// Uniform direction on the unit sphere.
+impl Distribution<Direction> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Direction {
+        unit_vector(rng)
+    }
+}
+
+/// An axis-aligned box to sample positions from.
+///
+/// - `Point`: uniform in the box.
+/// - `Motor`: uniform rotation followed by a translation to a uniform point
+///   in the box.
+/// - `Plane`: uniform normal, through a uniform point in the box.
+/// - `Line`: uniform direction, through a uniform point in the box.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Region {
+    min: [f32; 3],
+    max: [f32; 3],
+}
+
+impl Region {
+    /// The box spanned by the corners `min` and `max`. A degenerate extent
+    /// (`min[i] == max[i]`) is allowed and fixes that coordinate.
+    pub fn new(min: [f32; 3], max: [f32; 3]) -> Self {
+        Self { min, max }
+    }
+
+    /// The cube $`[-h, h]^3`$ centered at the origin.
+    pub fn cube(half_extent: f32) -> Self {
+        let h = half_extent;
+        Self::new([-h, -h, -h], [h, h, h])
+    }
+
+    fn position<R: Rng + ?Sized>(&self, rng: &mut R) -> [f32; 3] {
+        let mut out = [0.0; 3];
+        for (i, x) in out.iter_mut().enumerate() {
+            *x = self.min[i] + (self.max[i] - self.min[i]) * rng.gen::<f32>();
+        }
+        out
+    }
+}
+
+impl Distribution<Point> for Region {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point {
+        let [x, y, z] = self.position(rng);
+        Point::new(x, y, z)
+    }
+}
+
+impl Distribution<Motor> for Region {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Motor {
+        let r: Rotor = rng.gen();
+        Translator::from_offset(self.position(rng)) * r
+    }
+}
+
+impl Distribution<Plane> for Region {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Plane {
+        let n = unit_vector(rng);
+        let [x, y, z] = self.position(rng);
+        let d = -(n.x() * x + n.y() * y + n.z() * z);
+        Plane::new(n.x(), n.y(), n.z(), d)
+    }
+}
+
+impl Distribution<Line> for Region {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Line {
+        // A normalized line through the origin moved to the sampled point.
+        let u = unit_vector(rng);
+        let b = Branch::new(u.x(), u.y(), u.z());
+        let l = Line::from((b.p1, f32x4::zero()));
+        Translator::from_offset(self.position(rng)).conj_line(l)
+    }
+}
+
+fn unit_vector<R: Rng + ?Sized>(rng: &mut R) -> Direction {
+    let z = 2.0 * rng.gen::<f32>() - 1.0;
+    let (s, c) = (2.0 * PI * rng.gen::<f32>()).sin_cos();
+    let r = (1.0 - z * z).max(0.0).sqrt();
+    Direction::from(f32x4::new(z, r * s, r * c, 0.0))
+}
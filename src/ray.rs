@@ -0,0 +1,231 @@
+//! # Ray casting
+//!
+//! A [`Ray`] is a half line starting at a point. Every query returns the
+//! nearest [`Hit`] in front of the origin, if any, with the distance along
+//! the ray, the hit point and the tangent plane at the hit. Normals of
+//! volumes point outwards, normals of planes and triangles face the ray.
+//!
+//! ```rust
+//! use klein::ray::Ray;
+//! use klein::{Direction, Plane, Point};
+//!
+//! let ray = Ray::new(Point::new(0.0, 0.0, 5.0), Direction::new(0.0, 0.0, -1.0));
+//! let hit = ray.intersect_plane(Plane::new(0.0, 0.0, 1.0, -1.0)).unwrap();
+//! assert!((hit.distance - 4.0).abs() < 1e-5);
+//! assert!((hit.point.z() - 1.0).abs() < 1e-5);
+//! ```
+
+use crate::{
+    volume::{Aabb, Obb, Sphere},
+    Direction, Line, Plane, Point,
+};
+
+/// Homogeneous weights below this are treated as points at infinity.
+const EPSILON: f32 = 1e-7;
+
+/// A half line with a normalized origin and a unit direction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray {
+    pub origin: Point,
+    pub direction: Direction,
+}
+
+/// The result of a ray query.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hit {
+    /// Distance from the ray origin.
+    pub distance: f32,
+    pub point: Point,
+    /// Normalized tangent plane at `point`.
+    pub normal: Plane,
+}
+
+impl Ray {
+    pub fn new(origin: Point, direction: Direction) -> Self {
+        Self {
+            origin: origin.normalized(),
+            direction: direction.normalized(),
+        }
+    }
+
+    /// The ray along the oriented line `l`, starting at the point of the
+    /// line closest to the world origin.
+    pub fn from_line(l: Line) -> Self {
+        let (x, y, z) = (l.e23(), l.e31(), l.e12());
+        // The plane through the origin orthogonal to the line meets it at the
+        // closest point.
+        let origin = Plane::new(x, y, z, 0.0) ^ l;
+        Self::new(origin, Direction::new(x, y, z))
+    }
+
+    /// The normalized line carrying the ray, oriented along its direction.
+    pub fn line(&self) -> Line {
+        (self.origin & self.at(1.0)).normalized()
+    }
+
+    /// The point at distance `t` along the ray.
+    pub fn at(&self, t: f32) -> Point {
+        let (o, d) = (self.origin, self.direction);
+        Point::new(o.x() + t * d.x(), o.y() + t * d.y(), o.z() + t * d.z())
+    }
+
+    /// Intersect with a plane. Rays parallel to the plane never hit it.
+    pub fn intersect_plane(&self, plane: Plane) -> Option<Hit> {
+        let p = plane ^ self.line();
+        let w = p.w();
+        if w.abs() < EPSILON {
+            return None;
+        }
+        let point = p.normalized();
+        let t = self.distance_to(point);
+        if t < 0.0 {
+            return None;
+        }
+
+        let (a, b, c) = (plane.x(), plane.y(), plane.z());
+        let facing = a * self.direction.x() + b * self.direction.y() + c * self.direction.z();
+        let plane = plane.normalized();
+        let normal = if facing > 0.0 { plane * -1.0 } else { plane };
+        Some(Hit {
+            distance: t,
+            point,
+            normal,
+        })
+    }
+
+    /// Intersect with the triangle `abc`, from either side.
+    pub fn intersect_triangle(&self, a: Point, b: Point, c: Point) -> Option<Hit> {
+        let plane = a & b & c;
+        if plane.x() == 0.0 && plane.y() == 0.0 && plane.z() == 0.0 {
+            // Degenerate triangle.
+            return None;
+        }
+        let hit = self.intersect_plane(plane)?;
+
+        // The hit is inside when each edge turns the same way as the
+        // triangle around it.
+        let p = hit.point;
+        let inside = [(a, b), (b, c), (c, a)].iter().all(|&(u, v)| {
+            let side = u & v & p;
+            side.x() * plane.x() + side.y() * plane.y() + side.z() * plane.z() >= 0.0
+        });
+        if inside {
+            Some(hit)
+        } else {
+            None
+        }
+    }
+
+    pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<Hit> {
+        let c = sphere.center;
+        let (o, d) = (self.origin, self.direction);
+        let oc = [o.x() - c.x(), o.y() - c.y(), o.z() - c.z()];
+        let b = oc[0] * d.x() + oc[1] * d.y() + oc[2] * d.z();
+        let k = oc[0] * oc[0] + oc[1] * oc[1] + oc[2] * oc[2] - sphere.radius * sphere.radius;
+        let discriminant = b * b - k;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        // Take the exit point when starting inside the sphere.
+        let s = discriminant.sqrt();
+        let t = if -b - s >= 0.0 { -b - s } else { -b + s };
+        if t < 0.0 {
+            return None;
+        }
+
+        let point = self.at(t);
+        let n = [point.x() - c.x(), point.y() - c.y(), point.z() - c.z()];
+        Some(Hit {
+            distance: t,
+            point,
+            normal: tangent(point, n),
+        })
+    }
+
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<Hit> {
+        let o = [self.origin.x(), self.origin.y(), self.origin.z()];
+        let d = [self.direction.x(), self.direction.y(), self.direction.z()];
+        let (t, n) = slabs(o, d, aabb.min, aabb.max)?;
+        let point = self.at(t);
+        Some(Hit {
+            distance: t,
+            point,
+            normal: tangent(point, n),
+        })
+    }
+
+    /// Intersect with an oriented box by casting the ray in the box frame.
+    pub fn intersect_obb(&self, obb: &Obb) -> Option<Hit> {
+        let to_box = obb.motor.reversed();
+        let o = to_box.conj_point(self.origin);
+        let d = to_box.conj_dir(self.direction);
+        let [hx, hy, hz] = obb.half_extents;
+        let (t, n) = slabs(
+            [o.x(), o.y(), o.z()],
+            [d.x(), d.y(), d.z()],
+            [-hx, -hy, -hz],
+            [hx, hy, hz],
+        )?;
+
+        let point = self.at(t);
+        let local = Point::new(o.x() + t * d.x(), o.y() + t * d.y(), o.z() + t * d.z());
+        Some(Hit {
+            distance: t,
+            point,
+            normal: obb.motor.conj_plane(tangent(local, n)),
+        })
+    }
+
+    fn distance_to(&self, p: Point) -> f32 {
+        let (o, d) = (self.origin, self.direction);
+        (p.x() - o.x()) * d.x() + (p.y() - o.y()) * d.y() + (p.z() - o.z()) * d.z()
+    }
+}
+
+/// The normalized plane through `p` with normal `n`.
+fn tangent(p: Point, [a, b, c]: [f32; 3]) -> Plane {
+    Plane::new(a, b, c, -(a * p.x() + b * p.y() + c * p.z())).normalized()
+}
+
+/// Slab test against the box `[min, max]`. Returns the distance to the
+/// first crossing in front of `o` and the outward normal of the face hit.
+#[allow(clippy::needless_range_loop)]
+fn slabs(o: [f32; 3], d: [f32; 3], min: [f32; 3], max: [f32; 3]) -> Option<(f32, [f32; 3])> {
+    let (mut near, mut far) = (f32::NEG_INFINITY, f32::INFINITY);
+    let (mut near_axis, mut far_axis) = (0, 0);
+
+    for i in 0..3 {
+        if d[i] == 0.0 {
+            if o[i] < min[i] || o[i] > max[i] {
+                return None;
+            }
+            continue;
+        }
+        let inv = d[i].recip();
+        let (t0, t1) = ((min[i] - o[i]) * inv, (max[i] - o[i]) * inv);
+        let (t0, t1) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+        if t0 > near {
+            near = t0;
+            near_axis = i;
+        }
+        if t1 < far {
+            far = t1;
+            far_axis = i;
+        }
+    }
+
+    if near > far || far < 0.0 {
+        return None;
+    }
+
+    // Leaving through the far face when starting inside the box.
+    let (t, axis, sign) = if near >= 0.0 {
+        (near, near_axis, -d[near_axis].signum())
+    } else {
+        (far, far_axis, d[far_axis].signum())
+    };
+    let mut n = [0.0; 3];
+    n[axis] = sign;
+    Some((t, n))
+}
@@ -0,0 +1,298 @@
+//! # Rigid registration
+//!
+//! Find the motor $`M`$ best mapping a set of entities onto their
+//! counterparts, $`M a_i \widetilde{M} \approx b_i`$, in the weighted
+//! least-squares sense. Correspondences may mix points, lines and planes.
+//!
+//! The rotation is solved first with Horn's quaternion method, aligning the
+//! centered point offsets, the line directions and the plane normals at once.
+//! The translation then follows from a linear least-squares problem over the
+//! point positions, the line moments and the plane distances. For point sets
+//! alone this is the classic Kabsch solution.
+//!
+//! A single line or plane pair is aligned exactly by the square root of the
+//! geometric product of the pair, $`\sqrt{ba}`$. When `b` is `a` with the
+//! opposite orientation the square root is undefined and a half-turn about a
+//! perpendicular axis is used instead.
+//!
+//! ```rust
+//! use klein::registration::register_points;
+//! use klein::{Motor, Point, Rotor, Translator};
+//!
+//! let m = Motor::from_translator(Translator::new(1.0, 0.0, 1.0, 0.0))
+//!     * Motor::from_rotor(Rotor::new(0.5, 0.0, 0.0, 1.0));
+//! let from = [
+//!     Point::new(0.0, 0.0, 0.0),
+//!     Point::new(1.0, 0.0, 0.0),
+//!     Point::new(0.0, 2.0, 0.0),
+//!     Point::new(0.0, 0.0, 3.0),
+//! ];
+//! let to: Vec<Point> = from.iter().map(|&p| m.conj_point(p)).collect();
+//!
+//! let fit = register_points(&from, &to).unwrap();
+//! assert!(fit.residual < 1e-4);
+//! assert!(fit.motor.approx_eq(m, 1e-4) || fit.motor.approx_eq(-m, 1e-4));
+//! ```
+
+use crate::{
+    linalg::{self, Vec3},
+    Line, Motor, Plane, Point, Translator,
+};
+
+/// A pair of corresponding entities, mapped from the first to the second.
+///
+/// Lines and planes must be oriented consistently within each pair.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Pair {
+    Point(Point, Point),
+    Line(Line, Line),
+    Plane(Plane, Plane),
+}
+
+/// The result of a registration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Registration {
+    /// The best fitting motor.
+    pub motor: Motor,
+    /// Root of the weighted mean squared error of the mapped entities: the
+    /// distance between points and the coordinate distance between
+    /// normalized lines and planes.
+    pub residual: f32,
+}
+
+/// Register matching point sets `from` and `to` with equal weights.
+///
+/// Returns `None` if the slices are empty or differ in length.
+pub fn register_points(from: &[Point], to: &[Point]) -> Option<Registration> {
+    if from.len() != to.len() {
+        return None;
+    }
+    register(from.iter().zip(to).map(|(&a, &b)| (Pair::Point(a, b), 1.0)))
+}
+
+/// Register weighted pairs of entities.
+///
+/// Returns `None` if there are no pairs with positive weight.
+pub fn register(pairs: impl IntoIterator<Item = (Pair, f32)>) -> Option<Registration> {
+    let pairs: Vec<(Pair, f64)> = pairs
+        .into_iter()
+        .filter(|&(_, w)| w > 0.0)
+        .map(|(pair, w)| (normalized(pair), f64::from(w)))
+        .collect();
+    if pairs.is_empty() {
+        return None;
+    }
+
+    let motor = match pairs[..] {
+        [(Pair::Line(a, b), _)] => {
+            exact(line_motor(b) * line_motor(a) * -1.0).unwrap_or_else(|| line_half_turn(a, b))
+        }
+        [(Pair::Plane(a, b), _)] => exact(b * a).unwrap_or_else(|| plane_half_turn(a, b)),
+        _ => least_squares(&pairs),
+    };
+    Some(Registration {
+        motor,
+        residual: residual(motor, &pairs),
+    })
+}
+
+fn least_squares(pairs: &[(Pair, f64)]) -> Motor {
+    // Weighted centroids of the point pairs.
+    let (mut ca, mut cb, mut total) = ([0.0; 3], [0.0; 3], 0.0);
+    for &(pair, w) in pairs {
+        if let Pair::Point(a, b) = pair {
+            ca = linalg::add(ca, linalg::scale(linalg::vec3(a), w));
+            cb = linalg::add(cb, linalg::scale(linalg::vec3(b), w));
+            total += w;
+        }
+    }
+    if total > 0.0 {
+        ca = linalg::scale(ca, 1.0 / total);
+        cb = linalg::scale(cb, 1.0 / total);
+    }
+
+    let rotation = linalg::best_rotation(pairs.iter().map(|&(pair, w)| match pair {
+        Pair::Point(a, b) => (
+            linalg::sub(linalg::vec3(a), ca),
+            linalg::sub(linalg::vec3(b), cb),
+            w,
+        ),
+        Pair::Line(a, b) => (line_direction(a), line_direction(b), w),
+        Pair::Plane(a, b) => (plane_normal(a), plane_normal(b), w),
+    }));
+    let rotation = Motor::from_rotor(rotation);
+
+    // Each rotated entity depends linearly on the translation t. Accumulate
+    // the normal equations of J t = r, with the columns of J measured by
+    // translating along the coordinate axes.
+    let axes = [
+        Translator::from_offset([1.0, 0.0, 0.0]),
+        Translator::from_offset([0.0, 1.0, 0.0]),
+        Translator::from_offset([0.0, 0.0, 1.0]),
+    ];
+    let mut ata = [[0.0; 4]; 4];
+    let mut atb = [0.0; 3];
+    let mut accumulate = |rows: &[([f64; 3], f64)], w: f64| {
+        for &(j, r) in rows {
+            for i in 0..3 {
+                for k in 0..3 {
+                    ata[i][k] += w * j[i] * j[k];
+                }
+                atb[i] += w * j[i] * r;
+            }
+        }
+    };
+
+    for &(pair, w) in pairs {
+        match pair {
+            Pair::Point(a, b) => {
+                let a = linalg::vec3(rotation.conj_point(a));
+                let r = linalg::sub(linalg::vec3(b), a);
+                accumulate(
+                    &[
+                        ([1.0, 0.0, 0.0], r[0]),
+                        ([0.0, 1.0, 0.0], r[1]),
+                        ([0.0, 0.0, 1.0], r[2]),
+                    ],
+                    w,
+                );
+            }
+            Pair::Line(a, b) => {
+                let a = rotation.conj_line(a);
+                let m = line_moment(a);
+                let columns = [0, 1, 2].map(|k| linalg::sub(line_moment(axes[k].conj_line(a)), m));
+                let r = linalg::sub(line_moment(b), m);
+                let rows = [0, 1, 2].map(|i| ([columns[0][i], columns[1][i], columns[2][i]], r[i]));
+                accumulate(&rows, w);
+            }
+            Pair::Plane(a, b) => {
+                let a = rotation.conj_plane(a);
+                let d = f64::from(a.d());
+                let j = [0, 1, 2].map(|k| f64::from(axes[k].conj_plane(a).d()) - d);
+                accumulate(&[(j, f64::from(b.d()) - d)], w);
+            }
+        }
+    }
+
+    let t = solve(ata, atb);
+    Translator::from_offset([t[0] as f32, t[1] as f32, t[2] as f32]) * rotation
+}
+
+/// Minimum norm least-squares solution of the 3x3 normal equations, dropping
+/// directions the correspondences do not constrain.
+fn solve(ata: [[f64; 4]; 4], atb: Vec3) -> Vec3 {
+    let (values, vectors) = linalg::sym_eigen(ata, 3);
+    let mut t = [0.0; 3];
+    for k in 0..3 {
+        if values[k] <= 1e-9 * values[0].max(1e-30) {
+            continue;
+        }
+        let v = [vectors[0][k], vectors[1][k], vectors[2][k]];
+        t = linalg::add(t, linalg::scale(v, linalg::dot(v, atb) / values[k]));
+    }
+    t
+}
+
+fn residual(motor: Motor, pairs: &[(Pair, f64)]) -> f32 {
+    let (mut sum, mut total) = (0.0, 0.0);
+    for &(pair, w) in pairs {
+        let e2 = match pair {
+            Pair::Point(a, b) => {
+                let d = linalg::sub(linalg::vec3(motor.conj_point(a)), linalg::vec3(b));
+                linalg::dot(d, d)
+            }
+            Pair::Line(a, b) => {
+                let a = motor.conj_line(a);
+                let d = linalg::sub(line_direction(a), line_direction(b));
+                let m = linalg::sub(line_moment(a), line_moment(b));
+                linalg::dot(d, d) + linalg::dot(m, m)
+            }
+            Pair::Plane(a, b) => {
+                let a = motor.conj_plane(a);
+                let n = linalg::sub(plane_normal(a), plane_normal(b));
+                let d = f64::from(a.d() - b.d());
+                linalg::dot(n, n) + d * d
+            }
+        };
+        sum += w * e2;
+        total += w;
+    }
+    (sum / total).sqrt() as f32
+}
+
+/// The square root of a motor that is the product of a pair, or `None` when
+/// the pair is oppositely oriented. The product is then close to $`-1`$ and
+/// $`1 + ba`$ has no rotational part to normalize.
+fn exact(product: Motor) -> Option<Motor> {
+    let product = product.normalized();
+    if 1.0 + product.scalar() < 1e-6 {
+        None
+    } else {
+        Some(product.sqrt())
+    }
+}
+
+/// Half-turn taking line `a` onto the oppositely directed line `b`, about an
+/// axis perpendicular to both through the midpoint between them.
+fn line_half_turn(a: Line, b: Line) -> Motor {
+    let d = line_direction(a);
+    let through = |p: Vec3| {
+        Plane::new(
+            d[0] as f32,
+            d[1] as f32,
+            d[2] as f32,
+            -linalg::dot(d, p) as f32,
+        )
+    };
+    let pa = linalg::vec3(through([0.0; 3]) ^ a);
+    let pb = linalg::vec3(through(pa) ^ b);
+    let offset = linalg::sub(pb, pa);
+    let axis = if linalg::norm(offset) > 1e-6 {
+        linalg::cross(d, offset)
+    } else {
+        linalg::perpendicular(d)
+    };
+    half_turn(linalg::scale(linalg::add(pa, pb), 0.5), axis)
+}
+
+/// Half-turn taking plane `a` onto the oppositely oriented plane `b`, about a
+/// line in the plane midway between them.
+fn plane_half_turn(a: Plane, b: Plane) -> Motor {
+    let n = plane_normal(a);
+    let pa = linalg::scale(n, -f64::from(a.d()));
+    let pb = linalg::sub(pa, linalg::scale(n, linalg::dot(n, pa) - f64::from(b.d())));
+    half_turn(
+        linalg::scale(linalg::add(pa, pb), 0.5),
+        linalg::perpendicular(n),
+    )
+}
+
+/// Rotation by pi about the line through `center` along `axis`.
+fn half_turn(center: Vec3, axis: Vec3) -> Motor {
+    let line = linalg::point(center) & linalg::point(linalg::add(center, axis));
+    line_motor(line.normalized())
+}
+
+fn normalized(pair: Pair) -> Pair {
+    match pair {
+        Pair::Point(a, b) => Pair::Point(a.normalized(), b.normalized()),
+        Pair::Line(a, b) => Pair::Line(a.normalized(), b.normalized()),
+        Pair::Plane(a, b) => Pair::Plane(a.normalized(), b.normalized()),
+    }
+}
+
+fn line_motor(l: Line) -> Motor {
+    Motor::from((l.p1, l.p2))
+}
+
+fn line_direction(l: Line) -> Vec3 {
+    [f64::from(l.e23()), f64::from(l.e31()), f64::from(l.e12())]
+}
+
+fn line_moment(l: Line) -> Vec3 {
+    [f64::from(l.e01()), f64::from(l.e02()), f64::from(l.e03())]
+}
+
+fn plane_normal(p: Plane) -> Vec3 {
+    [f64::from(p.x()), f64::from(p.y()), f64::from(p.z())]
+}
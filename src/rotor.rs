@@ -1,11 +1,23 @@
-use crate::{arch::f32x4, Branch, Direction, Line, Plane, Point};
+use crate::{arch::f32x4, Branch, Direction, IdealLine, Line, Mat4x4, Plane, Point};
 
+/// # Memory layout
+///
+/// A single 16-byte aligned register holding $`(1, \mathbf{e}_{23},\
+/// \mathbf{e}_{31}, \mathbf{e}_{12})`$, lowest address first. This matches
+/// the `(w, x, y, z)` layout of a quaternion up to the sign of the bivector
+/// components.
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Rotor {
     pub(crate) p1: f32x4,
 }
 
 impl Rotor {
+    /// The identity rotor $`1`$ (a rotation by zero radians).
+    pub const IDENTITY: Self = Self {
+        p1: f32x4::from_const([1.0, 0.0, 0.0, 0.0]),
+    };
+
     /// Convenience constructor.
     ///
     /// Computes transcendentals and normalizes rotation axis.
@@ -104,15 +116,12 @@ impl Rotor {
         mat4x4_12<false, true>(p1_, nullptr, out.cols);
         return out;
     }
+    */
 
     /// Converts the rotor to a 4x4 column-major matrix.
-    [[nodiscard]] mat4x4 as_mat4x4() const noexcept
-    {
-        mat4x4 out;
-        mat4x4_12<false, false>(p1_, nullptr, out.cols);
-        return out;
+    pub fn as_mat4x4(self) -> Mat4x4 {
+        crate::matrix::mat4x4_12(self.p1, None)
     }
-    */
 
     /// Conjugates a plane $p$ with this rotor and returns the result
     /// $rp\widetilde{r}$.
@@ -142,12 +151,47 @@ impl Rotor {
         crate::arch::sw012(input.map(|d| d.p0), self.p1, None).map(|p0| Plane { p0 })
     }
 
+    /// Conjugates a branch $b$ with this rotor and returns the result
+    /// $`rb\widetilde{r}`$.
     pub fn conj_branch(&self, b: Branch) -> Branch {
         use core::iter::once;
         let p1 = crate::arch::sw_mm11(once(b.p1), self.p1).next().unwrap();
         Branch { p1 }
     }
 
+    /// Conjugates an array of branches with this rotor in the input array and
+    /// stores the result in the output array.
+    ///
+    /// # tip
+    ///
+    /// When applying a rotor to a list of tightly packed branches, this
+    /// routine will be *significantly faster* than applying the rotor to
+    /// each branch individually.
+    pub fn conj_branch_slice(
+        &self,
+        input: impl Iterator<Item = Branch>,
+    ) -> impl Iterator<Item = Branch> {
+        crate::arch::sw_mm11(input.map(|b| b.p1), self.p1).map(|p1| Branch { p1 })
+    }
+
+    /// Conjugates an ideal line $\ell$ with this rotor and returns the result
+    /// $`r\ell\widetilde{r}`$.
+    pub fn conj_ideal_line(&self, l: IdealLine) -> IdealLine {
+        // NOTE: The ideal partition rotates exactly like the Euclidean one
+        use core::iter::once;
+        let p2 = crate::arch::sw_mm11(once(l.p2), self.p1).next().unwrap();
+        IdealLine { p2 }
+    }
+
+    /// Conjugates an array of ideal lines with this rotor in the input array
+    /// and stores the result in the output array.
+    pub fn conj_ideal_line_slice(
+        &self,
+        input: impl Iterator<Item = IdealLine>,
+    ) -> impl Iterator<Item = IdealLine> {
+        crate::arch::sw_mm11(input.map(|l| l.p2), self.p1).map(|p2| IdealLine { p2 })
+    }
+
     /// Conjugates a line $\ell$ with this rotor and returns the result
     /// $`r\ell \widetilde{r}`$.
     pub fn conj_line(&self, l: Line) -> Line {
@@ -158,21 +202,19 @@ impl Rotor {
             .unwrap()
     }
 
-    /*
     /// Conjugates an array of lines with this rotor in the input array and
     /// stores the result in the output array. Aliasing is only permitted when
     /// `in == out` (in place rotor application).
     ///
-    /// !!! tip
+    /// # tip
     ///
     /// When applying a rotor to a list of tightly packed lines, this
     /// routine will be *significantly faster* than applying the rotor to
     /// each line individually.
-    void KLN_VEC_CALL operator()(line* in, line* out, size_t count) const noexcept
-    {
-        detail::swMM<true, false, true>(&in->p1_, p1_, nullptr, &out->p1_, count);
+    pub fn conj_line_slice(&self, input: impl Iterator<Item = Line>) -> impl Iterator<Item = Line> {
+        crate::arch::sw_mm22(input.map(|l| (l.p1, l.p2)), self.p1, None)
+            .map(|(p1, p2)| Line { p1, p2 })
     }
-    */
 
     /// Conjugates a point `p` with this rotor and returns the result
     /// $rp\widetilde{r}$.
@@ -0,0 +1,261 @@
+//! Serde support (enabled with the `serde` feature).
+//!
+//! Every entity is serialized as a struct whose fields are its named blade
+//! components, independent of the internal SIMD lane order. For example:
+//!
+//! ```json
+//! { "x": 1.0, "y": 2.0, "z": 3.0, "w": 1.0 }
+//! ```
+//!
+//! Deserialization accepts either that struct form or a flat sequence of the
+//! components in the same order. To always emit the flat form (e.g. for
+//! compact network messages), annotate a field with
+//! `#[serde(with = "klein::serde_array")]`.
+
+use crate::{
+    arch::f32x4, Branch, Direction, Dual, Flector, IdealLine, Line, Motor, Plane, Point, Rotor,
+    Translator,
+};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Serialize,
+};
+
+/// Access to the named blade components of an entity, used to drive
+/// serialization.
+pub trait Blades: Sized {
+    /// Name of the type as it appears in the serialized output.
+    const NAME: &'static str;
+
+    /// Names of the blade components in serialization order.
+    const BLADES: &'static [&'static str];
+
+    /// Writes the blade components (in the order given by `BLADES`) to `out`.
+    fn to_blades(&self, out: &mut [f32]);
+
+    /// Constructs the entity from blade components in the order given by
+    /// `BLADES`.
+    fn from_blades(blades: &[f32]) -> Self;
+}
+
+macro_rules! impl_blades {
+    ($ty:ident [$($part:ident),+] { $($blade:ident: $src:ident[$lane:literal]),+ }) => {
+        impl Blades for $ty {
+            const NAME: &'static str = stringify!($ty);
+            const BLADES: &'static [&'static str] = &[$(stringify!($blade)),+];
+
+            fn to_blades(&self, out: &mut [f32]) {
+                $( let $part = self.$part.into_array(); )+
+                let blades = [$($src[$lane]),+];
+                out[..blades.len()].copy_from_slice(&blades);
+            }
+
+            fn from_blades(blades: &[f32]) -> Self {
+                $( let mut $part = [0.0; 4]; )+
+                let mut blades = blades.iter();
+                $( $src[$lane] = *blades.next().unwrap(); )+
+                Self { $( $part: f32x4::from_array($part) ),+ }
+            }
+        }
+
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_struct(self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_struct(
+                    Self::NAME,
+                    Self::BLADES,
+                    BladesVisitor(PhantomData),
+                )
+            }
+        }
+    };
+}
+
+// Partition memory layouts
+//     LSB --> MSB
+// p0: (e0, e1, e2, e3)
+// p1: (1, e23, e31, e12)
+// p2: (e0123, e01, e02, e03)
+// p3: (e123, e032, e013, e021)
+
+impl_blades!(Plane [p0] { x: p0[1], y: p0[2], z: p0[3], d: p0[0] });
+impl_blades!(Point [p3] { x: p3[1], y: p3[2], z: p3[3], w: p3[0] });
+impl_blades!(Direction [p3] { x: p3[1], y: p3[2], z: p3[3] });
+impl_blades!(Branch [p1] { e23: p1[1], e31: p1[2], e12: p1[3] });
+impl_blades!(IdealLine [p2] { e01: p2[1], e02: p2[2], e03: p2[3] });
+impl_blades!(Line [p1, p2] {
+    e01: p2[1], e02: p2[2], e03: p2[3],
+    e23: p1[1], e31: p1[2], e12: p1[3]
+});
+impl_blades!(Rotor [p1] { scalar: p1[0], e23: p1[1], e31: p1[2], e12: p1[3] });
+impl_blades!(Translator [p2] { e01: p2[1], e02: p2[2], e03: p2[3] });
+impl_blades!(Motor [p1, p2] {
+    scalar: p1[0], e23: p1[1], e31: p1[2], e12: p1[3],
+    e0123: p2[0], e01: p2[1], e02: p2[2], e03: p2[3]
+});
+impl_blades!(Flector [p0, p3] {
+    e1: p0[1], e2: p0[2], e3: p0[3], e0: p0[0],
+    e032: p3[1], e013: p3[2], e021: p3[3], e123: p3[0]
+});
+
+impl Blades for Dual {
+    const NAME: &'static str = "Dual";
+    const BLADES: &'static [&'static str] = &["scalar", "e0123"];
+
+    fn to_blades(&self, out: &mut [f32]) {
+        out[0] = self.p;
+        out[1] = self.q;
+    }
+
+    fn from_blades(blades: &[f32]) -> Self {
+        Self {
+            p: blades[0],
+            q: blades[1],
+        }
+    }
+}
+
+impl Serialize for Dual {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_struct(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Dual {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct(Self::NAME, Self::BLADES, BladesVisitor(PhantomData))
+    }
+}
+
+// No entity has more than 8 components (the motor).
+const MAX_BLADES: usize = 8;
+
+fn serialize_struct<T: Blades, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut blades = [0.0; MAX_BLADES];
+    value.to_blades(&mut blades);
+
+    let mut state = serializer.serialize_struct(T::NAME, T::BLADES.len())?;
+    for (name, blade) in T::BLADES.iter().zip(blades.iter()) {
+        state.serialize_field(name, blade)?;
+    }
+    state.end()
+}
+
+struct BladesVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Blades> Visitor<'de> for BladesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} with components {:?}", T::NAME, T::BLADES)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut blades = [0.0; MAX_BLADES];
+        for (i, blade) in blades.iter_mut().take(T::BLADES.len()).enumerate() {
+            *blade = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Ok(T::from_blades(&blades))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T, A::Error> {
+        let mut blades = [None; MAX_BLADES];
+        while let Some(index) = map.next_key_seed(BladeIndex::<T>(PhantomData))? {
+            if blades[index].is_some() {
+                return Err(de::Error::duplicate_field(T::BLADES[index]));
+            }
+            blades[index] = Some(map.next_value()?);
+        }
+
+        let mut out = [0.0; MAX_BLADES];
+        for (i, name) in T::BLADES.iter().enumerate() {
+            out[i] = blades[i].ok_or_else(|| de::Error::missing_field(name))?;
+        }
+        Ok(T::from_blades(&out))
+    }
+}
+
+// Maps a field name to its position in `T::BLADES`.
+struct BladeIndex<T>(PhantomData<T>);
+
+impl<'de, T: Blades> DeserializeSeed<'de> for BladeIndex<T> {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de, T: Blades> Visitor<'de> for BladeIndex<T> {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "one of {:?}", T::BLADES)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<usize, E> {
+        let index = value as usize;
+        if index < T::BLADES.len() {
+            Ok(index)
+        } else {
+            Err(E::invalid_value(de::Unexpected::Unsigned(value), &self))
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<usize, E> {
+        T::BLADES
+            .iter()
+            .position(|name| *name == value)
+            .ok_or_else(|| E::unknown_field(value, T::BLADES))
+    }
+}
+
+/// Serializes an entity as a flat array of its blade components.
+///
+/// Use with `#[serde(with = "klein::serde_array")]`:
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Pose {
+///     #[serde(with = "klein::serde_array")]
+///     motor: klein::Motor,
+/// }
+/// ```
+pub mod serde_array {
+    pub use super::Blades;
+    use super::{BladesVisitor, MAX_BLADES};
+    use core::marker::PhantomData;
+    use serde::{
+        ser::{SerializeTuple, Serializer},
+        Deserializer,
+    };
+
+    pub fn serialize<T: Blades, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut blades = [0.0; MAX_BLADES];
+        value.to_blades(&mut blades);
+
+        let mut state = serializer.serialize_tuple(T::BLADES.len())?;
+        for blade in &blades[..T::BLADES.len()] {
+            state.serialize_element(blade)?;
+        }
+        state.end()
+    }
+
+    pub fn deserialize<'de, T: Blades, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_tuple(T::BLADES.len(), BladesVisitor(PhantomData))
+    }
+}
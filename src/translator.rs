@@ -1,11 +1,23 @@
 use crate::{arch::f32x4, Line, Plane, Point};
 
+/// # Memory layout
+///
+/// A single 16-byte aligned register holding $`(\mathbf{e}_{0123},\
+/// \mathbf{e}_{01}, \mathbf{e}_{02}, \mathbf{e}_{03})`$, lowest address
+/// first. The scalar `1` is implied and not stored; the first lane is
+/// normally zero.
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Translator {
     pub(crate) p2: f32x4,
 }
 
 impl Translator {
+    /// The identity translator $`1`$ (a translation by zero units).
+    pub const IDENTITY: Self = Self {
+        p2: f32x4::from_const([0.0; 4]),
+    };
+
     pub fn new(delta: f32, x: f32, y: f32, z: f32) -> Self {
         let inv_norm = (x * x + y * y + z * z).sqrt().recip();
 
@@ -15,6 +27,11 @@ impl Translator {
         Self::from(p2)
     }
 
+    /// The translator taking the origin to `(x, y, z)`.
+    pub(crate) fn from_offset([x, y, z]: [f32; 3]) -> Self {
+        Self::from(f32x4::new(-0.5 * z, -0.5 * y, -0.5 * x, 0.0))
+    }
+
     #[doc(hidden)]
     pub fn raw(a: f32, b: f32, c: f32, d: f32) -> Self {
         Self::from(f32x4::new(a, b, c, d))
@@ -49,15 +66,39 @@ impl Translator {
         Plane::from(crate::arch::sw02(p.p0, self.p2.blend1(f32x4::set0(1.0))))
     }
 
+    /// Conjugates an array of planes with this translator.
+    ///
+    /// # tip
+    ///
+    /// When applying a translator to a list of tightly packed planes, this
+    /// routine will be faster than applying the translator to each plane
+    /// individually.
+    pub fn conj_planes(&self, input: impl Iterator<Item = Plane>) -> impl Iterator<Item = Plane> {
+        let b = self.p2.blend1(f32x4::set0(1.0));
+        input.map(move |p| Plane::from(crate::arch::sw02(p.p0, b)))
+    }
+
     /// Conjugates a line $`\ell`$ with this translator and returns the result
     /// $`t\ell\widetilde{t}`$.
     pub fn conj_line(&self, l: Line) -> Line {
         Line::from(crate::arch::sw_l2(l.p1, l.p2, self.p2))
     }
 
+    /// Conjugates an array of lines with this translator.
+    pub fn conj_lines(&self, input: impl Iterator<Item = Line>) -> impl Iterator<Item = Line> {
+        let c = self.p2;
+        input.map(move |l| Line::from(crate::arch::sw_l2(l.p1, l.p2, c)))
+    }
+
     /// Conjugates a point $p$ with this translator and returns the result
     /// $`tp\widetilde{t}`$.
     pub fn conj_point(&self, p: Point) -> Point {
         Point::from(crate::arch::sw32(p.p3, self.p2))
     }
+
+    /// Conjugates an array of points with this translator.
+    pub fn conj_points(&self, input: impl Iterator<Item = Point>) -> impl Iterator<Item = Point> {
+        let b = self.p2;
+        input.map(move |p| Point::from(crate::arch::sw32(p.p3, b)))
+    }
 }
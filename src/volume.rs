@@ -0,0 +1,430 @@
+//! # Bounding volumes
+//!
+//! Axis aligned boxes ([`Aabb`]), oriented boxes ([`Obb`]) and spheres
+//! ([`Sphere`]), with containment and overlap queries.
+//!
+//! Volumes move with the `conj_aabb`, `conj_obb` and `conj_sphere` methods
+//! of [`Motor`] and [`Rotor`]. Oriented boxes and spheres transform exactly;
+//! an axis aligned box can not represent a rotated box, so it is refit to
+//! the transformed corners instead, which may grow it.
+//!
+//! ```rust
+//! use klein::volume::Aabb;
+//! use klein::{Motor, Point, Rotor};
+//!
+//! let points = [Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 2.0, 0.5)];
+//! let aabb = Aabb::from_points(&points).unwrap();
+//! assert!(aabb.contains_point(Point::new(0.0, 1.0, 0.25)));
+//!
+//! let spin = Motor::from_rotor(Rotor::new(0.5, 0.0, 0.0, 1.0));
+//! let moved = spin.conj_aabb(&aabb);
+//! assert!(points.iter().all(|&p| moved.contains_point(spin.conj_point(p))));
+//! ```
+
+use crate::{
+    linalg::{self, xyz},
+    Direction, Motor, Point, Rotor, Translator,
+};
+
+/// Axis aligned bounding box.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    pub min: [f32; 3],
+    pub max: [f32; 3],
+}
+
+impl Aabb {
+    /// The empty box, containing nothing. It is the identity of
+    /// [`union`](Self::union).
+    pub const EMPTY: Self = Self {
+        min: [f32::INFINITY; 3],
+        max: [f32::NEG_INFINITY; 3],
+    };
+
+    pub fn new(min: [f32; 3], max: [f32; 3]) -> Self {
+        Self { min, max }
+    }
+
+    /// The smallest box containing all `points`, or `None` if there are none.
+    pub fn from_points(points: &[Point]) -> Option<Self> {
+        Self::fit(points.iter().copied())
+    }
+
+    fn fit(points: impl Iterator<Item = Point>) -> Option<Self> {
+        let aabb = points.fold(Self::EMPTY, |aabb, p| aabb.extended(p));
+        if aabb.is_empty() {
+            None
+        } else {
+            Some(aabb)
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..3).any(|i| self.min[i] > self.max[i])
+    }
+
+    /// The box grown to contain `p`.
+    #[allow(clippy::needless_range_loop)]
+    pub fn extended(mut self, p: Point) -> Self {
+        let p = xyz(p);
+        for i in 0..3 {
+            self.min[i] = self.min[i].min(p[i]);
+            self.max[i] = self.max[i].max(p[i]);
+        }
+        self
+    }
+
+    /// The smallest box containing both boxes.
+    #[allow(clippy::needless_range_loop)]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut out = *self;
+        for i in 0..3 {
+            out.min[i] = out.min[i].min(other.min[i]);
+            out.max[i] = out.max[i].max(other.max[i]);
+        }
+        out
+    }
+
+    pub fn center(&self) -> Point {
+        let (a, b) = (self.min, self.max);
+        Point::new(
+            0.5 * (a[0] + b[0]),
+            0.5 * (a[1] + b[1]),
+            0.5 * (a[2] + b[2]),
+        )
+    }
+
+    pub fn half_extents(&self) -> [f32; 3] {
+        let (a, b) = (self.min, self.max);
+        [
+            0.5 * (b[0] - a[0]),
+            0.5 * (b[1] - a[1]),
+            0.5 * (b[2] - a[2]),
+        ]
+    }
+
+    pub fn corners(&self) -> [Point; 8] {
+        corners(self.min, self.max)
+    }
+
+    pub fn contains_point(&self, p: Point) -> bool {
+        let p = xyz(p);
+        (0..3).all(|i| self.min[i] <= p[i] && p[i] <= self.max[i])
+    }
+
+    pub fn contains_aabb(&self, other: &Self) -> bool {
+        (0..3).all(|i| self.min[i] <= other.min[i] && other.max[i] <= self.max[i])
+    }
+
+    pub fn intersects_aabb(&self, other: &Self) -> bool {
+        (0..3).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        let c = xyz(sphere.center);
+        let d2: f32 = (0..3)
+            .map(|i| {
+                let d = c[i] - c[i].max(self.min[i]).min(self.max[i]);
+                d * d
+            })
+            .sum();
+        d2 <= sphere.radius * sphere.radius
+    }
+}
+
+/// Oriented bounding box: a box centered at the origin of its own frame,
+/// placed in the world by a motor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Obb {
+    /// Box to world motor.
+    pub motor: Motor,
+    /// Half the edge lengths along the box frame axes.
+    pub half_extents: [f32; 3],
+}
+
+impl Obb {
+    pub fn new(motor: Motor, half_extents: [f32; 3]) -> Self {
+        Self {
+            motor,
+            half_extents,
+        }
+    }
+
+    /// The axis aligned box as an oriented one.
+    pub fn from_aabb(aabb: &Aabb) -> Self {
+        let c = xyz(aabb.center());
+        Self::new(
+            Motor::from_translator(Translator::from_offset(c)),
+            aabb.half_extents(),
+        )
+    }
+
+    /// A box aligned with the principal axes of `points`, or `None` if there
+    /// are none. The box is tight along those axes but not necessarily the
+    /// smallest oriented box.
+    pub fn from_points(points: &[Point]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let n = points.len() as f64;
+        let mean = points.iter().fold([0.0; 3], |m, &p| {
+            let p = linalg::vec3(p);
+            [m[0] + p[0] / n, m[1] + p[1] / n, m[2] + p[2] / n]
+        });
+        let mut covariance = [[0.0; 4]; 4];
+        for &p in points {
+            let d = linalg::sub(linalg::vec3(p), mean);
+            for (row, &di) in covariance.iter_mut().zip(d.iter()) {
+                for (c, &dj) in row.iter_mut().zip(d.iter()) {
+                    *c += di * dj;
+                }
+            }
+        }
+
+        // Rotate the coordinate axes onto a right handed principal frame.
+        let (_, v) = linalg::sym_eigen(covariance, 3);
+        let e1 = [v[0][0], v[1][0], v[2][0]];
+        let e2 = [v[0][1], v[1][1], v[2][1]];
+        let e3 = linalg::cross(e1, e2);
+        let rotation = linalg::best_rotation(
+            [
+                ([1.0, 0.0, 0.0], e1, 1.0),
+                ([0.0, 1.0, 0.0], e2, 1.0),
+                ([0.0, 0.0, 1.0], e3, 1.0),
+            ]
+            .iter()
+            .copied(),
+        );
+
+        let local = Aabb::fit(rotation.reversed().conj_point_slice(points.iter().copied()))?;
+        let center = xyz(rotation.conj_point(local.center()));
+        Some(Self::new(
+            Translator::from_offset(center) * rotation,
+            local.half_extents(),
+        ))
+    }
+
+    pub fn center(&self) -> Point {
+        self.motor.conj_origin()
+    }
+
+    pub fn corners(&self) -> [Point; 8] {
+        let [hx, hy, hz] = self.half_extents;
+        let local = corners([-hx, -hy, -hz], [hx, hy, hz]);
+        let mut out = local;
+        for (out, p) in out
+            .iter_mut()
+            .zip(self.motor.conj_points(local.iter().copied()))
+        {
+            *out = p;
+        }
+        out
+    }
+
+    /// The axis aligned box containing this box.
+    pub fn aabb(&self) -> Aabb {
+        Aabb::from_points(&self.corners()).unwrap()
+    }
+
+    pub fn contains_point(&self, p: Point) -> bool {
+        let p = xyz(self.motor.reversed().conj_point(p));
+        (0..3).all(|i| p[i].abs() <= self.half_extents[i])
+    }
+
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        let c = xyz(self.motor.reversed().conj_point(sphere.center));
+        let h = self.half_extents;
+        let d2: f32 = (0..3)
+            .map(|i| {
+                let d = c[i].abs() - h[i];
+                if d > 0.0 {
+                    d * d
+                } else {
+                    0.0
+                }
+            })
+            .sum();
+        d2 <= sphere.radius * sphere.radius
+    }
+
+    /// Separating axis test against another oriented box.
+    #[allow(clippy::needless_range_loop)]
+    pub fn intersects_obb(&self, other: &Self) -> bool {
+        // Work in the frame of this box: `b` holds the axes of the other box
+        // as columns and `t` the offset between the centers.
+        let to_self = self.motor.reversed() * other.motor;
+        let t = xyz(to_self.conj_origin());
+        let mut b = [[0.0; 3]; 3];
+        for (j, d) in axes().iter().enumerate() {
+            let d = to_self.conj_dir(*d);
+            let d = [d.x(), d.y(), d.z()];
+            for i in 0..3 {
+                b[i][j] = d[i];
+            }
+        }
+
+        // Guard against parallel edges producing near-zero cross products.
+        let mut abs_b = [[0.0; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                abs_b[i][j] = b[i][j].abs() + 1e-6;
+            }
+        }
+
+        let (ha, hb) = (self.half_extents, other.half_extents);
+        let dot = |u: [f32; 3], v: [f32; 3]| u[0] * v[0] + u[1] * v[1] + u[2] * v[2];
+        let row = |m: &[[f32; 3]; 3], i: usize| m[i];
+        let col = |m: &[[f32; 3]; 3], j: usize| [m[0][j], m[1][j], m[2][j]];
+
+        // Face axes of both boxes.
+        for i in 0..3 {
+            if t[i].abs() > ha[i] + dot(hb, row(&abs_b, i)) {
+                return false;
+            }
+        }
+        for j in 0..3 {
+            if dot(t, col(&b, j)).abs() > dot(ha, col(&abs_b, j)) + hb[j] {
+                return false;
+            }
+        }
+
+        // Cross products of edge directions.
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = ha[i1] * abs_b[i2][j] + ha[i2] * abs_b[i1][j];
+                let rb = hb[j1] * abs_b[i][j2] + hb[j2] * abs_b[i][j1];
+                if (t[i2] * b[i1][j] - t[i1] * b[i2][j]).abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sphere {
+    pub center: Point,
+    pub radius: f32,
+}
+
+impl Sphere {
+    pub fn new(center: Point, radius: f32) -> Self {
+        Self {
+            center: center.normalized(),
+            radius,
+        }
+    }
+
+    /// A sphere around the center of the bounding box of `points`, or `None`
+    /// if there are none. Cheap, but not the smallest enclosing sphere.
+    pub fn from_points(points: &[Point]) -> Option<Self> {
+        let center = Aabb::from_points(points)?.center();
+        let c = xyz(center);
+        let r2 = points
+            .iter()
+            .map(|&p| {
+                let p = xyz(p);
+                (0..3).map(|i| (p[i] - c[i]) * (p[i] - c[i])).sum::<f32>()
+            })
+            .fold(0.0, f32::max);
+        Some(Self::new(center, r2.sqrt()))
+    }
+
+    pub fn contains_point(&self, p: Point) -> bool {
+        self.distance2(p) <= self.radius * self.radius
+    }
+
+    pub fn contains_sphere(&self, other: &Self) -> bool {
+        let r = self.radius - other.radius;
+        r >= 0.0 && self.distance2(other.center) <= r * r
+    }
+
+    pub fn intersects_sphere(&self, other: &Self) -> bool {
+        let r = self.radius + other.radius;
+        self.distance2(other.center) <= r * r
+    }
+
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        aabb.intersects_sphere(self)
+    }
+
+    pub fn intersects_obb(&self, obb: &Obb) -> bool {
+        obb.intersects_sphere(self)
+    }
+
+    fn distance2(&self, p: Point) -> f32 {
+        let (p, c) = (xyz(p), xyz(self.center));
+        (0..3).map(|i| (p[i] - c[i]) * (p[i] - c[i])).sum()
+    }
+}
+
+impl Motor {
+    /// The axis aligned box containing the transformed box `aabb`.
+    pub fn conj_aabb(&self, aabb: &Aabb) -> Aabb {
+        if aabb.is_empty() {
+            return *aabb;
+        }
+        Aabb::fit(self.conj_points(aabb.corners().iter().copied())).unwrap()
+    }
+
+    pub fn conj_obb(&self, obb: &Obb) -> Obb {
+        Obb::new(*self * obb.motor, obb.half_extents)
+    }
+
+    pub fn conj_sphere(&self, sphere: &Sphere) -> Sphere {
+        Sphere::new(self.conj_point(sphere.center), sphere.radius)
+    }
+}
+
+impl Rotor {
+    /// The axis aligned box containing the transformed box `aabb`.
+    pub fn conj_aabb(&self, aabb: &Aabb) -> Aabb {
+        if aabb.is_empty() {
+            return *aabb;
+        }
+        Aabb::fit(self.conj_point_slice(aabb.corners().iter().copied())).unwrap()
+    }
+
+    pub fn conj_obb(&self, obb: &Obb) -> Obb {
+        Obb::new(*self * obb.motor, obb.half_extents)
+    }
+
+    pub fn conj_sphere(&self, sphere: &Sphere) -> Sphere {
+        Sphere::new(self.conj_point(sphere.center), sphere.radius)
+    }
+}
+
+fn axes() -> [Direction; 3] {
+    [
+        Direction::new(1.0, 0.0, 0.0),
+        Direction::new(0.0, 1.0, 0.0),
+        Direction::new(0.0, 0.0, 1.0),
+    ]
+}
+
+fn corners(min: [f32; 3], max: [f32; 3]) -> [Point; 8] {
+    let corner = |i: usize| {
+        let pick = |axis: usize| {
+            if i & (1 << axis) == 0 {
+                min[axis]
+            } else {
+                max[axis]
+            }
+        };
+        Point::new(pick(0), pick(1), pick(2))
+    };
+    [
+        corner(0),
+        corner(1),
+        corner(2),
+        corner(3),
+        corner(4),
+        corner(5),
+        corner(6),
+        corner(7),
+    ]
+}
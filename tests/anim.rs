@@ -0,0 +1,128 @@
+use klein::{
+    anim::{nlerp, slerp, Clip, Pose, Skeleton, SkeletonInstance},
+    Motor, Point, Rotor, Translator,
+};
+use std::f32::consts::FRAC_PI_2;
+
+fn close(a: Point, b: Point) -> bool {
+    (a.x() - b.x()).abs() < 1e-5 && (a.y() - b.y()).abs() < 1e-5 && (a.z() - b.z()).abs() < 1e-5
+}
+
+fn translation(x: f32, y: f32, z: f32) -> Motor {
+    let d = (x * x + y * y + z * z).sqrt();
+    Motor::from_translator(Translator::new(d, x, y, z))
+}
+
+// root at the origin, child at (0, 1, 0), grandchild at (0, 2, 0)
+fn chain() -> Skeleton {
+    let mut skeleton = Skeleton::default();
+    let a = skeleton.add_joint("a", None, Motor::IDENTITY, &[Point::new(0.0, 0.0, 0.0)]);
+    let b = skeleton.add_joint(
+        "b",
+        Some(a),
+        translation(0.0, 1.0, 0.0),
+        &[Point::new(0.0, 1.0, 0.0), Point::new(0.5, 1.5, 0.0)],
+    );
+    skeleton.add_joint(
+        "c",
+        Some(b),
+        translation(0.0, 2.0, 0.0),
+        &[Point::new(0.0, 2.0, 0.0)],
+    );
+    skeleton
+}
+
+#[test]
+fn skeleton_hierarchy() {
+    let skeleton = chain();
+    assert_eq!(skeleton.len(), 3);
+    assert_eq!(skeleton.find("c"), Some(2));
+    assert_eq!(skeleton.parent(0), None);
+    assert_eq!(skeleton.parent(2), Some(1));
+    let groups: Vec<_> = skeleton.groups().collect();
+    assert_eq!(groups, vec![0..1, 1..3, 3..4]);
+}
+
+#[test]
+fn bind_pose_is_rest() {
+    let skeleton = chain();
+    let world = translation(1.0, 0.0, 0.0);
+    let mut instance = SkeletonInstance::new(&skeleton, world);
+    instance.animate_keyframe(&skeleton, &skeleton.bind_pose());
+
+    for (out, p) in instance.joint_positions.iter().zip(&skeleton.positions) {
+        assert!(close(*out, Point::new(p.x() + 1.0, p.y(), p.z())));
+    }
+    assert!(close(instance.joint_origin(2), Point::new(1.0, 2.0, 0.0)));
+}
+
+#[test]
+fn forward_kinematics() {
+    let skeleton = chain();
+    let mut pose = skeleton.bind_pose();
+    // Rotating the root by 90 degrees about z carries the whole chain.
+    pose.joint_poses[0] = Motor::from_rotor(Rotor::new(FRAC_PI_2, 0.0, 0.0, 1.0));
+
+    let mut instance = SkeletonInstance::new(&skeleton, Motor::IDENTITY);
+    instance.animate_keyframe(&skeleton, &pose);
+
+    let expected = [
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(-1.0, 0.0, 0.0),
+        Point::new(-1.5, 0.5, 0.0),
+        Point::new(-2.0, 0.0, 0.0),
+    ];
+    for (out, p) in instance.joint_positions.iter().zip(&expected) {
+        assert!(close(*out, *p), "{:?} {:?}", out, p);
+    }
+    let world_c = instance.world_motors[2];
+    assert!(close(world_c.conj_origin(), expected[3]));
+}
+
+#[test]
+fn motor_blend() {
+    let a = Motor::from_rotor(Rotor::new(0.2, 1.0, 0.0, 0.0)) * translation(1.0, 0.0, 0.0);
+    let b = Motor::from_rotor(Rotor::new(1.4, 0.0, 1.0, 1.0)) * translation(0.0, -2.0, 1.0);
+
+    assert!(slerp(a, b, 0.0).approx_eq(a, 1e-5));
+    assert!(slerp(a, b, 1.0).approx_eq(b, 1e-5));
+    // Either sign of a motor describes the same motion.
+    assert!(slerp(a, -b, 1.0).approx_eq(b, 1e-5));
+    assert!(nlerp(a, b, 1.0).approx_eq(b, 1e-5));
+
+    let half = slerp(a, b, 0.5);
+    let step = half * a.reversed();
+    assert!((step * step * a).approx_eq(b, 1e-5));
+}
+
+#[test]
+fn clip_sampling() {
+    let skeleton = chain();
+    let rest = skeleton.bind_pose();
+    let mut moved = rest.clone();
+    moved.joint_poses[0] = translation(0.0, 0.0, 2.0);
+
+    let clip = Clip {
+        poses: vec![rest.clone(), moved.clone(), rest.clone()],
+        timestamps: vec![10, 20, 40],
+        timestamp_us: 500,
+    };
+    assert_eq!(clip.duration_us(), 20_000);
+
+    let (p, n, t) = clip.pose_endpoints(12_500);
+    assert_eq!((p, n), (&moved, &rest));
+    assert!((t - 0.25).abs() < 1e-6);
+
+    let mut scratch = Pose::default();
+    let mut instance = SkeletonInstance::new(&skeleton, Motor::IDENTITY);
+    for (time_us, z) in &[
+        (0, 0.0),
+        (7_500, 1.0),
+        (10_000, 2.0),
+        (15_000, 1.0),
+        (99_999, 0.0),
+    ] {
+        instance.animate_sample(&skeleton, &clip, *time_us, &mut scratch);
+        assert!(close(instance.joint_positions[3], Point::new(0.0, 2.0, *z)));
+    }
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "approx")]
+
+use approx::{
+    assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq, relative_eq,
+};
+use klein::{
+    Branch, Direction, Dual, IdealLine, Line, Motor, Plane, Point, Projective, Rotor, Translator,
+};
+use std::f32::consts::FRAC_PI_2;
+
+#[test]
+fn entities() {
+    let r = Rotor::new(FRAC_PI_2, 0.0, 0.0, 1.0);
+    let t = Translator::new(1.0, 0.0, 0.0, 1.0);
+    let m: Motor = r * t;
+
+    assert_relative_eq!(
+        m * m.reversed(),
+        Motor::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+    );
+    assert_relative_eq!(r * r.reversed(), Rotor::new(0.0, 1.0, 0.0, 0.0));
+    assert_abs_diff_eq!(
+        m.conj_point(Point::new(1.0, 0.0, 0.0)),
+        Point::new(0.0, 1.0, 1.0),
+        epsilon = 1e-6
+    );
+    assert_abs_diff_eq!(
+        r.conj_dir(Direction::new(1.0, 0.0, 0.0)),
+        Direction::new(0.0, 1.0, 0.0),
+        epsilon = 1e-6
+    );
+    assert_ulps_eq!(t, Translator::new(1.0, 0.0, 0.0, 1.0));
+    assert_ulps_eq!(Branch::new(1.0, 2.0, 3.0), Branch::new(1.0, 2.0, 3.0));
+    assert_ulps_eq!(IdealLine::new(1.0, 2.0, 3.0), IdealLine::new(1.0, 2.0, 3.0));
+    assert_relative_eq!(Dual::new(1.0, 2.0), Dual::new(1.0, 2.0 + 1e-7));
+    assert_relative_ne!(
+        Line::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0),
+        Line::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.1)
+    );
+}
+
+#[test]
+fn projective() {
+    let p = Point::new(1.0, 2.0, 3.0);
+    assert_relative_eq!(Projective(p), Projective(p * -4.0));
+    assert_relative_ne!(Projective(p), Projective(Point::new(1.0, 2.0, 3.1)));
+    assert!(!relative_eq!(p, p * 2.0));
+
+    let a = Plane::new(1.0, 2.0, 3.0, 4.0);
+    let b = Plane::new(0.5, 1.0, 1.5, 2.0);
+    assert_eq!(Projective(a), Projective(b));
+    assert_relative_ne!(Projective(a), Projective(Plane::new(1.0, 2.0, 3.0, 0.0)));
+
+    // The implied scalar of a translator makes it compare exactly.
+    let t = Translator::new(1.0, 0.0, 0.0, 1.0);
+    assert_relative_ne!(Projective(t), Projective(t * 2.0));
+
+    let zero = Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    assert_relative_eq!(Projective(zero), Projective(zero));
+    assert_relative_ne!(
+        Projective(zero),
+        Projective(Line::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0))
+    );
+    assert_relative_ne!(
+        Projective(Line::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0)),
+        Projective(zero)
+    );
+}
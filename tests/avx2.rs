@@ -0,0 +1,89 @@
+use approx::assert_abs_diff_eq;
+use core::f32::consts::FRAC_PI_2;
+use klein::{Line, Motor, Plane, Point, Rotor, Translator};
+
+// With an odd number of inputs the batch routines run the first two entities
+// through the AVX2 kernel (when available) and the last one through SSE, so
+// every output must agree with the single-entity call.
+
+#[test]
+fn motor_points_wide_and_narrow_agree() {
+    let m = Motor::new(1.0, 4.0, 3.0, 2.0, 5.0, 6.0, 7.0, 8.0);
+    let ps = [
+        Point::new(-1.0, 1.0, 2.0),
+        Point::new(3.0, -2.0, 0.5),
+        Point::new(0.0, 4.0, -1.0),
+    ];
+
+    for (p, q) in ps.iter().zip(m.conj_points(ps.iter().copied())) {
+        let expected = m.conj_point(*p);
+        assert_abs_diff_eq!(q.x(), expected.x(), epsilon = 1e-3);
+        assert_abs_diff_eq!(q.y(), expected.y(), epsilon = 1e-3);
+        assert_abs_diff_eq!(q.z(), expected.z(), epsilon = 1e-3);
+        assert_abs_diff_eq!(q.w(), expected.w(), epsilon = 1e-3);
+    }
+}
+
+#[test]
+fn motor_planes_wide_and_narrow_agree() {
+    let m = Motor::new(1.0, 4.0, 3.0, 2.0, 5.0, 6.0, 7.0, 8.0);
+    let ps = [
+        Plane::new(3.0, 2.0, 1.0, -1.0),
+        Plane::new(-1.0, 0.5, 2.0, 4.0),
+        Plane::new(0.0, 1.0, 0.0, 2.0),
+    ];
+
+    for (p, q) in ps.iter().zip(m.conj_planes(ps.iter().copied())) {
+        let expected = m.conj_plane(*p);
+        assert_abs_diff_eq!(q.x(), expected.x(), epsilon = 1e-3);
+        assert_abs_diff_eq!(q.y(), expected.y(), epsilon = 1e-3);
+        assert_abs_diff_eq!(q.z(), expected.z(), epsilon = 1e-3);
+        assert_abs_diff_eq!(q.d(), expected.d(), epsilon = 1e-3);
+    }
+}
+
+#[test]
+fn motor_lines_wide_and_narrow_agree() {
+    let r = Rotor::new(FRAC_PI_2, 0.3, -3.0, 1.0);
+    let t = Translator::new(12.0, -2.0, 0.4, 1.0);
+    let m: Motor = r * t;
+    let ls = [
+        Line::new(-1.0, 2.0, -3.0, -6.0, 5.0, 4.0),
+        Line::new(0.5, 0.0, 1.0, 1.0, -2.0, 3.0),
+        Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0),
+    ];
+
+    for (l, k) in ls.iter().zip(m.conj_lines(ls.iter().copied())) {
+        let expected = m.conj_line(*l);
+        assert_abs_diff_eq!(k.e01(), expected.e01(), epsilon = 1e-3);
+        assert_abs_diff_eq!(k.e02(), expected.e02(), epsilon = 1e-3);
+        assert_abs_diff_eq!(k.e03(), expected.e03(), epsilon = 1e-3);
+        assert_abs_diff_eq!(k.e23(), expected.e23(), epsilon = 1e-3);
+        assert_abs_diff_eq!(k.e31(), expected.e31(), epsilon = 1e-3);
+        assert_abs_diff_eq!(k.e12(), expected.e12(), epsilon = 1e-3);
+    }
+
+    for (l, k) in ls.iter().zip(r.conj_line_slice(ls.iter().copied())) {
+        let expected = r.conj_line(*l);
+        assert_abs_diff_eq!(k.e01(), expected.e01(), epsilon = 1e-3);
+        assert_abs_diff_eq!(k.e23(), expected.e23(), epsilon = 1e-3);
+    }
+}
+
+#[test]
+fn motor_motor_product() {
+    // Integer inputs keep every intermediate exact, so the fused kernel (used
+    // when the crate is built with AVX2 and FMA) and the SSE path agree.
+    let m1 = Motor::new(2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let m2 = Motor::new(6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0);
+
+    let mul = m1 * m2;
+    assert_eq!(mul.scalar(), -86.0);
+    assert_eq!(mul.e23(), 36.0);
+    assert_eq!(mul.e31(), 32.0);
+    assert_eq!(mul.e12(), 52.0);
+    assert_eq!(mul.e01(), -38.0);
+    assert_eq!(mul.e02(), -76.0);
+    assert_eq!(mul.e03(), -66.0);
+    assert_eq!(mul.e0123(), 384.0);
+}
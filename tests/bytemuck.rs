@@ -0,0 +1,57 @@
+#![cfg(feature = "bytemuck")]
+
+use klein::{Line, Motor, Plane, Point, Rotor, Translator};
+use std::mem::{align_of, size_of};
+
+#[test]
+fn layout() {
+    assert_eq!(size_of::<Plane>(), 16);
+    assert_eq!(size_of::<Point>(), 16);
+    assert_eq!(size_of::<Rotor>(), 16);
+    assert_eq!(size_of::<Translator>(), 16);
+    assert_eq!(size_of::<Line>(), 32);
+    assert_eq!(size_of::<Motor>(), 32);
+
+    assert_eq!(align_of::<Point>(), 16);
+    assert_eq!(align_of::<Motor>(), 16);
+}
+
+#[test]
+fn point_floats() {
+    let points = [Point::new(1.0, 2.0, 3.0), Point::new(4.0, 5.0, 6.0)];
+    let floats = Point::as_floats(&points);
+    assert_eq!(floats, &[1.0, 1.0, 2.0, 3.0, 1.0, 4.0, 5.0, 6.0]);
+
+    let back = Point::from_floats(floats).unwrap();
+    assert_eq!(back.len(), 2);
+    assert_eq!(back[1].x(), 4.0);
+    assert_eq!(back[1].y(), 5.0);
+    assert_eq!(back[1].z(), 6.0);
+}
+
+#[test]
+fn motor_floats() {
+    let mut motors = [Motor::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0)];
+    assert_eq!(
+        Motor::as_floats(&motors),
+        &[1.0, 2.0, 3.0, 4.0, 8.0, 5.0, 6.0, 7.0]
+    );
+    assert_eq!(Motor::as_bytes(&motors).len(), 32);
+
+    Motor::as_floats_mut(&mut motors)[0] = -1.0;
+    assert_eq!(motors[0].scalar(), -1.0);
+}
+
+#[test]
+fn plane_floats() {
+    let planes = [Plane::new(1.0, 2.0, 3.0, 4.0)];
+    assert_eq!(Plane::as_floats(&planes), &[4.0, 1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn misaligned() {
+    let points = [Point::new(1.0, 2.0, 3.0), Point::new(4.0, 5.0, 6.0)];
+    let floats = Point::as_floats(&points);
+    assert!(Point::from_floats(&floats[1..5]).is_err());
+    assert!(Point::from_floats(&floats[..6]).is_err());
+}
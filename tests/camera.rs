@@ -0,0 +1,179 @@
+use klein::{
+    camera::{Camera, Frustum, Intrinsics},
+    volume::{Aabb, Sphere},
+    Line, Motor, Point, Rotor, Translator,
+};
+
+fn camera(intrinsics: Intrinsics) -> Camera {
+    let pose = Motor::from_translator(Translator::new(3.0, 1.0, 2.0, -1.0))
+        * Motor::from_rotor(Rotor::new(0.4, 0.2, 1.0, 0.1));
+    Camera::new(pose, intrinsics)
+}
+
+fn world_point(camera: &Camera, x: f32, y: f32, z: f32) -> Point {
+    camera.pose.conj_point(Point::new(x, y, z))
+}
+
+// The join of a line with a point on it vanishes.
+fn incident(l: Line, p: Point, epsilon: f32) -> bool {
+    let j = l & p;
+    [j.x(), j.y(), j.z(), j.d()]
+        .iter()
+        .all(|c| c.abs() < epsilon)
+}
+
+#[test]
+fn project_in_camera_frame() {
+    let camera = camera(Intrinsics::new(800.0, 600.0, 320.0, 240.0));
+    let [u, v] = camera
+        .project(world_point(&camera, 0.5, -0.25, 2.0))
+        .unwrap();
+    assert!((u - 520.0).abs() < 1e-2 && (v - 165.0).abs() < 1e-2);
+
+    // The optical axis lands on the principal point.
+    let [u, v] = camera.project(world_point(&camera, 0.0, 0.0, 5.0)).unwrap();
+    assert!((u - 320.0).abs() < 1e-2 && (v - 240.0).abs() < 1e-2);
+
+    // Points behind the camera have no image.
+    assert!(camera
+        .project(world_point(&camera, 0.0, 0.0, -1.0))
+        .is_none());
+}
+
+#[test]
+fn pixel_ray_round_trip() {
+    for &intrinsics in &[
+        Intrinsics::new(800.0, 600.0, 320.0, 240.0),
+        Intrinsics::new(800.0, 600.0, 320.0, 240.0).with_distortion(-0.2, 0.05),
+    ] {
+        let camera = camera(intrinsics);
+        let p = world_point(&camera, 0.3, 0.2, 4.0);
+        let uv = camera.project(p).unwrap();
+        let ray = camera.pixel_ray(uv);
+
+        assert!((ray.norm() - 1.0).abs() < 1e-4);
+        // Both the point and the camera center lie on the ray.
+        assert!(incident(ray, p, 1e-3));
+        assert!(incident(ray, camera.center(), 1e-4));
+
+        // The ray points away from the camera.
+        let c = camera.center();
+        let d = [p.x() - c.x(), p.y() - c.y(), p.z() - c.z()];
+        let dot = d[0] * ray.e23() + d[1] * ray.e31() + d[2] * ray.e12();
+        assert!(dot > 0.0);
+    }
+}
+
+#[test]
+fn distortion_round_trip() {
+    let intrinsics = Intrinsics::new(500.0, 500.0, 300.0, 200.0).with_distortion(-0.25, 0.08);
+    for &xy in &[[0.0, 0.0], [0.3, -0.2], [-0.4, 0.35]] {
+        let uv = intrinsics.project(xy);
+        let [x, y] = intrinsics.unproject(uv);
+        assert!((x - xy[0]).abs() < 1e-5 && (y - xy[1]).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn image_line_to_plane() {
+    let camera = camera(Intrinsics::new(800.0, 600.0, 320.0, 240.0));
+    let a = world_point(&camera, -0.5, 0.2, 3.0);
+    let b = world_point(&camera, 0.4, -0.3, 5.0);
+    let [ua, va] = camera.project(a).unwrap();
+    let [ub, vb] = camera.project(b).unwrap();
+
+    // Image line through both projections.
+    let line = [vb - va, ua - ub, ub * va - ua * vb];
+    let plane = camera.image_line_plane(line);
+
+    assert!((plane.norm() - 1.0).abs() < 1e-4);
+    for p in &[a, b, camera.center()] {
+        let d = plane.d() + plane.x() * p.x() + plane.y() * p.y() + plane.z() * p.z();
+        assert!(d.abs() < 1e-3, "{}", d);
+    }
+}
+
+fn in_front(camera: &Camera, x: f32, y: f32, z: f32) -> Point {
+    world_point(camera, x, y, z)
+}
+
+#[test]
+fn perspective_frustum() {
+    let cam = camera(Intrinsics::new(1.0, 1.0, 0.0, 0.0));
+    let pose = cam.pose;
+    // 90 degrees vertically, twice as wide.
+    let frustum = Frustum::perspective(pose, core::f32::consts::FRAC_PI_2, 2.0, 0.5, 10.0);
+    for plane in frustum.planes.iter() {
+        let n = plane.x() * plane.x() + plane.y() * plane.y() + plane.z() * plane.z();
+        assert!((n - 1.0).abs() < 1e-4);
+    }
+
+    assert!(frustum.contains_point(in_front(&cam, 0.0, 0.0, 5.0)));
+    assert!(frustum.contains_point(in_front(&cam, 1.9, 0.9, 1.0)));
+    assert!(!frustum.contains_point(in_front(&cam, 2.1, 0.0, 1.0)));
+    assert!(!frustum.contains_point(in_front(&cam, 0.0, 1.1, 1.0)));
+    assert!(!frustum.contains_point(in_front(&cam, 0.0, 0.0, 0.4)));
+    assert!(!frustum.contains_point(in_front(&cam, 0.0, 0.0, 11.0)));
+    assert!(!frustum.contains_point(in_front(&cam, 0.0, 0.0, -5.0)));
+
+    let sphere = |x, y, z, r| Sphere::new(in_front(&cam, x, y, z), r);
+    assert!(frustum.intersects_sphere(&sphere(0.0, 0.0, 5.0, 1.0)));
+    assert!(frustum.intersects_sphere(&sphere(0.0, 0.0, 10.5, 1.0)));
+    assert!(!frustum.intersects_sphere(&sphere(0.0, 0.0, 11.5, 1.0)));
+    assert!(!frustum.intersects_sphere(&sphere(0.0, 0.0, -2.0, 1.0)));
+}
+
+#[test]
+fn orthographic_frustum_culls_boxes() {
+    let frustum = Frustum::orthographic(Motor::IDENTITY, [-2.0, 2.0], [-1.0, 1.0], 0.0, 5.0);
+    assert!(frustum.contains_point(Point::new(1.9, -0.9, 4.9)));
+    assert!(!frustum.contains_point(Point::new(1.9, -1.1, 4.9)));
+
+    assert!(frustum.intersects_aabb(&Aabb::new([1.0, 0.5, 1.0], [3.0, 3.0, 2.0])));
+    assert!(frustum.intersects_aabb(&Aabb::new([-10.0; 3], [10.0; 3])));
+    assert!(!frustum.intersects_aabb(&Aabb::new([2.5, 0.0, 1.0], [3.0, 1.0, 2.0])));
+    assert!(!frustum.intersects_aabb(&Aabb::new([0.0, 0.0, 6.0], [1.0, 1.0, 7.0])));
+}
+
+#[test]
+fn camera_frustum_matches_projection() {
+    let cam = camera(Intrinsics::new(800.0, 600.0, 300.0, 260.0));
+    let frustum = cam.frustum([640.0, 480.0], 0.1, 100.0);
+    for &(x, y, z) in &[
+        (0.1, 0.2, 3.0),
+        (-1.0, 0.5, 2.0),
+        (2.0, -2.0, 4.0),
+        (0.0, 0.0, 200.0),
+    ] {
+        let p = in_front(&cam, x, y, z);
+        let visible = matches!(
+            cam.project(p),
+            Some([u, v]) if (0.0..=640.0).contains(&u) && (0.0..=480.0).contains(&v)
+        ) && z <= 100.0;
+        assert_eq!(frustum.contains_point(p), visible, "{:?}", (x, y, z));
+    }
+}
+
+#[test]
+fn frustum_transform_and_clip() {
+    let frustum = Frustum::perspective(Motor::IDENTITY, 1.0, 1.0, 1.0, 10.0);
+    let m = Motor::from_translator(Translator::new(5.0, 0.0, 1.0, 0.0))
+        * Motor::from_rotor(Rotor::new(0.6, 1.0, 0.0, 0.0));
+    let moved = m.conj_frustum(&frustum);
+    assert_eq!(moved, Frustum::perspective(m, 1.0, 1.0, 1.0, 10.0));
+    let p = Point::new(0.1, -0.2, 3.0);
+    assert!(moved.contains_point(m.conj_point(p)));
+
+    // A large quad across the view is cut down to the frustum.
+    let quad = [
+        Point::new(-10.0, -10.0, 5.0),
+        Point::new(10.0, -10.0, 5.0),
+        Point::new(10.0, 10.0, 5.0),
+        Point::new(-10.0, 10.0, 5.0),
+    ];
+    let clipped = frustum.clip_polygon(&quad);
+    assert_eq!(clipped.len(), 4);
+    assert!(clipped
+        .iter()
+        .all(|&p| frustum.polytope().contains_point(p)));
+}
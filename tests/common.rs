@@ -28,6 +28,20 @@ fn multivector_sum_planes() {
     abs_diff_eq!(p_norm, 1.0);
 }
 
+#[test]
+fn plane_normalize_scales_distance() {
+    // 2z + 4 = 0, the plane z = -2.
+    let p = Plane::new(0.0, 0.0, 2.0, 4.0).normalized();
+    assert!((p.z() - 1.0).abs() < 1e-6);
+    assert!((p.d() - 2.0).abs() < 1e-6);
+
+    for &(x, y) in &[(0.0, 0.0), (1.0, -3.0), (-2.5, 4.0)] {
+        let q = Point::new(x, y, -2.0);
+        let distance = p.x() * q.x() + p.y() * q.y() + p.z() * q.z() + p.d();
+        assert!(distance.abs() < 1e-6);
+    }
+}
+
 #[test]
 fn rotor_constrain() {
     let r1 = Rotor::new(1.0, 2.0, 3.0, 4.0);
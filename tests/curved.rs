@@ -0,0 +1,260 @@
+use approx::assert_abs_diff_eq;
+use klein::curved::{elliptic, hyperbolic, Flector, Line, Metric, Motor, Plane, Point};
+
+fn on_plane<M: Metric>(plane: Plane<M>, p: Point<M>) -> f32 {
+    plane.x() * p.x() + plane.y() * p.y() + plane.z() * p.z() + plane.d() * p.w()
+}
+
+fn same_point<M: Metric>(a: Point<M>, b: Point<M>) -> bool {
+    let (a, b) = (a * a.w().recip(), b * b.w().recip());
+    a.approx_eq(b, 1e-4)
+}
+
+fn motors<M: Metric>() -> Vec<Motor<M>> {
+    vec![
+        Motor::from_rotation(1.0, 0.3, -0.5, 1.0),
+        Motor::from_translation(0.7, 1.0, 2.0, 0.0),
+        Motor::from_translation(0.4, -1.0, 0.5, 2.0) * Motor::from_rotation(2.5, 0.3, -0.5, 1.0),
+        Motor::from_rotation(0.5, 1.0, 0.0, 0.0) * Motor::from_translation(0.9, 0.0, 0.0, 1.0),
+    ]
+}
+
+#[test]
+fn elliptic_translation() {
+    let m = elliptic::Motor::from_translation(0.5, 0.0, 1.0, 0.0);
+    let p = m.conj_point(elliptic::Point::new(0.0, 0.0, 0.0));
+    assert_abs_diff_eq!(p.y(), 0.5f32.sin(), epsilon = 1e-6);
+    assert_abs_diff_eq!(p.w(), 0.5f32.cos(), epsilon = 1e-6);
+
+    // Translating by a half turn reaches the antipode, which is the origin.
+    let m = elliptic::Motor::from_translation(core::f32::consts::PI, 1.0, 0.0, 0.0);
+    let p = m.conj_point(elliptic::Point::new(0.0, 0.0, 0.0));
+    assert_abs_diff_eq!(p.x(), 0.0, epsilon = 1e-6);
+}
+
+#[test]
+fn hyperbolic_translation() {
+    let m = hyperbolic::Motor::from_translation(2.0, 0.0, 0.0, 1.0);
+    let p = m.conj_point(hyperbolic::Point::new(0.0, 0.0, 0.0));
+    assert_abs_diff_eq!(p.z(), 2f32.sinh(), epsilon = 1e-5);
+    assert_abs_diff_eq!(p.w(), 2f32.cosh(), epsilon = 1e-5);
+
+    // Points stay inside the unit ball.
+    let p = m.conj_point(hyperbolic::Point::new(0.0, 0.5, 0.8));
+    assert!(p.x() * p.x() + p.y() * p.y() + p.z() * p.z() < p.w() * p.w());
+}
+
+#[test]
+fn rotation_matches_euclidean() {
+    let r = klein::Rotor::new(1.0, 0.3, -0.5, 1.0);
+    let expected = r.conj_point(klein::Point::new(1.0, 2.0, 3.0));
+    let m = elliptic::Motor::from_rotation(1.0, 0.3, -0.5, 1.0);
+    let p = m.conj_point(elliptic::Point::new(1.0, 2.0, 3.0));
+    assert_abs_diff_eq!(p.x(), expected.x(), epsilon = 1e-5);
+    assert_abs_diff_eq!(p.y(), expected.y(), epsilon = 1e-5);
+    assert_abs_diff_eq!(p.z(), expected.z(), epsilon = 1e-5);
+}
+
+fn exp_log<M: Metric>() {
+    for m in motors::<M>() {
+        let l = m.log();
+        let e = l.exp();
+        assert!(
+            e.approx_eq(m, 1e-4) || e.approx_eq(-m, 1e-4),
+            "{:?} {:?}",
+            m,
+            e
+        );
+
+        let s = m.sqrt();
+        assert!((s * s).approx_eq(m, 1e-4) || (s * s).approx_eq(-m, 1e-4));
+        assert!((m * m.inverse()).approx_eq(Motor::IDENTITY, 1e-4));
+    }
+}
+
+#[test]
+fn elliptic_exp_log() {
+    exp_log::<klein::curved::Elliptic>();
+}
+
+#[test]
+fn hyperbolic_exp_log() {
+    exp_log::<klein::curved::Hyperbolic>();
+}
+
+fn incidence<M: Metric>() {
+    let a = Point::<M>::new(0.1, 0.2, -0.3);
+    let b = Point::<M>::new(-0.4, 0.1, 0.2);
+    let c = Point::<M>::new(0.3, -0.5, 0.1);
+    let plane = (a & b) & c;
+    for &p in &[a, b, c] {
+        assert_abs_diff_eq!(on_plane(plane, p), 0.0, epsilon = 1e-6);
+    }
+
+    let p = Plane::<M>::new(1.0, 0.0, 0.0, -0.2);
+    let q = Plane::<M>::new(0.0, 1.0, 1.0, 0.1);
+    let r = Plane::<M>::new(0.5, -1.0, 2.0, 0.3);
+    let x = (p ^ q) ^ r;
+    for &plane in &[p, q, r] {
+        assert_abs_diff_eq!(on_plane(plane, x), 0.0, epsilon = 1e-6);
+    }
+
+    // Motors preserve incidence.
+    for m in motors::<M>() {
+        let moved = m.conj_point(x);
+        for &plane in &[p, q, r] {
+            assert_abs_diff_eq!(on_plane(m.conj_plane(plane), moved), 0.0, epsilon = 1e-5);
+        }
+        let line = m.conj_line(a & b);
+        let joined = m.conj_point(a) & m.conj_point(b);
+        assert!(
+            line.normalized().approx_eq(joined.normalized(), 1e-4),
+            "{:?} {:?}",
+            line,
+            joined
+        );
+        assert_abs_diff_eq!(m.conj_point(a).norm(), a.norm(), epsilon = 1e-5);
+    }
+}
+
+#[test]
+fn elliptic_incidence() {
+    incidence::<klein::curved::Elliptic>();
+}
+
+#[test]
+fn hyperbolic_incidence() {
+    incidence::<klein::curved::Hyperbolic>();
+}
+
+#[test]
+fn norms_and_inner_products() {
+    let p = elliptic::Point::new(1.0, 2.0, 2.0);
+    assert_abs_diff_eq!(p.norm(), 10f32.sqrt(), epsilon = 1e-6);
+    let p = hyperbolic::Point::new(0.6, 0.0, 0.0);
+    assert_abs_diff_eq!(p.norm(), 0.8, epsilon = 1e-6);
+
+    // Planes through the origin meet at the Euclidean angle.
+    let a = elliptic::Plane::new(1.0, 0.0, 0.0, 0.0);
+    let b = elliptic::Plane::new(1.0, 1.0, 0.0, 0.0).normalized();
+    assert_abs_diff_eq!(a | b, 0.5f32.sqrt(), epsilon = 1e-6);
+
+    // The plane through a point orthogonal to a line contains the point.
+    let l = hyperbolic::Point::new(0.0, 0.0, 0.0) & hyperbolic::Point::new(0.5, 0.0, 0.0);
+    let p = hyperbolic::Point::new(0.2, 0.3, 0.0);
+    let plane = l | p;
+    assert_abs_diff_eq!(on_plane(plane, p), 0.0, epsilon = 1e-6);
+}
+
+fn reflections<M: Metric>() {
+    let mirror = Plane::<M>::new(1.0, 0.5, -0.2, 0.3);
+    let p = Point::<M>::new(0.2, -0.1, 0.4);
+    let reflected = mirror.reflect_point(p);
+    assert!(same_point(mirror.reflect_point(reflected), p));
+    assert!(!same_point(reflected, p));
+
+    // The product of two reflections is a motor applying both.
+    let other = Plane::<M>::new(0.0, 1.0, 0.0, -0.1);
+    let m = other * mirror;
+    assert!(same_point(m.conj_point(p), other.reflect_point(reflected)));
+
+    let l = Point::<M>::new(0.0, 0.0, 0.0) & p;
+    let back = mirror.reflect_line(mirror.reflect_line(l));
+    assert!(back.approx_eq(l, 1e-5));
+    assert!(mirror.reflect_plane(mirror).approx_eq(mirror, 1e-5));
+}
+
+#[test]
+fn elliptic_reflections() {
+    reflections::<klein::curved::Elliptic>();
+}
+
+#[test]
+fn hyperbolic_reflections() {
+    reflections::<klein::curved::Hyperbolic>();
+}
+
+fn operators<M: Metric>() {
+    let a = Point::<M>::new(0.1, 0.2, -0.3);
+    let b = Point::<M>::new(-0.4, 0.1, 0.2);
+    let plane = Plane::<M>::new(1.0, 0.5, -0.2, 0.3);
+    let line = a & b;
+
+    // The dual swaps planes and points and turns the meet into the join.
+    assert!((!!plane).approx_eq(plane, 1e-6));
+    assert!((!!a).approx_eq(a, 1e-6));
+    assert!((!!line).approx_eq(line, 1e-6));
+    assert!((!(!a ^ !b)).approx_eq(line, 1e-6));
+
+    let mut sum = plane + plane;
+    assert!((sum - plane).approx_eq(plane, 1e-6));
+    assert!((sum / 2.0).approx_eq(plane, 1e-6));
+    sum -= plane;
+    sum /= 0.5;
+    assert!(sum.approx_eq(plane * 2.0, 1e-6));
+    let total: Line<M> = vec![line, line, -line].into_iter().sum();
+    assert!(total.approx_eq(line, 1e-6));
+
+    let other = Point::<M>::new(0.3, -0.5, 0.1) & b;
+    assert!(((line / other) * (other / line)).approx_eq(Motor::IDENTITY, 1e-4));
+}
+
+#[test]
+fn elliptic_operators() {
+    operators::<klein::curved::Elliptic>();
+}
+
+#[test]
+fn hyperbolic_operators() {
+    operators::<klein::curved::Hyperbolic>();
+}
+
+fn odd_products<M: Metric>() {
+    let plane = Plane::<M>::new(1.0, 0.5, -0.2, 0.3);
+    let center = Point::<M>::new(0.2, -0.1, 0.1);
+    let p = Point::<M>::new(-0.3, 0.25, 0.1);
+    let (fp, fq) = (Flector::from(plane), Flector::from(center));
+    assert!(same_point(fp.conj_point(p), plane.reflect_point(p)));
+
+    for m in motors::<M>() {
+        let line = Point::<M>::new(0.0, 0.0, 0.0) & center;
+        assert!(((m * line) / line).approx_eq(m, 1e-4));
+        assert!(((line * m) / m).approx_eq(line * Motor::IDENTITY, 1e-4));
+
+        let products = [
+            (m * plane, m.conj_point(fp.conj_point(p))),
+            (plane * m, fp.conj_point(m.conj_point(p))),
+            (m * center, m.conj_point(fq.conj_point(p))),
+            (center * m, fq.conj_point(m.conj_point(p))),
+        ];
+        for &(f, expected) in &products {
+            assert!(same_point(f.conj_point(p), expected));
+            assert!(same_point(
+                (f * f).conj_point(p),
+                f.conj_point(f.conj_point(p))
+            ));
+            assert!(((f * f) / f).approx_eq(f, 1e-4));
+        }
+
+        let glide = m * plane;
+        let point_first = center * plane;
+        assert!(same_point(
+            point_first.conj_point(p),
+            fq.conj_point(plane.reflect_point(p))
+        ));
+        assert!(same_point(
+            (glide * center).conj_point(p),
+            glide.conj_point(fq.conj_point(p))
+        ));
+    }
+}
+
+#[test]
+fn elliptic_odd_products() {
+    odd_products::<klein::curved::Elliptic>();
+}
+
+#[test]
+fn hyperbolic_odd_products() {
+    odd_products::<klein::curved::Hyperbolic>();
+}
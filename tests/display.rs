@@ -0,0 +1,101 @@
+use klein::{Dual, Line, Motor, ParseError, Plane, Point, Rotor, Translator};
+
+#[test]
+fn display_motor() {
+    let m = Motor::new(1.0, 0.5, 0.0, 0.0, -2.0, 0.0, 0.0, 0.0);
+    assert_eq!(m.to_string(), "1 + 0.5e23 - 2e01");
+    assert_eq!(
+        m.latex().to_string(),
+        "1 + 0.5\\mathbf{e}_{23} - 2\\mathbf{e}_{01}"
+    );
+}
+
+#[test]
+fn display_entities() {
+    assert_eq!(
+        Point::new(2.0, 3.0, 0.0).to_string(),
+        "2e032 + 3e013 + e123"
+    );
+    assert_eq!(
+        Plane::new(1.0, -1.0, 0.0, 0.5).to_string(),
+        "e1 - e2 + 0.5e0"
+    );
+    assert_eq!(
+        Line::new(0.0, 0.0, 1.0, -1.0, 0.0, 0.0).to_string(),
+        "e03 - e23"
+    );
+    assert_eq!(Dual::new(-1.0, 0.0).to_string(), "-1");
+    assert_eq!(Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0).to_string(), "0");
+    assert_eq!(Translator::new(2.0, 1.0, 0.0, 0.0).to_string(), "1 - e01");
+}
+
+#[test]
+fn display_precision() {
+    let r = Rotor::new(std::f32::consts::FRAC_PI_2, 0.0, 0.0, 1.0);
+    assert_eq!(format!("{:.3}", r), "0.707 - 0.707e12");
+}
+
+#[test]
+fn parse_point() {
+    let p: Point = "2e032 + 3e013 + e123".parse().unwrap();
+    assert_eq!(p.x(), 2.0);
+    assert_eq!(p.y(), 3.0);
+    assert_eq!(p.z(), 0.0);
+    assert_eq!(p.w(), 1.0);
+
+    // Index order determines the sign.
+    let p: Point = "e023 + 2 * e021 + e123".parse().unwrap();
+    assert_eq!(p.x(), -1.0);
+    assert_eq!(p.z(), 2.0);
+}
+
+#[test]
+fn parse_motor() {
+    let m: Motor = "1 + 0.5e23 - 2e01 + e13".parse().unwrap();
+    assert_eq!(m.scalar(), 1.0);
+    assert_eq!(m.e23(), 0.5);
+    assert_eq!(m.e31(), -1.0);
+    assert_eq!(m.e01(), -2.0);
+    assert_eq!(m.e0123(), 0.0);
+
+    let m: Motor = "-e0123 + e0123 - 2e1230".parse().unwrap();
+    assert_eq!(m.e0123(), 2.0);
+}
+
+#[test]
+fn parse_round_trip() {
+    let m = Motor::new(1.0, 4.0, 3.0, 2.0, 5.0, 6.0, 7.0, 8.0);
+    let n: Motor = m.to_string().parse().unwrap();
+    assert_eq!(m, n);
+
+    let t = Translator::new(2.0, 1.0, 0.0, 0.0);
+    let u: Translator = t.to_string().parse().unwrap();
+    assert_eq!(u.e01(), t.e01());
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!("".parse::<Point>().err(), Some(ParseError::Empty));
+    assert_eq!(
+        "e12".parse::<Point>().err(),
+        Some(ParseError::UnexpectedBlade("e12".into()))
+    );
+    assert_eq!(
+        "1 + e032".parse::<Point>().err(),
+        Some(ParseError::UnexpectedBlade("1".into()))
+    );
+    assert_eq!(
+        "e4".parse::<Plane>().err(),
+        Some(ParseError::UnknownBlade("e4".into()))
+    );
+    assert_eq!(
+        "e11".parse::<Line>().err(),
+        Some(ParseError::UnknownBlade("e11".into()))
+    );
+    assert_eq!("e1 e2".parse::<Plane>().err(), Some(ParseError::Syntax(3)));
+    assert_eq!("e1 + ".parse::<Plane>().err(), Some(ParseError::Syntax(5)));
+    assert_eq!(
+        "2 + e01".parse::<Translator>().err(),
+        Some(ParseError::UnexpectedBlade("1".into()))
+    );
+}
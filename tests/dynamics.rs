@@ -0,0 +1,114 @@
+use klein::{
+    dynamics::{force, torque, Inertia, Integrator, RigidBody},
+    Line, Motor, Point,
+};
+
+fn spinning() -> RigidBody {
+    // Asymmetric body tumbling about all three axes while drifting.
+    let mut body = RigidBody::new(Motor::IDENTITY, Inertia::cuboid(3.0, [1.0, 2.0, 3.0]));
+    body.rate = Line::new(0.5, -0.2, 0.1, 1.0, 0.3, -2.0);
+    body
+}
+
+fn close(a: Line, b: Line, epsilon: f32) -> bool {
+    a.approx_eq(b, epsilon)
+}
+
+#[test]
+fn inertia_map() {
+    let inertia = Inertia::new(2.0, [1.0, 3.0, 4.0]);
+    let rate = Line::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let momentum = inertia.apply(rate);
+    assert_eq!(momentum, Line::new(4.0, 15.0, 24.0, 2.0, 4.0, 6.0));
+    assert!(close(inertia.apply_inverse(momentum), rate, 1e-5));
+
+    let sphere = Inertia::sphere(5.0, 2.0);
+    assert_eq!(sphere.moments, [8.0, 8.0, 8.0]);
+}
+
+#[test]
+fn commutator() {
+    let a = Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    let b = Line::new(0.0, 0.0, 0.0, 0.0, 1.0, 0.0);
+    // Commutator of two rotation generators is the third one (up to sign).
+    let c = a.commutator(b);
+    assert_eq!(c.e23(), 0.0);
+    assert_eq!(c.e31(), 0.0);
+    assert_eq!(c.e12().abs(), 1.0);
+    assert_eq!(a.commutator(a), Line::ZERO);
+    assert!(close(b.commutator(a), -c, 1e-6));
+}
+
+#[test]
+fn free_fall() {
+    for &integrator in &[Integrator::SymplecticEuler, Integrator::RungeKutta4] {
+        let mut body = RigidBody::new(Motor::IDENTITY, Inertia::sphere(2.0, 0.5));
+        body.gravity = Line::new(0.0, -9.81, 0.0, 0.0, 0.0, 0.0);
+        for _ in 0..1000 {
+            body.step(1e-3, Line::ZERO, integrator);
+        }
+        let p = body.motor.conj_origin();
+        assert!(p.x().abs() < 1e-4 && p.z().abs() < 1e-4);
+        assert!((p.y() + 0.5 * 9.81).abs() < 1e-2, "{:?}", p);
+        // No rotation.
+        assert!(body.rate.e23().abs() < 1e-6 && body.rate.e12().abs() < 1e-6);
+    }
+}
+
+#[test]
+fn torque_free_conservation() {
+    let mut body = spinning();
+    let momentum = body.world_momentum();
+    let energy = body.kinetic_energy();
+
+    for _ in 0..2000 {
+        body.step(1e-3, Line::ZERO, Integrator::RungeKutta4);
+    }
+    assert!(
+        close(body.world_momentum(), momentum, 1e-3),
+        "{:?} {:?}",
+        body.world_momentum(),
+        momentum
+    );
+    assert!((body.kinetic_energy() - energy).abs() < 1e-3 * energy);
+
+    // Motor stays normalized.
+    let m = body.motor * body.motor.reversed();
+    assert!(m.approx_eq(Motor::IDENTITY, 1e-5));
+
+    // The symplectic integrator keeps the energy bounded as well.
+    let mut body = spinning();
+    for _ in 0..2000 {
+        body.step(1e-3, Line::ZERO, Integrator::SymplecticEuler);
+    }
+    assert!((body.kinetic_energy() - energy).abs() < 1e-2 * energy);
+}
+
+#[test]
+fn applied_forques() {
+    // A pure torque about z spins the body up at rate torque / moment.
+    let mut body = RigidBody::new(Motor::IDENTITY, Inertia::new(1.0, [2.0, 2.0, 2.0]));
+    for _ in 0..100 {
+        body.step(1e-2, torque(0.0, 0.0, 4.0), Integrator::RungeKutta4);
+    }
+    assert!((body.rate.e12() - 2.0).abs() < 1e-4);
+    assert!(body.rate.e01().abs() < 1e-4);
+
+    // A force through the center of mass only accelerates.
+    let mut body = RigidBody::new(Motor::IDENTITY, Inertia::new(2.0, [1.0, 1.0, 1.0]));
+    let push = force(Point::new(0.0, 0.0, 0.0), 4.0, 0.0, 0.0);
+    body.step(0.5, push, Integrator::RungeKutta4);
+    let v = body.world_rate();
+    assert!((v.e01() - 1.0).abs() < 1e-5, "{:?}", v);
+    assert!(v.e23().abs() < 1e-6 && v.e31().abs() < 1e-6 && v.e12().abs() < 1e-6);
+
+    // An off-center force also produces a torque: pushing +x at y = 1 spins
+    // the body about -z.
+    let mut body = RigidBody::new(Motor::IDENTITY, Inertia::new(2.0, [1.0, 1.0, 1.0]));
+    body.step(
+        0.01,
+        force(Point::new(0.0, 1.0, 0.0), 4.0, 0.0, 0.0),
+        Integrator::RungeKutta4,
+    );
+    assert!(body.rate.e12() < 0.0, "{:?}", body.rate);
+}
@@ -101,3 +101,23 @@ fn motor_blend() {
     abs_diff_eq!(result.e03(), m2.e03());
     abs_diff_eq!(result.e0123(), m2.e0123());
 }
+
+#[test]
+fn translator_motor_exp_log() {
+    // Without a rotational part the logarithm is the ideal line itself.
+    let t = Translator::new(3.0, 1.0, -2.0, 0.5);
+    let m1 = Motor::from_translator(t);
+    let l: Line = m1.log();
+    assert_eq!(l.e23(), 0.0);
+    assert_eq!(l.e31(), 0.0);
+    assert_eq!(l.e12(), 0.0);
+    assert_eq!(l.e01(), t.e01());
+    assert_eq!(l.e02(), t.e02());
+    assert_eq!(l.e03(), t.e03());
+
+    let m2: Motor = (l * 0.5).exp();
+    assert!((m2 * m2).approx_eq(m1, 1e-6));
+
+    let id: Motor = Motor::IDENTITY.log().exp();
+    assert_eq!(id, Motor::IDENTITY);
+}
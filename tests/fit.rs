@@ -0,0 +1,112 @@
+use klein::{Line, Plane, Point};
+
+fn distance_to_plane(plane: Plane, p: Point) -> f32 {
+    plane.d() + plane.x() * p.x() + plane.y() * p.y() + plane.z() * p.z()
+}
+
+#[test]
+fn plane_fit_exact() {
+    let truth = Plane::new(1.0, -2.0, 0.5, 3.0).normalized();
+    let points: Vec<Point> = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (2.0, -1.5), (-0.5, 3.0)]
+        .iter()
+        .map(|&(x, y)| Point::new(x, y, -(3.0 + x - 2.0 * y) / 0.5))
+        .collect();
+
+    let (plane, residual) = Plane::fit(&points).unwrap();
+    assert!(residual < 1e-4);
+    let s = if plane.d() * truth.d() < 0.0 {
+        -1.0
+    } else {
+        1.0
+    };
+    assert!((plane.x() * s - truth.x()).abs() < 1e-4);
+    assert!((plane.y() * s - truth.y()).abs() < 1e-4);
+    assert!((plane.z() * s - truth.z()).abs() < 1e-4);
+    assert!((plane.d() * s - truth.d()).abs() < 1e-4);
+}
+
+#[test]
+fn plane_fit_residual() {
+    // Points alternating 0.1 above and below z = 0.
+    let points = [
+        Point::new(0.0, 0.0, 0.1),
+        Point::new(1.0, 0.0, -0.1),
+        Point::new(1.0, 1.0, 0.1),
+        Point::new(0.0, 1.0, -0.1),
+    ];
+    let (plane, residual) = Plane::fit(&points).unwrap();
+    assert!((residual - 0.1).abs() < 1e-5);
+    assert!((plane.z().abs() - 1.0).abs() < 1e-5);
+    for &p in &points {
+        assert!((distance_to_plane(plane, p).abs() - 0.1).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn plane_fit_degenerate() {
+    let a = Point::new(0.0, 0.0, 0.0);
+    let b = Point::new(1.0, 1.0, 1.0);
+    assert!(Plane::fit(&[a, b]).is_none());
+    assert!(Plane::fit(&[a, b, Point::new(2.0, 2.0, 2.0)]).is_none());
+}
+
+#[test]
+fn line_fit_exact() {
+    let points: Vec<Point> = (0..5)
+        .map(|i| {
+            let t = i as f32;
+            Point::new(1.0 + 2.0 * t, -1.0 + t, 0.5 - 2.0 * t)
+        })
+        .collect();
+    let (line, residual) = Line::fit(&points).unwrap();
+    assert!(residual < 1e-4);
+
+    let truth = (points[0] & points[4]).normalized();
+    for (a, b) in [
+        (line.e01(), truth.e01()),
+        (line.e02(), truth.e02()),
+        (line.e03(), truth.e03()),
+        (line.e23(), truth.e23()),
+        (line.e31(), truth.e31()),
+        (line.e12(), truth.e12()),
+    ]
+    .iter()
+    {
+        assert!((a - b).abs() < 1e-4, "{:?} {:?}", line, truth);
+    }
+}
+
+#[test]
+fn line_fit_residual() {
+    // Points along the x axis, offset by 0.2 on either side.
+    let points = [
+        Point::new(0.0, 0.2, 0.0),
+        Point::new(1.0, -0.2, 0.0),
+        Point::new(2.0, -0.2, 0.0),
+        Point::new(3.0, 0.2, 0.0),
+    ];
+    let (line, residual) = Line::fit(&points).unwrap();
+    assert!((residual - 0.2).abs() < 1e-5);
+    assert!(line.e23() > 0.99);
+    assert!(Line::fit(&[points[0], points[0]]).is_none());
+}
+
+#[test]
+fn closest_to_lines() {
+    let lines = [
+        Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+        Point::new(0.0, 0.0, 1.0) & Point::new(0.0, 1.0, 1.0),
+    ];
+    // Skew lines: the x axis and the line x = 0, z = 1.
+    let (p, residual) = Point::closest_to_lines(&lines).unwrap();
+    assert!(p.x().abs() < 1e-5 && p.y().abs() < 1e-5);
+    assert!((p.z() - 0.5).abs() < 1e-5);
+    assert!((residual - 0.5).abs() < 1e-5);
+
+    let parallel = [
+        Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+        Point::new(0.0, 1.0, 0.0) & Point::new(1.0, 1.0, 0.0),
+    ];
+    assert!(Point::closest_to_lines(&parallel).is_none());
+    assert!(Point::closest_to_lines(&lines[..1]).is_none());
+}
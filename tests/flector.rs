@@ -0,0 +1,180 @@
+use klein::{Branch, Direction, Flector, IdealLine, Line, Motor, Plane, Point, Rotor, Translator};
+
+fn assert_point(p: Point, x: f32, y: f32, z: f32) {
+    let w = p.w();
+    assert!((p.x() / w - x).abs() < 1e-5, "{:?}", p);
+    assert!((p.y() / w - y).abs() < 1e-5, "{:?}", p);
+    assert!((p.z() / w - z).abs() < 1e-5, "{:?}", p);
+}
+
+fn assert_points_eq(a: Point, b: Point) {
+    assert_point(a, b.x() / b.w(), b.y() / b.w(), b.z() / b.w());
+}
+
+fn rotoreflection() -> Flector {
+    let m = Motor::from_rotor(Rotor::new(0.7, 1.0, -2.0, 0.5))
+        * Motor::from_translator(Translator::new(1.5, -1.0, 0.0, 2.0));
+    m * Plane::new(1.0, 2.0, -1.0, 0.5).normalized()
+}
+
+#[test]
+fn plane_flector_reflects() {
+    let plane = Plane::new(1.0, 2.0, 3.0, 4.0).normalized();
+    let f = Flector::from(plane);
+
+    let p = Point::new(-1.0, 0.5, 2.0);
+    assert_points_eq(f.conj_point(p), plane.reflect_point(p));
+
+    let l = Line::new(1.0, -2.0, 0.5, 0.3, 0.7, -0.1);
+    assert!(f.conj_line(l).approx_eq(plane.reflect_line(l), 1e-5));
+
+    let q = Plane::new(0.0, 1.0, 1.0, -2.0);
+    assert!(f.conj_plane(q).approx_eq(plane.reflect_plane(q), 1e-5));
+}
+
+#[test]
+fn three_reflections() {
+    let a = Plane::new(1.0, 0.0, 0.5, 1.0).normalized();
+    let b = Plane::new(0.0, 1.0, -1.0, 2.0).normalized();
+    let c = Plane::new(0.3, -0.2, 1.0, -0.5).normalized();
+    let f = a * (b * c);
+
+    let p = Point::new(1.0, 2.0, 3.0);
+    let expected = a.reflect_point(b.reflect_point(c.reflect_point(p)));
+    assert_points_eq(f.conj_point(p), expected);
+    assert_points_eq((a * b * c).conj_point(p), expected);
+}
+
+#[test]
+fn glide_reflection() {
+    let t = Motor::from_translator(Translator::new(2.0, 0.0, 0.0, 1.0));
+    let f = t * Plane::new(1.0, 0.0, 0.0, 0.0);
+    assert_point(f.conj_point(Point::new(1.0, 2.0, 3.0)), -1.0, 2.0, 5.0);
+
+    // Applying a glide reflection twice leaves a pure translation.
+    let twice: Motor = f * f;
+    assert_point(twice.conj_point(Point::new(1.0, 2.0, 3.0)), 1.0, 2.0, 7.0);
+}
+
+#[test]
+fn point_inversion() {
+    let center = Point::new(1.0, 1.0, 0.0);
+    let f = center * Motor::IDENTITY;
+    assert_point(f.conj_point(Point::new(2.0, 3.0, 1.0)), 0.0, -1.0, -1.0);
+
+    let d = f.conj_dir(Direction::new(1.0, 0.0, 0.0));
+    assert!((d.x() + 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn composition_with_motors() {
+    let f = rotoreflection();
+    let m = Motor::from_rotor(Rotor::new(-1.2, 0.0, 1.0, 1.0))
+        * Motor::from_translator(Translator::new(0.5, 1.0, 1.0, 0.0));
+    let p = Point::new(0.25, -3.0, 1.0);
+
+    assert_points_eq((f * m).conj_point(p), f.conj_point(m.conj_point(p)));
+    assert_points_eq((m * f).conj_point(p), m.conj_point(f.conj_point(p)));
+    assert_points_eq((f * f).conj_point(p), f.conj_point(f.conj_point(p)));
+
+    let mut g = f;
+    g *= m;
+    assert!(g.approx_eq(f * m, 1e-5));
+}
+
+#[test]
+fn products_match_composition() {
+    let f = rotoreflection();
+    let m = Motor::from_rotor(Rotor::new(0.9, -1.0, 0.5, 2.0))
+        * Motor::from_translator(Translator::new(-2.0, 0.3, 1.0, 1.0));
+    let plane = Plane::new(0.5, -1.0, 2.0, 1.5);
+    let point = Point::new(1.0, -0.5, 2.0) * 2.0;
+    let (fp, fq) = (Flector::from(plane), Flector::from(point));
+    let p = Point::new(-1.5, 0.75, 0.5);
+
+    let flectors = [
+        (plane * m, fp.conj_point(m.conj_point(p))),
+        (m * plane, m.conj_point(fp.conj_point(p))),
+        (point * m, fq.conj_point(m.conj_point(p))),
+        (m * point, m.conj_point(fq.conj_point(p))),
+    ];
+    for &(product, expected) in &flectors {
+        assert_points_eq(product.conj_point(p), expected);
+    }
+
+    let motors = [
+        (f * plane, f.conj_point(fp.conj_point(p))),
+        (plane * f, fp.conj_point(f.conj_point(p))),
+        (f * point, f.conj_point(fq.conj_point(p))),
+        (point * f, fq.conj_point(f.conj_point(p))),
+    ];
+    for &(product, expected) in &motors {
+        assert_points_eq(product.conj_point(p), expected);
+    }
+}
+
+#[test]
+fn inverse() {
+    let f = rotoreflection();
+    let p = Point::new(2.0, -1.0, 0.5);
+    assert_points_eq(f.inverse().conj_point(f.conj_point(p)), p);
+    assert!((f * f.inverse()).approx_eq(Motor::IDENTITY, 1e-5));
+    assert!((f / f).approx_eq(Motor::IDENTITY, 1e-5));
+
+    let n = (f * 3.0).normalized();
+    assert!((n * n.reversed()).approx_eq(Motor::IDENTITY, 1e-5));
+}
+
+#[test]
+fn batch_matches_single() {
+    let f = rotoreflection();
+    let points = [Point::new(1.0, 0.0, 0.0), Point::new(0.0, -2.0, 5.0)];
+    for (a, &p) in f.conj_points(points.iter().copied()).zip(&points) {
+        assert_points_eq(a, f.conj_point(p));
+    }
+}
+
+#[test]
+fn branches_ideal_lines_and_origin() {
+    let f = rotoreflection();
+
+    let b = Branch::new(0.5, -1.0, 2.0);
+    let expected = f.conj_line(Line::from_branch(b));
+    assert!(f.conj_branch(b).approx_eq(expected, 1e-5));
+
+    let l = IdealLine::new(1.0, 0.25, -2.0);
+    let expected = f.conj_line(Line::from_ideal_line(l));
+    let ideal = f.conj_ideal_line(l);
+    assert!((ideal.e01() - expected.e01()).abs() < 1e-5);
+    assert!((ideal.e02() - expected.e02()).abs() < 1e-5);
+    assert!((ideal.e03() - expected.e03()).abs() < 1e-5);
+    assert!(expected.e23().abs() < 1e-5);
+
+    assert_points_eq(f.conj_origin(), f.conj_point(Point::new(0.0, 0.0, 0.0)));
+
+    let branches = [b, Branch::new(-1.0, 0.0, 1.0)];
+    for (a, &b) in f.conj_branches(branches.iter().copied()).zip(&branches) {
+        assert!(a.approx_eq(f.conj_branch(b), 1e-5));
+    }
+    let ideals = [l, IdealLine::new(0.0, 3.0, 1.0)];
+    for (a, &l) in f.conj_ideal_lines(ideals.iter().copied()).zip(&ideals) {
+        assert!((a.e01() - f.conj_ideal_line(l).e01()).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn matrix() {
+    let f = rotoreflection();
+    let mat = f.as_mat4x4();
+    for &p in &[Point::new(1.0, 2.0, 3.0), Point::new(-4.0, 0.5, 0.0)] {
+        assert_points_eq(mat.apply(p), f.conj_point(p));
+    }
+
+    let c = mat.to_cols();
+    let det3 = c[0][0] * (c[1][1] * c[2][2] - c[2][1] * c[1][2])
+        - c[1][0] * (c[0][1] * c[2][2] - c[2][1] * c[0][2])
+        + c[2][0] * (c[0][1] * c[1][2] - c[1][1] * c[0][2]);
+    assert!((det3 + 1.0).abs() < 1e-4);
+    assert!(c[0][3].abs() < 1e-6);
+    assert!((c[3][3] - 1.0).abs() < 1e-5);
+}
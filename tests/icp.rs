@@ -0,0 +1,137 @@
+use klein::{
+    icp::{icp, Metric, Settings},
+    Direction, Motor, Point, Rotor, Translator,
+};
+
+fn same(a: Motor, b: Motor, epsilon: f32) -> bool {
+    a.approx_eq(b, epsilon) || a.approx_eq(-b, epsilon)
+}
+
+/// A curved patch sampled on a grid, with its unit normals.
+fn surface() -> (Vec<Point>, Vec<Direction>) {
+    let mut points = Vec::new();
+    let mut normals = Vec::new();
+    for i in 0..12 {
+        for j in 0..12 {
+            let (x, y) = (i as f32 * 0.2 - 1.1, j as f32 * 0.2 - 1.1);
+            points.push(Point::new(x, y, 0.3 * x * x - 0.2 * y * y + 0.1 * x * y));
+            normals.push(Direction::new(
+                -(0.6 * x + 0.1 * y),
+                -(-0.4 * y + 0.1 * x),
+                1.0,
+            ));
+        }
+    }
+    (points, normals)
+}
+
+fn offset() -> Motor {
+    Motor::from_translator(Translator::new(0.08, 1.0, -0.5, 0.3))
+        * Motor::from_rotor(Rotor::new(0.06, 0.2, 1.0, 0.4))
+}
+
+#[test]
+fn point_to_point_recovers_motor() {
+    let (target, _) = surface();
+    let m = offset();
+    let source: Vec<Point> = m.reversed().conj_points(target.iter().copied()).collect();
+
+    let settings = Settings {
+        max_iterations: 100,
+        ..Settings::default()
+    };
+    let solution = icp(
+        &source,
+        &target,
+        Metric::PointToPoint,
+        Motor::IDENTITY,
+        &settings,
+    );
+    assert!(solution.converged);
+    assert_eq!(solution.pairs, source.len());
+    assert!(solution.residual < 1e-3, "{:?}", solution);
+    assert!(same(solution.motor, m, 1e-3), "{:?}", solution);
+}
+
+#[test]
+fn point_to_plane_recovers_motor() {
+    let (target, normals) = surface();
+    let m = offset();
+    let source: Vec<Point> = m.reversed().conj_points(target.iter().copied()).collect();
+
+    let solution = icp(
+        &source,
+        &target,
+        Metric::PointToPlane(&normals),
+        Motor::IDENTITY,
+        &Settings::default(),
+    );
+    assert!(solution.converged);
+    assert!(solution.residual < 1e-4, "{:?}", solution);
+    assert!(same(solution.motor, m, 1e-3), "{:?}", solution);
+}
+
+#[test]
+fn starts_from_initial_motor() {
+    let (target, _) = surface();
+    let m = offset();
+    let source: Vec<Point> = m.reversed().conj_points(target.iter().copied()).collect();
+
+    let solution = icp(
+        &source,
+        &target,
+        Metric::PointToPoint,
+        m,
+        &Settings::default(),
+    );
+    assert!(solution.converged);
+    assert!(solution.iterations <= 2);
+    assert!(same(solution.motor, m, 1e-4));
+}
+
+#[test]
+fn rejects_distant_pairs() {
+    let (target, _) = surface();
+    let far: Vec<Point> = target
+        .iter()
+        .map(|p| Point::new(p.x() + 10.0, p.y(), p.z()))
+        .collect();
+
+    let settings = Settings {
+        max_distance: 1.0,
+        ..Settings::default()
+    };
+    let solution = icp(
+        &far,
+        &target,
+        Metric::PointToPoint,
+        Motor::IDENTITY,
+        &settings,
+    );
+    assert_eq!(solution.pairs, 0);
+    assert_eq!(solution.iterations, 0);
+    assert!(!solution.converged);
+}
+
+#[test]
+fn skips_non_finite_points() {
+    let (mut target, _) = surface();
+    let m = offset();
+    let mut source: Vec<Point> = m.reversed().conj_points(target.iter().copied()).collect();
+    target.push(Point::new(f32::NAN, 0.0, 0.0));
+    source.push(Point::new(0.0, f32::NAN, 0.0));
+
+    let settings = Settings {
+        max_iterations: 100,
+        ..Settings::default()
+    };
+    let solution = icp(
+        &source,
+        &target,
+        Metric::PointToPoint,
+        Motor::IDENTITY,
+        &settings,
+    );
+    assert_eq!(solution.pairs, source.len() - 1);
+    assert!(same(solution.motor, m, 1e-3), "{:?}", solution);
+}
@@ -0,0 +1,160 @@
+use klein::{
+    ik::{ccd, fabrik, Chain, Constraint, Settings, Target},
+    Direction, Motor, Point, Rotor, Translator,
+};
+
+fn bone(length: f32) -> Motor {
+    Motor::from_translator(Translator::new(length, 0.0, 1.0, 0.0))
+}
+
+// A chain of `n` unit bones along the y axis, all with the same constraint.
+fn arm(n: usize, constraint: Constraint) -> Chain {
+    let mut chain = Chain::new(Motor::IDENTITY, bone(1.0));
+    chain.push(Motor::IDENTITY, constraint);
+    for _ in 1..n {
+        chain.push(bone(1.0), constraint);
+    }
+    chain
+}
+
+fn distance(a: Point, b: Point) -> f32 {
+    ((a.x() - b.x()).powi(2) + (a.y() - b.y()).powi(2) + (a.z() - b.z()).powi(2)).sqrt()
+}
+
+#[test]
+fn hinge_constraint() {
+    let z = Direction::new(0.0, 0.0, 1.0);
+    let hinge = Constraint::Hinge {
+        axis: z,
+        min: -0.5,
+        max: 0.5,
+    };
+
+    let r = Rotor::new(0.3, 0.0, 0.0, 1.0);
+    assert!(hinge.apply(r).approx_eq(r, 1e-6));
+    let r = Rotor::new(1.0, 0.0, 0.0, 1.0);
+    assert!(hinge
+        .apply(r)
+        .approx_eq(Rotor::new(0.5, 0.0, 0.0, 1.0), 1e-6));
+    let r = Rotor::new(-1.0, 0.0, 0.0, 1.0);
+    assert!(hinge
+        .apply(r)
+        .approx_eq(Rotor::new(-0.5, 0.0, 0.0, 1.0), 1e-6));
+
+    // Components off the axis are dropped.
+    let r = Rotor::new(0.2, 0.0, 0.0, 1.0) * Rotor::new(0.4, 1.0, 0.0, 0.0);
+    let c = hinge.apply(r);
+    assert!(c.e23().abs() < 1e-6 && c.e13().abs() < 1e-6);
+}
+
+#[test]
+fn cone_twist_constraint() {
+    let z = Direction::new(0.0, 0.0, 1.0);
+    let limit = Constraint::ConeTwist {
+        axis: z,
+        cone: 0.5,
+        twist: 0.3,
+    };
+
+    let twist = Rotor::new(1.0, 0.0, 0.0, 1.0);
+    assert!(limit
+        .apply(twist)
+        .approx_eq(Rotor::new(0.3, 0.0, 0.0, 1.0), 1e-6));
+
+    let swing = Rotor::new(1.0, 1.0, 0.0, 0.0);
+    assert!(limit
+        .apply(swing)
+        .approx_eq(Rotor::new(0.5, 1.0, 0.0, 0.0), 1e-6));
+
+    let inside = Rotor::new(0.4, 0.0, 1.0, 0.0) * Rotor::new(0.2, 0.0, 0.0, 1.0);
+    assert!(limit.apply(inside).approx_eq(inside, 1e-6));
+}
+
+#[test]
+fn reach_point() {
+    let goal = Point::new(1.2, 1.5, 0.7);
+    let settings = Settings::default();
+
+    for solve in &[ccd, fabrik] {
+        let mut chain = arm(3, Constraint::Free);
+        let solution = solve(&mut chain, Target::Point(goal), &settings);
+        assert!(solution.converged, "{:?}", solution);
+        assert!(solution.position_error <= settings.tolerance);
+        assert_eq!(solution.joint_motors, chain.joint_motors());
+        assert!(distance(chain.end_effector().conj_origin(), goal) <= 1e-3);
+
+        // Bone lengths are preserved.
+        let origins: Vec<Point> = chain
+            .world_motors()
+            .iter()
+            .map(|m| m.conj_origin())
+            .collect();
+        assert!((distance(origins[0], origins[1]) - 1.0).abs() < 1e-4);
+        assert!((distance(origins[1], origins[2]) - 1.0).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn reach_point_with_hinges() {
+    let hinge = Constraint::Hinge {
+        axis: Direction::new(0.0, 0.0, 1.0),
+        min: -2.0,
+        max: 2.0,
+    };
+    let goal = Point::new(-1.5, 1.0, 0.0);
+
+    for solve in &[ccd, fabrik] {
+        let mut chain = arm(3, hinge);
+        let solution = solve(&mut chain, Target::Point(goal), &Settings::default());
+        assert!(solution.converged, "{:?}", solution);
+        for joint in &chain.joints {
+            let r = joint.rotation;
+            assert!(r.e23().abs() < 1e-5 && r.e13().abs() < 1e-5);
+        }
+    }
+}
+
+#[test]
+fn reach_motor() {
+    // Pose the arm, remember where it ended up and solve back to it.
+    let mut posed = arm(4, Constraint::Free);
+    let angles = [
+        (0.3, 1.0, 0.0, 0.0),
+        (0.8, 0.0, 0.0, 1.0),
+        (-0.6, 1.0, 1.0, 0.0),
+        (0.5, 0.0, 1.0, 0.0),
+    ];
+    for (joint, &(a, x, y, z)) in posed.joints.iter_mut().zip(&angles) {
+        joint.rotation = Rotor::new(a, x, y, z);
+    }
+    let goal = posed.end_effector();
+
+    let settings = Settings {
+        max_iterations: 500,
+        tolerance: 1e-3,
+    };
+    for solve in &[ccd, fabrik] {
+        let mut chain = arm(4, Constraint::Free);
+        let solution = solve(&mut chain, Target::Motor(goal), &settings);
+        assert!(solution.converged, "{:?}", solution);
+        assert!(solution.orientation_error <= settings.tolerance);
+    }
+}
+
+#[test]
+fn unreachable() {
+    let goal = Point::new(0.0, 5.0, 0.0);
+    let settings = Settings {
+        max_iterations: 20,
+        tolerance: 1e-4,
+    };
+
+    for solve in &[ccd, fabrik] {
+        let mut chain = arm(2, Constraint::Free);
+        let solution = solve(&mut chain, Target::Point(goal), &settings);
+        assert!(!solution.converged);
+        assert_eq!(solution.iterations, 20);
+        // The chain is stretched out toward the goal.
+        assert!((solution.position_error - 3.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,124 @@
+#![cfg(any(feature = "mint", feature = "glam", feature = "nalgebra"))]
+
+use approx::assert_abs_diff_eq;
+use klein::{Motor, Point, Rotor, Translator};
+use std::f32::consts::FRAC_PI_2;
+
+// A quarter turn about +z takes +x to +y in every library.
+fn quarter_turn() -> Rotor {
+    Rotor::new(FRAC_PI_2, 0.0, 0.0, 1.0)
+}
+
+fn assert_rotor_eq(a: Rotor, b: Rotor) {
+    assert_abs_diff_eq!(a.scalar(), b.scalar(), epsilon = 1e-6);
+    assert_abs_diff_eq!(a.e23(), b.e23(), epsilon = 1e-6);
+    assert_abs_diff_eq!(a.e13(), b.e13(), epsilon = 1e-6);
+    assert_abs_diff_eq!(a.e12(), b.e12(), epsilon = 1e-6);
+}
+
+fn motor() -> Motor {
+    Translator::new(3.0, 1.0, 2.0, -1.0) * Rotor::new(1.2, 1.0, -2.0, 0.5)
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn mint() {
+    use klein::Direction;
+
+    let p: mint::Point3<f32> = (Point::new(2.0, 4.0, 6.0) * 0.5).into();
+    assert_eq!((p.x, p.y, p.z), (2.0, 4.0, 6.0));
+    let p = Point::from(p);
+    assert_eq!((p.x(), p.y(), p.z(), p.w()), (2.0, 4.0, 6.0, 1.0));
+
+    let v: mint::Vector3<f32> = Direction::new(0.0, 0.0, 2.0).into();
+    assert_abs_diff_eq!(v.z, 1.0, epsilon = 1e-6);
+    assert_eq!((v.x, v.y), (0.0, 0.0));
+
+    let q: mint::Quaternion<f32> = quarter_turn().into();
+    let h = 0.5f32.sqrt();
+    assert_abs_diff_eq!(q.s, h, epsilon = 1e-6);
+    assert_abs_diff_eq!(q.v.z, h, epsilon = 1e-6);
+    assert_eq!((q.v.x, q.v.y), (0.0, 0.0));
+    let r = Rotor::from(q);
+    assert_rotor_eq(r, quarter_turn());
+
+    let m: mint::ColumnMatrix4<f32> = quarter_turn().into();
+    // The image of +x is the first column.
+    assert_abs_diff_eq!(m.x.x, 0.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(m.x.y, 1.0, epsilon = 1e-6);
+    let back = klein::Mat4x4::from(m).to_cols();
+    assert_eq!(back[0], [m.x.x, m.x.y, m.x.z, m.x.w]);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam() {
+    use glam::{Mat4, Quat, Vec3, Vec3A};
+
+    let r = quarter_turn();
+    let q = Quat::from(r);
+    let expected = Quat::from_rotation_z(FRAC_PI_2);
+    assert_abs_diff_eq!(q.x, expected.x, epsilon = 1e-6);
+    assert_abs_diff_eq!(q.y, expected.y, epsilon = 1e-6);
+    assert_abs_diff_eq!(q.z, expected.z, epsilon = 1e-6);
+    assert_abs_diff_eq!(q.w, expected.w, epsilon = 1e-6);
+    assert_rotor_eq(Rotor::from(expected), r);
+
+    // Rotating with either library gives the same point.
+    let p = Point::new(1.0, 2.0, 3.0);
+    let a = Vec3A::from(r.conj_point(p));
+    let b = q * Vec3::new(1.0, 2.0, 3.0);
+    assert_abs_diff_eq!(a.x, b.x, epsilon = 1e-5);
+    assert_abs_diff_eq!(a.y, b.y, epsilon = 1e-5);
+    assert_abs_diff_eq!(a.z, b.z, epsilon = 1e-5);
+
+    // As does transforming with the matrix form of a motor.
+    let m = motor();
+    let a = Vec3A::from(m.conj_point(p));
+    let b = Mat4::from(m).transform_point3(Vec3::new(1.0, 2.0, 3.0));
+    assert_abs_diff_eq!(a.x, b.x, epsilon = 1e-5);
+    assert_abs_diff_eq!(a.y, b.y, epsilon = 1e-5);
+    assert_abs_diff_eq!(a.z, b.z, epsilon = 1e-5);
+
+    let p = Point::from(Vec3A::new(1.0, 2.0, 3.0));
+    assert_eq!((p.x(), p.y(), p.z(), p.w()), (1.0, 2.0, 3.0, 1.0));
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra() {
+    use nalgebra::{Isometry3, Point3, UnitQuaternion, Vector3};
+
+    let r = quarter_turn();
+    let q = UnitQuaternion::from(r);
+    let expected = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), FRAC_PI_2);
+    assert_abs_diff_eq!(q.angle_to(&expected), 0.0, epsilon = 1e-3);
+    assert_rotor_eq(Rotor::from(expected), r);
+
+    // Transforming with the motor and the isometry agrees.
+    let m = motor();
+    let iso = Isometry3::from(m);
+    let p = Point::new(1.0, 2.0, 3.0);
+    let a = Point3::from(m.conj_point(p));
+    let b = iso * Point3::new(1.0, 2.0, 3.0);
+    assert_abs_diff_eq!(a.x, b.x, epsilon = 1e-5);
+    assert_abs_diff_eq!(a.y, b.y, epsilon = 1e-5);
+    assert_abs_diff_eq!(a.z, b.z, epsilon = 1e-5);
+
+    // And the round trip preserves the motor.
+    let n = Motor::from(iso);
+    assert_abs_diff_eq!(m.scalar(), n.scalar(), epsilon = 1e-5);
+    assert_abs_diff_eq!(m.e23(), n.e23(), epsilon = 1e-5);
+    assert_abs_diff_eq!(m.e31(), n.e31(), epsilon = 1e-5);
+    assert_abs_diff_eq!(m.e12(), n.e12(), epsilon = 1e-5);
+    assert_abs_diff_eq!(m.e01(), n.e01(), epsilon = 1e-5);
+    assert_abs_diff_eq!(m.e02(), n.e02(), epsilon = 1e-5);
+    assert_abs_diff_eq!(m.e03(), n.e03(), epsilon = 1e-5);
+    assert_abs_diff_eq!(m.e0123(), n.e0123(), epsilon = 1e-5);
+
+    let mat: nalgebra::Matrix4<f32> = m.as_mat4x4().into();
+    let c = mat.transform_point(&Point3::new(1.0, 2.0, 3.0));
+    assert_abs_diff_eq!(c.x, b.x, epsilon = 1e-5);
+    assert_abs_diff_eq!(c.y, b.y, epsilon = 1e-5);
+    assert_abs_diff_eq!(c.z, b.z, epsilon = 1e-5);
+}
@@ -0,0 +1,78 @@
+use klein::{Line, Motor, Point, Rotor, Translator};
+
+fn pose() -> Motor {
+    Motor::from_translator(Translator::new(2.0, 1.0, -1.0, 0.5))
+        * Motor::from_rotor(Rotor::new(0.7, 0.3, 1.0, -0.4))
+}
+
+#[test]
+fn integrate_twist() {
+    // Pure angular velocity about z and pure linear velocity along x.
+    let spin = Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    let m = Motor::IDENTITY.integrate(spin, core::f32::consts::FRAC_PI_2);
+    let p = m.conj_point(Point::new(1.0, 0.0, 0.0));
+    assert!(p.x().abs() < 1e-5 && (p.y() - 1.0).abs() < 1e-5);
+
+    let slide = Line::new(3.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    let p = Motor::IDENTITY.integrate(slide, 0.5).conj_origin();
+    assert!((p.x() - 1.5).abs() < 1e-5 && p.y().abs() < 1e-5);
+
+    // Integrating in small steps matches a single step for a constant twist.
+    let twist = Line::new(0.5, -1.0, 0.2, 0.3, 0.8, -0.6);
+    let once = pose().integrate(twist, 1.0);
+    let stepped = (0..100).fold(pose(), |m, _| m.integrate(twist, 0.01));
+    assert!(once.approx_eq(stepped, 1e-4));
+}
+
+#[test]
+fn twist_between_motors() {
+    let a = pose();
+    let twist = Line::new(0.5, -1.0, 0.2, 0.3, 0.8, -0.6);
+    let b = a.integrate(twist, 0.25);
+    assert!(Motor::twist_between(a, b, 0.25).approx_eq(twist, 1e-3));
+    assert!(a
+        .integrate(Motor::twist_between(a, b, 0.25), 0.25)
+        .approx_eq(b, 1e-4));
+
+    // The sign of the motor does not matter.
+    assert!(Motor::twist_between(a, -b, 0.25).approx_eq(twist, 1e-3));
+}
+
+#[test]
+fn point_velocity() {
+    let twist = Line::new(0.5, -1.0, 0.2, 0.3, 0.8, -0.6);
+    let p = Point::new(1.0, -2.0, 0.5);
+    let v = twist.velocity_at(p);
+
+    let dt = 1e-3;
+    let q = Motor::IDENTITY.integrate(twist, dt).conj_point(p);
+    assert!(((q.x() - p.x()) / dt - v.x()).abs() < 1e-2);
+    assert!(((q.y() - p.y()) / dt - v.y()).abs() < 1e-2);
+    assert!(((q.z() - p.z()) / dt - v.z()).abs() < 1e-2);
+
+    // Points on the axis of a pure rotation stay put.
+    let axis = Point::new(0.0, 0.0, 0.0) & Point::new(0.0, 0.0, 1.0);
+    let v = axis.velocity_at(Point::new(0.0, 0.0, 4.0));
+    assert_eq!([v.x(), v.y(), v.z()], [0.0, 0.0, 0.0]);
+}
+
+#[test]
+fn body_and_world_twists() {
+    let m = pose();
+    let body = Line::new(0.5, -1.0, 0.2, 0.3, 0.8, -0.6);
+    let world = m.twist_to_world(body);
+    assert!(m.twist_to_body(world).approx_eq(body, 1e-5));
+
+    // Applying the body twist on the right equals the world twist on the left.
+    let dt = 0.1;
+    let right = m * (body * (-0.5 * dt)).exp();
+    assert!(m.integrate(world, dt).approx_eq(right, 1e-5));
+
+    // Both give the same point velocities.
+    let p = Point::new(0.3, 0.1, -0.2);
+    let v_world = world.velocity_at(m.conj_point(p));
+    let v_body = m.conj_dir(body.velocity_at(p));
+    assert!((v_world.x() - v_body.x()).abs() < 1e-4);
+    assert!((v_world.y() - v_body.y()).abs() < 1e-4);
+    assert!((v_world.z() - v_body.z()).abs() < 1e-4);
+}
@@ -16,11 +16,11 @@ fn plane_plane() {
     assert_eq!(p12.e03(), 2.0);
     assert_eq!(p12.e0123(), 0.0);
 
+    let p1 = p1.normalized();
+    let p2 = p2.normalized();
     let p3: Plane = (p1 * p2).sqrt().conj_plane(p2);
     assert!(p3.approx_eq(p1, 0.001));
 
-    let p1 = p1.normalized();
-
     let m: Motor = p1 * p1;
     abs_diff_eq!(m.scalar(), 1.0);
 
@@ -1,7 +1,7 @@
-use approx::abs_diff_eq;
+use approx::{abs_diff_eq, assert_abs_diff_eq};
 use klein::{
     arch::{f32x4, sw02},
-    Line, Motor, Plane, Point, Rotor, Translator,
+    Branch, IdealLine, Line, Motor, Plane, Point, Rotor, Translator,
 };
 use std::f32::consts::FRAC_PI_2;
 
@@ -55,6 +55,32 @@ fn reflect_point() {
     assert_eq!(p3.e123(), 14.0);
 }
 
+#[test]
+fn reflect_variadic() {
+    let p = Plane::new(3.0, 2.0, 1.0, -1.0);
+
+    let ls = [
+        Line::new(1.0, -2.0, 3.0, 6.0, 5.0, -4.0),
+        Line::new(1.0, -2.0, 3.0, 6.0, 5.0, -4.0),
+    ];
+    for l in p.reflect_lines(ls.iter().copied()) {
+        assert_eq!(l.e01(), 28.0);
+        assert_eq!(l.e02(), -72.0);
+        assert_eq!(l.e03(), 32.0);
+        assert_eq!(l.e12(), 104.0);
+        assert_eq!(l.e31(), 26.0);
+        assert_eq!(l.e23(), 60.0);
+    }
+
+    let ps = [Point::new(4.0, -2.0, -1.0), Point::new(4.0, -2.0, -1.0)];
+    for p in p.reflect_points(ps.iter().copied()) {
+        assert_eq!(p.e021(), -26.0);
+        assert_eq!(p.e013(), -52.0);
+        assert_eq!(p.e032(), 20.0);
+        assert_eq!(p.e123(), 14.0);
+    }
+}
+
 #[test]
 //#[ignore]
 fn rotor_line() {
@@ -72,6 +98,43 @@ fn rotor_line() {
     assert_eq!(l2.e23(), -36.0);
 }
 
+#[test]
+fn rotor_line_variadic() {
+    let r = Rotor::from(f32x4::from_array([1.0, 4.0, -3.0, 2.0]));
+    // a*e01 + b*e01 + c*e02 + d*e23 + e*e31 + f*e12
+    let ls = [
+        Line::new(-1.0, 2.0, -3.0, -6.0, 5.0, 4.0),
+        Line::new(-1.0, 2.0, -3.0, -6.0, 5.0, 4.0),
+    ];
+
+    for l in r.conj_line_slice(ls.iter().copied()) {
+        assert_eq!(l.e01(), -110.0);
+        assert_eq!(l.e02(), 20.0);
+        assert_eq!(l.e03(), 10.0);
+        assert_eq!(l.e12(), -240.0);
+        assert_eq!(l.e31(), 102.0);
+        assert_eq!(l.e23(), -36.0);
+    }
+
+    let bs = [Branch::new(-6.0, 5.0, 4.0), Branch::new(-6.0, 5.0, 4.0)];
+    for b in r.conj_branch_slice(bs.iter().copied()) {
+        assert_eq!(b.e12(), -240.0);
+        assert_eq!(b.e31(), 102.0);
+        assert_eq!(b.e23(), -36.0);
+    }
+
+    let ls = [
+        IdealLine::new(-1.0, 2.0, -3.0),
+        IdealLine::new(-1.0, 2.0, -3.0),
+    ];
+    let expected = r.conj_line(Line::new(-1.0, 2.0, -3.0, 0.0, 0.0, 0.0));
+    for l in r.conj_ideal_line_slice(ls.iter().copied()) {
+        assert_eq!(l.e01(), expected.e01());
+        assert_eq!(l.e02(), expected.e02());
+        assert_eq!(l.e03(), expected.e03());
+    }
+}
+
 #[test]
 fn rotor_point() {
     let r = Rotor::new(FRAC_PI_2, 0.0, 0.0, 1.0);
@@ -108,6 +171,40 @@ fn translator_line() {
     assert_eq!(l2.e23(), -6.0);
 }
 
+#[test]
+fn translator_variadic() {
+    let t = Translator::from(f32x4::from_array([0.0, -5.0, -2.0, 2.0]));
+
+    let ls = [
+        Line::new(-1.0, 2.0, -3.0, -6.0, 5.0, 4.0),
+        Line::new(-1.0, 2.0, -3.0, -6.0, 5.0, 4.0),
+    ];
+    for l in t.conj_lines(ls.iter().copied()) {
+        assert_eq!(l.e01(), 35.0);
+        assert_eq!(l.e02(), -14.0);
+        assert_eq!(l.e03(), 71.0);
+        assert_eq!(l.e12(), 4.0);
+        assert_eq!(l.e31(), 5.0);
+        assert_eq!(l.e23(), -6.0);
+    }
+
+    let t = Translator::new(1.0, 0.0, 0.0, 1.0);
+    let ps = [Point::new(1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)];
+    for p in t.conj_points(ps.iter().copied()) {
+        assert_eq!(p.x(), 1.0);
+        assert_eq!(p.y(), 0.0);
+        assert_eq!(p.z(), 1.0);
+    }
+
+    let ps = [
+        Plane::new(0.0, 0.0, 1.0, 0.0),
+        Plane::new(0.0, 0.0, 1.0, 0.0),
+    ];
+    for p in t.conj_planes(ps.iter().copied()) {
+        assert_eq!(p, t.conj_plane(Plane::new(0.0, 0.0, 1.0, 0.0)));
+    }
+}
+
 #[test]
 fn construct_motor() {
     let r = Rotor::new(FRAC_PI_2, 0.0, 0.0, 1.0);
@@ -158,22 +255,19 @@ fn motor_plane() {
 }
 
 #[test]
-#[ignore]
 fn motor_plane_variadic() {
-    /*
-    motor m{1.0, 4.0, 3.0, 2.0, 5.0, 6.0, 7.0, 8.0};
-    plane ps[2] = {{3.0, 2.0, 1.0, -1.0}, {3.0, 2.0, 1.0, -1.0}};
-    plane ps2[2];
-    m(ps, ps2, 2);
-
-    for (size_t i = 0; i != 2; ++i)
-    {
-        assert_eq!(ps2[i].x(), 78.0);
-        assert_eq!(ps2[i].y(), 60.0);
-        assert_eq!(ps2[i].z(), 54.0);
-        assert_eq!(ps2[i].d(), 358.0);
+    let m = Motor::new(1.0, 4.0, 3.0, 2.0, 5.0, 6.0, 7.0, 8.0);
+    let ps = [
+        Plane::new(3.0, 2.0, 1.0, -1.0),
+        Plane::new(3.0, 2.0, 1.0, -1.0),
+    ];
+
+    for p in m.conj_planes(ps.iter().copied()) {
+        assert_eq!(p.x(), 78.0);
+        assert_eq!(p.y(), 60.0);
+        assert_eq!(p.z(), 54.0);
+        assert_eq!(p.d(), 358.0);
     }
-    */
 }
 
 #[test]
@@ -188,22 +282,16 @@ fn motor_point() {
 }
 
 #[test]
-#[ignore]
 fn motor_point_variadic() {
-    /*
-    motor m{1.0, 4.0, 3.0, 2.0, 5.0, 6.0, 7.0, 8.0};
-    point ps[2] = {{-1.0, 1.0, 2.0}, {-1.0, 1.0, 2.0}};
-    point ps2[2];
-    m(ps, ps2, 2);
-
-    for (size_t i = 0; i != 2; ++i)
-    {
-        assert_eq!(ps2[i].x(), -12.0);
-        assert_eq!(ps2[i].y(), -86.0);
-        assert_eq!(ps2[i].z(), -86.0);
-        assert_eq!(ps2[i].w(), 30.0);
+    let m = Motor::new(1.0, 4.0, 3.0, 2.0, 5.0, 6.0, 7.0, 8.0);
+    let ps = [Point::new(-1.0, 1.0, 2.0), Point::new(-1.0, 1.0, 2.0)];
+
+    for p in m.conj_points(ps.iter().copied()) {
+        assert_eq!(p.x(), -12.0);
+        assert_eq!(p.y(), -86.0);
+        assert_eq!(p.z(), -86.0);
+        assert_eq!(p.w(), 30.0);
     }
-    */
 }
 
 #[test]
@@ -222,26 +310,45 @@ fn motor_line() {
 }
 
 #[test]
-#[ignore]
 fn motor_line_variadic() {
-    /*
-    motor m{2.0, 4.0, 3.0, -1.0, -5.0, -2.0, 2.0, -3.0};
+    let m = Motor::new(2.0, 4.0, 3.0, -1.0, -5.0, -2.0, 2.0, -3.0);
     // a*e01 + b*e01 + c*e02 + d*e23 + e*e31 + f*e12
-    line ls[2]
-        = {{-1.0, 2.0, -3.0, -6.0, 5.0, 4.0}, {-1.0, 2.0, -3.0, -6.0, 5.0, 4.0}};
-    line ls2[2];
-    m(ls, ls2, 2);
-
-    for (size_t i = 0; i != 2; ++i)
-    {
-        assert_eq!(ls2[i].e01(), 6.0);
-        assert_eq!(ls2[i].e02(), 522.0);
-        assert_eq!(ls2[i].e03(), 96.0);
-        assert_eq!(ls2[i].e12(), -214.0);
-        assert_eq!(ls2[i].e31(), -148.0);
-        assert_eq!(ls2[i].e23(), -40.0);
+    let ls = [
+        Line::new(-1.0, 2.0, -3.0, -6.0, 5.0, 4.0),
+        Line::new(-1.0, 2.0, -3.0, -6.0, 5.0, 4.0),
+    ];
+
+    for l in m.conj_lines(ls.iter().copied()) {
+        assert_eq!(l.e01(), 6.0);
+        assert_eq!(l.e02(), 522.0);
+        assert_eq!(l.e03(), 96.0);
+        assert_eq!(l.e12(), -214.0);
+        assert_eq!(l.e31(), -148.0);
+        assert_eq!(l.e23(), -40.0);
+    }
+}
+
+#[test]
+fn motor_branch_ideal_line_variadic() {
+    let m = Motor::new(2.0, 4.0, 3.0, -1.0, -5.0, -2.0, 2.0, -3.0);
+    let bs = [Branch::new(-6.0, 5.0, 4.0), Branch::new(-6.0, 5.0, 4.0)];
+    let expected = m.conj_line(Line::new(0.0, 0.0, 0.0, -6.0, 5.0, 4.0));
+    for l in m.conj_branches(bs.iter().copied()) {
+        assert_eq!(l, expected);
+    }
+
+    // Ideal lines are only affected by the rotational part of the motor
+    let r = Rotor::from(f32x4::from_array([2.0, 4.0, 3.0, -1.0]));
+    let ls = [
+        IdealLine::new(-1.0, 2.0, -3.0),
+        IdealLine::new(-1.0, 2.0, -3.0),
+    ];
+    let expected = r.conj_line(Line::new(-1.0, 2.0, -3.0, 0.0, 0.0, 0.0));
+    for l in m.conj_ideal_lines(ls.iter().copied()) {
+        assert_eq!(l.e01(), expected.e01());
+        assert_eq!(l.e02(), expected.e02());
+        assert_eq!(l.e03(), expected.e03());
     }
-    */
 }
 
 #[test]
@@ -256,21 +363,52 @@ fn motor_origin() {
 }
 
 #[test]
-#[ignore]
 fn motor_to_matrix4x4() {
-    /*
-    motor m{1.0, 4.0, 3.0, 2.0, 5.0, 6.0, 7.0, 8.0};
-    __m128 p1    = _mm_set_ps(1.0, 2.0, 1.0, -1.0);
-    mat4x4 m_mat = m.as_mat4x4();
-    __m128 p2    = m_mat(p1);
-    float buf[4];
-    _mm_storeu_ps(buf, p2);
+    let m = Motor::new(1.0, 4.0, 3.0, 2.0, 5.0, 6.0, 7.0, 8.0);
+    let p1 = Point::new(-1.0, 1.0, 2.0);
+    let m_mat = m.as_mat4x4();
+    let p2 = m_mat.apply(p1);
 
-    assert_eq!(buf[0], -12.0);
-    assert_eq!(buf[1], -86.0);
-    assert_eq!(buf[2], -86.0);
-    assert_eq!(buf[3], 30.0);
-    */
+    assert_eq!(p2.x(), -12.0);
+    assert_eq!(p2.y(), -86.0);
+    assert_eq!(p2.z(), -86.0);
+    assert_eq!(p2.w(), 30.0);
+}
+
+#[test]
+fn motor_to_matrix4x4_conj() {
+    let r = Rotor::new(1.2, 1.0, -2.0, 0.5);
+    let t = Translator::new(3.0, 1.0, 2.0, -1.0);
+    let m: Motor = t * r;
+    let m_mat = m.as_mat4x4();
+
+    for &p1 in &[Point::new(0.0, 0.0, 0.0), Point::new(-2.0, 3.0, 0.5)] {
+        let p2 = m_mat.apply(p1);
+        let p3 = m.conj_point(p1);
+        assert_abs_diff_eq!(p2.x(), p3.x(), epsilon = 1e-5);
+        assert_abs_diff_eq!(p2.y(), p3.y(), epsilon = 1e-5);
+        assert_abs_diff_eq!(p2.z(), p3.z(), epsilon = 1e-5);
+        assert_abs_diff_eq!(p2.w(), p3.w(), epsilon = 1e-5);
+    }
+
+    // The translation lands in the last column.
+    let o = m.conj_origin();
+    let w = m_mat.to_cols()[3];
+    assert_abs_diff_eq!(w[0], o.x(), epsilon = 1e-5);
+    assert_abs_diff_eq!(w[1], o.y(), epsilon = 1e-5);
+    assert_abs_diff_eq!(w[2], o.z(), epsilon = 1e-5);
+    assert_abs_diff_eq!(w[3], 1.0, epsilon = 1e-5);
+}
+
+#[test]
+fn rotor_to_matrix4x4() {
+    let r = Rotor::new(FRAC_PI_2, 0.0, 0.0, 1.0);
+    let p = r.as_mat4x4().apply(Point::new(1.0, 0.0, 0.0));
+
+    assert_abs_diff_eq!(p.x(), 0.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(p.y(), 1.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(p.z(), 0.0);
+    assert_abs_diff_eq!(p.w(), 1.0, epsilon = 1e-6);
 }
 
 #[test]
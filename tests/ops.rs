@@ -0,0 +1,84 @@
+use klein::{Dual, Line, Mat4x4, Motor, Plane, Point, Rotor, Translator};
+
+#[test]
+fn identity() {
+    let p = Point::new(1.0, -2.0, 3.0);
+
+    assert_eq!(Motor::IDENTITY.conj_point(p), p);
+    assert_eq!(Rotor::IDENTITY.conj_point(p), p);
+    assert_eq!(Translator::IDENTITY.conj_point(p), p);
+    assert_eq!(Mat4x4::IDENTITY.apply(p), p);
+
+    assert_eq!(Motor::default(), Motor::IDENTITY);
+    assert_eq!(Rotor::default(), Rotor::IDENTITY);
+    assert_eq!(Translator::default(), Translator::IDENTITY);
+    assert_eq!(Mat4x4::default(), Mat4x4::IDENTITY);
+    assert_eq!(Point::default(), Point::new(0.0, 0.0, 0.0));
+    assert_eq!(Plane::default(), Plane::new(0.0, 0.0, 0.0, 0.0));
+    assert_eq!(Line::default(), Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+    assert_eq!(Dual::default(), Dual::new(0.0, 0.0));
+}
+
+#[test]
+fn assign() {
+    let mut l = Line::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    l += Line::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0);
+    assert_eq!(l, Line::new(2.0, 3.0, 4.0, 5.0, 6.0, 7.0));
+    l -= Line::new(2.0, 2.0, 2.0, 2.0, 2.0, 2.0);
+    assert_eq!(l, Line::new(0.0, 1.0, 2.0, 3.0, 4.0, 5.0));
+    l *= 2.0;
+    assert_eq!(l, Line::new(0.0, 2.0, 4.0, 6.0, 8.0, 10.0));
+    l /= 2.0;
+    assert!(l.approx_eq(Line::new(0.0, 1.0, 2.0, 3.0, 4.0, 5.0), 1e-6));
+
+    let r = Rotor::new(1.0, 1.0, 0.0, 0.0);
+    let t = Translator::new(2.0, 0.0, 1.0, 0.0);
+    let m = Motor::new(0.8, 0.2, -0.1, 0.3, 0.5, -0.4, 0.1, 0.2);
+
+    let mut a = m;
+    a *= r;
+    a *= t;
+    assert_eq!(a, m * r * t);
+    a /= t;
+    assert!(a.approx_eq(m * r, 1e-6));
+
+    let mut b = r;
+    b *= r;
+    assert_eq!(b, r * r);
+}
+
+#[test]
+fn sum_product() {
+    let points = [
+        Point::new(1.0, 0.0, 0.0),
+        Point::new(0.0, 2.0, 0.0),
+        Point::new(0.0, 0.0, 3.0),
+    ];
+    let sum: Point = points.iter().sum();
+    assert_eq!(sum.w(), 3.0);
+    assert_eq!([sum.x(), sum.y(), sum.z()], [1.0, 2.0, 3.0]);
+
+    let r1 = Rotor::new(0.3, 1.0, 0.0, 0.0);
+    let r2 = Rotor::new(-1.2, 0.0, 1.0, 1.0);
+    let t = Translator::new(1.5, 1.0, 1.0, 0.0);
+    let motors = [
+        Motor::from_rotor(r1),
+        Motor::from_translator(t),
+        Motor::from_rotor(r2),
+    ];
+
+    // The last motor is applied first.
+    let m: Motor = motors.iter().product();
+    let p = Point::new(1.0, 2.0, 3.0);
+    let expected = r1.conj_point(t.conj_point(r2.conj_point(p)));
+    let q = m.conj_point(p);
+    assert!((q.x() - expected.x()).abs() < 1e-5);
+    assert!((q.y() - expected.y()).abs() < 1e-5);
+    assert!((q.z() - expected.z()).abs() < 1e-5);
+
+    let empty: [Motor; 0] = [];
+    assert_eq!(empty.iter().product::<Motor>(), Motor::IDENTITY);
+
+    let r: Rotor = vec![r1, r2].into_iter().product();
+    assert_eq!(r, r1 * r2);
+}
@@ -0,0 +1,149 @@
+use approx::assert_abs_diff_eq;
+use core::f32::consts::{FRAC_PI_2, PI};
+use klein::pga2d::{Line2, Motor2, Point2, Rotor2, Translator2};
+
+fn assert_point(p: Point2, x: f32, y: f32) {
+    let p = p.normalized();
+    assert_abs_diff_eq!(p.x(), x, epsilon = 1e-5);
+    assert_abs_diff_eq!(p.y(), y, epsilon = 1e-5);
+}
+
+#[test]
+fn meet_lines() {
+    // x = 1 and y = 2
+    let a = Line2::new(1.0, 0.0, -1.0);
+    let b = Line2::new(0.0, 1.0, -2.0);
+    assert_point(a ^ b, 1.0, 2.0);
+
+    // Parallel lines meet at infinity.
+    let c = Line2::new(1.0, 0.0, 3.0);
+    let p = a ^ c;
+    assert_eq!(p.w(), 0.0);
+}
+
+#[test]
+fn join_points() {
+    let a = Point2::new(1.0, 2.0);
+    let b = Point2::new(3.0, -1.0);
+    let l = a & b;
+    for &p in &[a, b] {
+        assert_abs_diff_eq!(l.x() * p.x() + l.y() * p.y() + l.d(), 0.0, epsilon = 1e-5);
+    }
+
+    // The normalized join has the distance to the origin in d.
+    let l = (Point2::new(0.0, 2.0) & Point2::new(1.0, 2.0)).normalized();
+    assert_abs_diff_eq!(l.d().abs(), 2.0, epsilon = 1e-6);
+}
+
+#[test]
+fn inner_products() {
+    let a = Line2::new(1.0, 0.0, 0.0);
+    let b = Line2::new(0.6, 0.8, 4.0);
+    assert_abs_diff_eq!(a | b, 0.6, epsilon = 1e-6);
+
+    // Perpendicular to the y axis through (2, 3) is y = 3.
+    let l: Line2 = Point2::new(2.0, 3.0) | a;
+    assert_abs_diff_eq!(l.x(), 0.0);
+    assert_abs_diff_eq!(-l.d() / l.y(), 3.0);
+
+    assert_abs_diff_eq!(Point2::new(2.0, 3.0) | Point2::new(1.0, 0.0), -1.0);
+}
+
+#[test]
+fn rotor_translator() {
+    let r = Rotor2::new(FRAC_PI_2);
+    assert_point(r.conj_point(Point2::new(1.0, 0.0)), 0.0, 1.0);
+
+    let t = Translator2::new(2.0, 0.0, 1.0);
+    assert_point(t.conj_point(Point2::new(1.0, 0.0)), 1.0, 2.0);
+    assert_eq!(Translator2::new(2.0, 0.0, 0.0), Translator2::IDENTITY);
+
+    // Rotate first, then translate.
+    let m: Motor2 = t * r;
+    assert_point(m.conj_point(Point2::new(1.0, 0.0)), 0.0, 3.0);
+    let m = Motor2::from_translator(t) * Motor2::from_rotor(r);
+    assert_point(m.conj_point(Point2::new(1.0, 0.0)), 0.0, 3.0);
+
+    let l = r.conj_line(Line2::new(1.0, 0.0, -1.0));
+    assert!(l.approx_eq(Line2::new(0.0, 1.0, -1.0), 1e-6));
+
+    let tt = t * t;
+    assert_point(tt.conj_point(Point2::new(0.0, 0.0)), 0.0, 4.0);
+    let rr = r * r;
+    assert_point(rr.conj_point(Point2::new(1.0, 0.0)), -1.0, 0.0);
+}
+
+#[test]
+fn motor_from_point() {
+    let m = Motor2::from_point(PI, Point2::new(1.0, 1.0));
+    assert_point(m.conj_point(Point2::new(0.0, 0.0)), 2.0, 2.0);
+    assert_point(m.conj_point(Point2::new(1.0, 1.0)), 1.0, 1.0);
+
+    let points = [Point2::new(0.0, 1.0), Point2::new(3.0, 1.0)];
+    let moved: Vec<Point2> = m.conj_points(points.iter().copied()).collect();
+    assert_point(moved[0], 2.0, 1.0);
+    assert_point(moved[1], -1.0, 1.0);
+
+    let m = Motor2::from_point(FRAC_PI_2, Point2::new(0.0, 0.0));
+    assert!(m.approx_eq(Motor2::from_rotor(Rotor2::new(FRAC_PI_2)), 1e-6));
+}
+
+#[test]
+fn line_products_are_motors() {
+    // Reflecting in two lines through the origin an angle apart rotates by
+    // twice the angle.
+    let a = Line2::new(1.0, 0.0, 0.0);
+    let b = Line2::new(1.0, 1.0, 0.0).normalized();
+    let m: Motor2 = b * a;
+    assert_point(m.conj_point(Point2::new(1.0, 0.0)), 0.0, 1.0);
+
+    // Parallel lines translate by twice their distance.
+    let c = Line2::new(1.0, 0.0, -1.5);
+    let m: Motor2 = c * a;
+    assert_point(m.conj_point(Point2::new(0.0, 4.0)), 3.0, 4.0);
+
+    let p = Point2::new(0.0, 0.0) * Point2::new(1.0, 0.0);
+    assert_abs_diff_eq!(p.norm(), 1.0);
+}
+
+#[test]
+fn reflections() {
+    let mirror = Line2::new(1.0, 0.0, -1.0);
+    assert_point(mirror.reflect_point(Point2::new(0.0, 3.0)), 2.0, 3.0);
+    assert!(mirror.reflect_point(Point2::new(0.0, 3.0)).w() > 0.0);
+
+    let l = mirror.reflect_line(Line2::new(1.0, 1.0, 0.0));
+    // The reflected line passes through (2, 0) and (1, -1).
+    for &(x, y) in &[(2.0, 0.0), (1.0, -1.0)] {
+        assert_abs_diff_eq!(l.x() * x + l.y() * y + l.d(), 0.0, epsilon = 1e-5);
+    }
+    assert!(mirror.reflect_line(mirror).approx_eq(mirror, 1e-6));
+}
+
+#[test]
+fn exp_log() {
+    let m = Translator2::new(3.0, 1.0, -2.0) * Rotor2::new(1.2);
+    let b = m.log();
+    assert!(b.exp().approx_eq(m, 1e-5));
+
+    // A pure translation has an ideal logarithm.
+    let t = Motor2::from(Translator2::new(2.0, 1.0, 1.0));
+    let b = t.log();
+    assert_abs_diff_eq!(b.w(), 0.0);
+    assert!(b.exp().approx_eq(t, 1e-6));
+
+    // The exponential of a point rotates about it.
+    let m = (Point2::new(1.0, 0.0) * -0.25 * PI).exp();
+    assert_point(m.conj_point(Point2::new(1.0, 1.0)), 0.0, 0.0);
+}
+
+#[test]
+fn sqrt_inverse() {
+    let m = Translator2::new(3.0, 1.0, -2.0) * Rotor2::new(1.2);
+    let s = m.sqrt();
+    assert!((s * s).approx_eq(m, 1e-5));
+
+    let i = (m * 2.0).inverse();
+    assert!((i * m * 2.0).approx_eq(Motor2::IDENTITY, 1e-5));
+    assert!((m * m.reversed()).approx_eq(Motor2::IDENTITY, 1e-5));
+}
@@ -0,0 +1,175 @@
+use klein::{
+    polytope::{clip_polygon, ConvexPolytope},
+    volume::Aabb,
+    Motor, Plane, Point, Rotor, Translator,
+};
+
+fn cube() -> ConvexPolytope {
+    ConvexPolytope::from_aabb(&Aabb::new([-1.0, -1.0, -1.0], [1.0, 2.0, 3.0]))
+}
+
+fn close(p: Point, x: f32, y: f32, z: f32) -> bool {
+    (p.x() - x).abs() < 1e-4 && (p.y() - y).abs() < 1e-4 && (p.z() - z).abs() < 1e-4
+}
+
+#[test]
+fn containment() {
+    let cube = cube();
+    assert!(cube.contains_point(Point::new(0.0, 0.0, 0.0)));
+    assert!(cube.contains_point(Point::new(1.0, 2.0, 3.0)));
+    assert!(!cube.contains_point(Point::new(0.0, 2.5, 0.0)));
+
+    // Homogeneous weights do not change the answer.
+    let p = Point::new(0.5, 0.5, 0.5) * -2.0;
+    assert!(cube.contains_point(p));
+
+    let d: Vec<f32> = cube.distances(Point::new(0.0, 0.0, 0.0)).collect();
+    assert!((d[1] + 1.0).abs() < 1e-5 && (d[5] + 3.0).abs() < 1e-5);
+
+    // Unnormalized planes are normalized on construction.
+    let slab = ConvexPolytope::new(vec![
+        Plane::new(0.0, 0.0, 4.0, -4.0),
+        Plane::new(0.0, 0.0, -2.0, 0.0),
+    ]);
+    assert!((slab.distances(Point::new(0.0, 0.0, 3.0)).next().unwrap() - 2.0).abs() < 1e-5);
+}
+
+#[test]
+fn segment_and_line_clipping() {
+    let cube = cube();
+    let (a, b) = cube
+        .clip_segment(Point::new(-3.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0))
+        .unwrap();
+    assert!(close(a, -1.0, 0.0, 0.0) && close(b, 0.0, 0.0, 0.0));
+    assert!(cube
+        .clip_segment(Point::new(-3.0, 0.0, 0.0), Point::new(-2.0, 0.0, 0.0))
+        .is_none());
+
+    let l = Point::new(0.0, 0.0, 1.0) & Point::new(0.0, 1.0, 1.0);
+    let (a, b) = cube.clip_line(l).unwrap();
+    assert!(close(a, 0.0, -1.0, 1.0) && close(b, 0.0, 2.0, 1.0));
+
+    let miss = Point::new(5.0, 0.0, 0.0) & Point::new(5.0, 1.0, 0.0);
+    assert!(cube.clip_line(miss).is_none());
+
+    // Lines through an open slab are unbounded.
+    let slab = ConvexPolytope::new(vec![Plane::new(0.0, 0.0, 1.0, -1.0)]);
+    assert!(slab.clip_line(l).is_none());
+}
+
+#[test]
+fn vertices_and_edges() {
+    let cube = cube();
+    let vertices = cube.vertices();
+    assert_eq!(vertices.len(), 8);
+    assert!(vertices.iter().any(|&p| close(p, 1.0, 2.0, 3.0)));
+    assert!(vertices.iter().any(|&p| close(p, -1.0, -1.0, -1.0)));
+
+    let edges = cube.edges();
+    assert_eq!(edges.len(), 12);
+    let length: f32 = edges
+        .iter()
+        .map(|(a, b)| {
+            let d = [a.x() - b.x(), a.y() - b.y(), a.z() - b.z()];
+            (d[0] * d[0] + d[1] * d[1] + d[2] * d[2]).sqrt()
+        })
+        .sum();
+    assert!((length - 4.0 * (2.0 + 3.0 + 4.0)).abs() < 1e-3);
+
+    // A tetrahedron, with a redundant plane that touches only a vertex.
+    let tetra = ConvexPolytope::new(vec![
+        Plane::new(-1.0, 0.0, 0.0, 0.0),
+        Plane::new(0.0, -1.0, 0.0, 0.0),
+        Plane::new(0.0, 0.0, -1.0, 0.0),
+        Plane::new(1.0, 1.0, 1.0, -1.0),
+        Plane::new(1.0, 1.0, -1.0, -1.0),
+    ]);
+    assert_eq!(tetra.vertices().len(), 4);
+    assert_eq!(tetra.edges().len(), 6);
+}
+
+#[test]
+fn motor_transform() {
+    let m = Motor::from_translator(Translator::new(2.0, 0.0, 1.0, 1.0))
+        * Motor::from_rotor(Rotor::new(0.8, 1.0, -0.5, 0.2));
+    let cube = cube();
+    let moved = m.conj_polytope(&cube);
+
+    let inside = Point::new(0.9, 1.9, 2.9);
+    let outside = Point::new(1.1, 0.0, 0.0);
+    assert!(moved.contains_point(m.conj_point(inside)));
+    assert!(!moved.contains_point(m.conj_point(outside)));
+
+    let vertices = moved.vertices();
+    assert_eq!(vertices.len(), 8);
+    for v in cube.vertices() {
+        let v = m.conj_point(v);
+        assert!(vertices.iter().any(|&p| (p.x() - v.x()).abs() < 1e-3
+            && (p.y() - v.y()).abs() < 1e-3
+            && (p.z() - v.z()).abs() < 1e-3));
+    }
+}
+
+fn square() -> Vec<Point> {
+    vec![
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(2.0, 0.0, 0.0),
+        Point::new(2.0, 2.0, 0.0),
+        Point::new(0.0, 2.0, 0.0),
+    ]
+}
+
+#[test]
+fn polygon_clipping() {
+    // Cutting a corner off the square leaves a pentagon.
+    let plane = Plane::new(1.0, 1.0, 0.0, -3.0);
+    let clipped = clip_polygon(&square(), plane);
+    assert_eq!(clipped.len(), 5);
+    assert!(close(clipped[1], 2.0, 0.0, 0.0));
+    assert!(close(clipped[2], 2.0, 1.0, 0.0));
+    assert!(close(clipped[3], 1.0, 2.0, 0.0));
+
+    // Entirely in front or entirely behind.
+    assert!(clip_polygon(&square(), Plane::new(1.0, 0.0, 0.0, 5.0)).is_empty());
+    assert_eq!(
+        clip_polygon(&square(), Plane::new(1.0, 0.0, 0.0, -5.0)).len(),
+        4
+    );
+    assert!(clip_polygon(&[], plane).is_empty());
+
+    // Vertices on the plane are kept without duplicates.
+    let on = clip_polygon(&square(), Plane::new(1.0, 0.0, 0.0, -2.0));
+    assert_eq!(on.len(), 4);
+}
+
+#[test]
+fn polygon_clipping_weights() {
+    // Scaling the vertices by any nonzero weight gives the same result.
+    let plane = Plane::new(1.0, 1.0, 0.0, -3.0);
+    let weighted: Vec<Point> = square()
+        .into_iter()
+        .zip(&[2.0, -0.5, 3.0, -4.0])
+        .map(|(p, &w)| p * w)
+        .collect();
+    let a = clip_polygon(&square(), plane);
+    let b = clip_polygon(&weighted, plane);
+    assert_eq!(a.len(), b.len());
+    for (a, b) in a.iter().zip(&b) {
+        assert!(close(*b, a.x(), a.y(), a.z()));
+        assert!((b.w() - 1.0).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn polygon_clipping_by_polytope() {
+    let cube = ConvexPolytope::from_aabb(&Aabb::new([0.5, 0.5, -1.0], [1.5, 3.0, 1.0]));
+    let clipped = cube.clip_polygon(&square());
+    assert_eq!(clipped.len(), 4);
+    for &(x, y) in &[(0.5, 0.5), (1.5, 0.5), (1.5, 2.0), (0.5, 2.0)] {
+        assert!(clipped.iter().any(|&p| close(p, x, y, 0.0)));
+    }
+    assert!(clipped.iter().all(|&p| cube.contains_point(p)));
+
+    let away = ConvexPolytope::from_aabb(&Aabb::new([5.0; 3], [6.0; 3]));
+    assert!(away.clip_polygon(&square()).is_empty());
+}
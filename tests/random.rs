@@ -0,0 +1,95 @@
+#![cfg(feature = "rand")]
+
+use klein::{Direction, Line, Motor, Plane, Point, Region, Rotor};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const N: usize = 4096;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(0x6b6c_6569_6e00)
+}
+
+#[test]
+fn rotor_uniform() {
+    let mut rng = rng();
+    let mut mean = [0.0; 3];
+    for _ in 0..N {
+        let r: Rotor = rng.gen();
+        let n = r.scalar().powi(2) + r.e23().powi(2) + r.e13().powi(2) + r.e12().powi(2);
+        assert!((n - 1.0).abs() < 1e-5);
+
+        // The image of a fixed point is uniform on the sphere.
+        let p = r.conj_point(Point::new(1.0, 0.0, 0.0));
+        mean[0] += p.x() / N as f32;
+        mean[1] += p.y() / N as f32;
+        mean[2] += p.z() / N as f32;
+    }
+    assert!(mean.iter().all(|m| m.abs() < 0.05), "{:?}", mean);
+}
+
+#[test]
+fn direction_uniform() {
+    let mut rng = rng();
+    let mut mean = [0.0; 3];
+    let mut upper = 0;
+    for _ in 0..N {
+        let d: Direction = rng.gen();
+        let n = d.x() * d.x() + d.y() * d.y() + d.z() * d.z();
+        assert!((n - 1.0).abs() < 1e-5);
+        mean[0] += d.x() / N as f32;
+        mean[1] += d.y() / N as f32;
+        mean[2] += d.z() / N as f32;
+        if d.z() > 0.5 {
+            upper += 1;
+        }
+    }
+    assert!(mean.iter().all(|m| m.abs() < 0.05), "{:?}", mean);
+    // The cap z > 1/2 covers a quarter of the sphere.
+    assert!((upper as f32 / N as f32 - 0.25).abs() < 0.03);
+}
+
+#[test]
+fn motor_in_region() {
+    let mut rng = rng();
+    let region = Region::new([-1.0, 2.0, 0.0], [1.0, 3.0, 0.5]);
+    for _ in 0..N {
+        let m: Motor = rng.sample(region);
+        assert!((m * m.reversed()).approx_eq(Motor::IDENTITY, 1e-5));
+
+        let o = m.conj_origin();
+        assert!(o.x() >= -1.0 && o.x() <= 1.0);
+        assert!(o.y() >= 2.0 && o.y() <= 3.0);
+        assert!(o.z() >= 0.0 && o.z() <= 0.5);
+    }
+
+    let m: Motor = rng.sample(Region::new([1.0, 2.0, 3.0], [1.0, 2.0, 3.0]));
+    let o = m.conj_origin();
+    assert!((o.x() - 1.0).abs() < 1e-5);
+    assert!((o.y() - 2.0).abs() < 1e-5);
+    assert!((o.z() - 3.0).abs() < 1e-5);
+}
+
+#[test]
+fn plane_and_line_through_region() {
+    let mut rng = rng();
+    let p = Point::new(0.5, -1.0, 2.0);
+    let region = Region::new([0.5, -1.0, 2.0], [0.5, -1.0, 2.0]);
+    for _ in 0..256 {
+        let plane: Plane = rng.sample(region);
+        assert!((plane.norm() - 1.0).abs() < 1e-5);
+        let d = plane.d() + plane.x() * p.x() + plane.y() * p.y() + plane.z() * p.z();
+        assert!(d.abs() < 1e-5);
+
+        let line: Line = rng.sample(region);
+        assert!((line.norm() - 1.0).abs() < 1e-5);
+        assert!((line * line.reversed()).approx_eq(Motor::IDENTITY, 1e-5));
+        // The join of the line with a point on it vanishes.
+        let j = line & p;
+        assert!([j.x(), j.y(), j.z(), j.d()].iter().all(|c| c.abs() < 1e-5));
+    }
+
+    let points: Vec<Point> = (&mut rng).sample_iter(Region::cube(2.0)).take(N).collect();
+    assert!(points
+        .iter()
+        .all(|p| p.x().abs() <= 2.0 && p.y().abs() <= 2.0 && p.z().abs() <= 2.0));
+}
@@ -0,0 +1,142 @@
+use klein::{
+    ray::{Hit, Ray},
+    volume::{Aabb, Obb, Sphere},
+    Direction, Line, Motor, Plane, Point, Rotor, Translator,
+};
+
+fn close(p: Point, x: f32, y: f32, z: f32) -> bool {
+    (p.x() - x).abs() < 1e-4 && (p.y() - y).abs() < 1e-4 && (p.z() - z).abs() < 1e-4
+}
+
+// Normal plane through the hit point with the expected unit normal.
+fn normal_is(hit: &Hit, x: f32, y: f32, z: f32) -> bool {
+    let n = hit.normal;
+    let p = hit.point;
+    let d = n.d() + n.x() * p.x() + n.y() * p.y() + n.z() * p.z();
+    d.abs() < 1e-4
+        && (n.x() - x).abs() < 1e-4
+        && (n.y() - y).abs() < 1e-4
+        && (n.z() - z).abs() < 1e-4
+}
+
+#[test]
+fn ray_and_line() {
+    let ray = Ray::new(Point::new(1.0, 2.0, 3.0), Direction::new(0.0, 2.0, 0.0));
+    assert!(close(ray.at(2.0), 1.0, 4.0, 3.0));
+
+    // Round trip through the carrying line keeps the orientation.
+    let back = Ray::from_line(ray.line());
+    assert!(close(back.origin, 1.0, 0.0, 3.0));
+    assert!((back.direction.y() - 1.0).abs() < 1e-5);
+
+    let l: Line = Point::new(0.0, 1.0, 0.0) & Point::new(1.0, 1.0, 0.0);
+    let ray = Ray::from_line(l);
+    assert!(close(ray.origin, 0.0, 1.0, 0.0));
+    assert!((ray.direction.x() - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn plane_hits() {
+    let ray = Ray::new(Point::new(0.0, 0.0, 5.0), Direction::new(0.0, 0.0, -1.0));
+    // z = 1, given with an unnormalized equation.
+    let hit = ray
+        .intersect_plane(Plane::new(0.0, 0.0, 2.0, -2.0))
+        .unwrap();
+    assert!((hit.distance - 4.0).abs() < 1e-5);
+    assert!(close(hit.point, 0.0, 0.0, 1.0));
+    assert!(normal_is(&hit, 0.0, 0.0, 1.0));
+
+    // The normal faces the ray whatever the plane orientation.
+    let hit = ray
+        .intersect_plane(Plane::new(0.0, 0.0, -1.0, 1.0))
+        .unwrap();
+    assert!(normal_is(&hit, 0.0, 0.0, 1.0));
+
+    // Behind the origin and parallel planes are missed.
+    assert!(ray
+        .intersect_plane(Plane::new(0.0, 0.0, 1.0, -6.0))
+        .is_none());
+    assert!(ray
+        .intersect_plane(Plane::new(1.0, 0.0, 0.0, 0.0))
+        .is_none());
+}
+
+#[test]
+fn triangle_hits() {
+    let (a, b, c) = (
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(2.0, 0.0, 0.0),
+        Point::new(0.0, 2.0, 0.0),
+    );
+    let down = Direction::new(0.0, 0.0, -1.0);
+    let hit = Ray::new(Point::new(0.5, 0.5, 3.0), down)
+        .intersect_triangle(a, b, c)
+        .unwrap();
+    assert!((hit.distance - 3.0).abs() < 1e-5);
+    assert!(normal_is(&hit, 0.0, 0.0, 1.0));
+
+    // Both windings are hit.
+    assert!(Ray::new(Point::new(0.5, 0.5, 3.0), down)
+        .intersect_triangle(a, c, b)
+        .is_some());
+    assert!(Ray::new(Point::new(1.5, 1.5, 3.0), down)
+        .intersect_triangle(a, b, c)
+        .is_none());
+    assert!(Ray::new(Point::new(0.5, 0.5, 3.0), down)
+        .intersect_triangle(a, b, Point::new(4.0, 0.0, 0.0))
+        .is_none());
+}
+
+#[test]
+fn sphere_hits() {
+    let sphere = Sphere::new(Point::new(0.0, 0.0, -5.0), 2.0);
+    let ray = Ray::new(Point::new(0.0, 0.0, 0.0), Direction::new(0.0, 0.0, -1.0));
+    let hit = ray.intersect_sphere(&sphere).unwrap();
+    assert!((hit.distance - 3.0).abs() < 1e-5);
+    assert!(normal_is(&hit, 0.0, 0.0, 1.0));
+
+    // From the inside the exit point is returned.
+    let inside = Ray::new(Point::new(0.0, 0.0, -5.0), Direction::new(1.0, 0.0, 0.0));
+    let hit = inside.intersect_sphere(&sphere).unwrap();
+    assert!(close(hit.point, 2.0, 0.0, -5.0));
+    assert!(normal_is(&hit, 1.0, 0.0, 0.0));
+
+    let miss = Ray::new(Point::new(3.0, 0.0, 0.0), Direction::new(0.0, 0.0, -1.0));
+    assert!(miss.intersect_sphere(&sphere).is_none());
+    let away = Ray::new(Point::new(0.0, 0.0, 0.0), Direction::new(0.0, 0.0, 1.0));
+    assert!(away.intersect_sphere(&sphere).is_none());
+}
+
+#[test]
+fn box_hits() {
+    let aabb = Aabb::new([-1.0, -1.0, -1.0], [1.0, 2.0, 1.0]);
+    let ray = Ray::new(Point::new(-5.0, 0.5, 0.0), Direction::new(1.0, 0.0, 0.0));
+    let hit = ray.intersect_aabb(&aabb).unwrap();
+    assert!((hit.distance - 4.0).abs() < 1e-5);
+    assert!(normal_is(&hit, -1.0, 0.0, 0.0));
+
+    let inside = Ray::new(Point::new(0.0, 0.0, 0.0), Direction::new(0.0, 1.0, 0.0));
+    let hit = inside.intersect_aabb(&aabb).unwrap();
+    assert!(close(hit.point, 0.0, 2.0, 0.0));
+    assert!(normal_is(&hit, 0.0, 1.0, 0.0));
+
+    let miss = Ray::new(Point::new(-5.0, 3.0, 0.0), Direction::new(1.0, 0.0, 0.0));
+    assert!(miss.intersect_aabb(&aabb).is_none());
+
+    // The same box rotated and moved: the hit follows the motor.
+    let motor = Motor::from_translator(Translator::new(4.0, 0.0, 0.0, 1.0))
+        * Motor::from_rotor(Rotor::new(0.9, 0.3, -1.0, 0.2));
+    let obb = Obb::new(motor, [1.0, 1.5, 0.5]);
+    let local = Ray::new(Point::new(0.0, 0.0, 7.0), Direction::new(0.0, 0.0, -1.0));
+    let world = Ray::new(
+        motor.conj_point(local.origin),
+        motor.conj_dir(local.direction),
+    );
+    let hit = world.intersect_obb(&obb).unwrap();
+    assert!((hit.distance - 6.5).abs() < 1e-4);
+
+    let expected = motor.conj_point(Point::new(0.0, 0.0, 0.5));
+    assert!(close(hit.point, expected.x(), expected.y(), expected.z()));
+    let n = motor.conj_dir(Direction::new(0.0, 0.0, 1.0));
+    assert!(normal_is(&hit, n.x(), n.y(), n.z()));
+}
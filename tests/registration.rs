@@ -0,0 +1,142 @@
+use klein::{
+    registration::{register, register_points, Pair},
+    Line, Motor, Plane, Point, Rotor, Translator,
+};
+
+fn motor() -> Motor {
+    Motor::from_translator(Translator::new(2.5, 1.0, -2.0, 0.5))
+        * Motor::from_rotor(Rotor::new(1.2, 0.3, 1.0, -0.6))
+}
+
+fn same(a: Motor, b: Motor, epsilon: f32) -> bool {
+    a.approx_eq(b, epsilon) || a.approx_eq(-b, epsilon)
+}
+
+fn points() -> Vec<Point> {
+    vec![
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(1.0, 0.2, -0.5),
+        Point::new(-0.3, 2.0, 0.1),
+        Point::new(0.4, -0.7, 3.0),
+        Point::new(2.0, 1.0, 1.0),
+    ]
+}
+
+fn lines() -> Vec<Line> {
+    vec![
+        Point::new(0.0, 0.0, 1.0) & Point::new(1.0, 0.0, 1.0),
+        Point::new(2.0, -1.0, 0.0) & Point::new(2.0, 1.0, 0.5),
+        Point::new(-1.0, 0.5, 0.0) & Point::new(-1.0, 0.0, 2.0),
+    ]
+}
+
+fn planes() -> Vec<Plane> {
+    vec![
+        Plane::new(1.0, 0.0, 0.0, -1.0),
+        Plane::new(0.0, 2.0, 0.0, 1.0),
+        Plane::new(0.0, 0.0, 1.0, 3.0),
+        Plane::new(1.0, 1.0, 1.0, -2.0),
+    ]
+}
+
+#[test]
+fn points_kabsch() {
+    let m = motor();
+    let from = points();
+    let to: Vec<Point> = m.conj_points(from.iter().copied()).collect();
+    let fit = register_points(&from, &to).unwrap();
+    assert!(same(fit.motor, m, 1e-4));
+    assert!(fit.residual < 1e-4);
+
+    // Noise shows up in the residual but the fit stays close.
+    let noisy: Vec<Point> = to
+        .iter()
+        .zip(&[0.01, -0.02, 0.015, -0.01, 0.005])
+        .map(|(p, &e)| Point::new(p.x() + e, p.y() - e, p.z() + 0.5 * e))
+        .collect();
+    let fit = register_points(&from, &noisy).unwrap();
+    assert!(fit.residual > 1e-3 && fit.residual < 0.03);
+    assert!(same(fit.motor, m, 2e-2));
+
+    assert!(register_points(&from, &to[1..]).is_none());
+    assert!(register_points(&[], &[]).is_none());
+}
+
+#[test]
+fn lines_and_planes() {
+    let m = motor();
+    let pairs = lines()
+        .into_iter()
+        .map(|l| (Pair::Line(l, m.conj_line(l)), 1.0));
+    let fit = register(pairs).unwrap();
+    assert!(same(fit.motor, m, 1e-3), "{:?}", fit);
+    assert!(fit.residual < 1e-3);
+
+    let pairs = planes()
+        .into_iter()
+        .map(|p| (Pair::Plane(p, m.conj_plane(p)), 1.0));
+    let fit = register(pairs).unwrap();
+    assert!(same(fit.motor, m, 1e-3), "{:?}", fit);
+    assert!(fit.residual < 1e-3);
+}
+
+#[test]
+fn single_pairs_are_exact() {
+    let m = motor();
+    let l = lines()[1].normalized();
+    let fit = register(vec![(Pair::Line(l, m.conj_line(l)), 1.0)]).unwrap();
+    assert!(fit.motor.conj_line(l).approx_eq(m.conj_line(l), 1e-4));
+    assert!(fit.residual < 1e-4);
+
+    let p = planes()[3].normalized();
+    let fit = register(vec![(Pair::Plane(p, m.conj_plane(p)), 2.0)]).unwrap();
+    assert!(fit.motor.conj_plane(p).approx_eq(m.conj_plane(p), 1e-4));
+
+    let a = Point::new(1.0, 2.0, 3.0);
+    let fit = register(vec![(Pair::Point(a, m.conj_point(a)), 1.0)]).unwrap();
+    assert!(fit.residual < 1e-4);
+}
+
+#[test]
+fn single_flipped_pairs() {
+    let t = Motor::from_translator(Translator::new(1.5, 0.2, 1.0, -0.4));
+    for &offset in &[Motor::IDENTITY, t] {
+        for &l in &lines() {
+            let l = l.normalized();
+            let target = -offset.conj_line(l);
+            let fit = register(vec![(Pair::Line(l, target), 1.0)]).unwrap();
+            assert!(fit.motor.conj_line(l).approx_eq(target, 1e-4));
+            assert!(fit.residual < 1e-4);
+        }
+
+        for &p in &planes() {
+            let p = p.normalized();
+            let target = -offset.conj_plane(p);
+            let fit = register(vec![(Pair::Plane(p, target), 1.0)]).unwrap();
+            assert!(fit.motor.conj_plane(p).approx_eq(target, 1e-4));
+            assert!(fit.residual < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn weighted_mix() {
+    let m = motor();
+    let mut pairs = vec![
+        (Pair::Point(points()[0], m.conj_point(points()[0])), 1.0),
+        (Pair::Line(lines()[0], m.conj_line(lines()[0])), 2.0),
+        (Pair::Plane(planes()[3], m.conj_plane(planes()[3])), 0.5),
+    ];
+    let fit = register(pairs.iter().copied()).unwrap();
+    assert!(same(fit.motor, m, 1e-3), "{:?}", fit);
+
+    // An outlier with zero weight is ignored, one with weight pulls the fit.
+    let outlier = Pair::Point(Point::new(5.0, 5.0, 5.0), Point::new(0.0, 0.0, 0.0));
+    pairs.push((outlier, 0.0));
+    let fit = register(pairs.iter().copied()).unwrap();
+    assert!(same(fit.motor, m, 1e-3));
+    pairs.last_mut().unwrap().1 = 1.0;
+    assert!(register(pairs.iter().copied()).unwrap().residual > 0.1);
+
+    assert!(register(vec![(Pair::Point(points()[0], points()[1]), 0.0)]).is_none());
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "serde")]
+
+use klein::{Branch, Direction, Dual, IdealLine, Line, Motor, Plane, Point, Rotor, Translator};
+use serde::{Deserialize, Serialize};
+
+fn round_trip<T>(value: T) -> T
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    let json = serde_json::to_string(&value).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn point_fields() {
+    let p = Point::new(1.0, 2.0, 3.0);
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"x":1.0,"y":2.0,"z":3.0,"w":1.0}"#);
+
+    let q: Point = serde_json::from_str(r#"{"w":1.0,"z":3.0,"y":2.0,"x":1.0}"#).unwrap();
+    assert_eq!(q.x(), 1.0);
+    assert_eq!(q.y(), 2.0);
+    assert_eq!(q.z(), 3.0);
+    assert_eq!(q.w(), 1.0);
+}
+
+#[test]
+fn motor_fields() {
+    let m = Motor::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"scalar":1.0,"e23":2.0,"e31":3.0,"e12":4.0,"#,
+            r#""e0123":8.0,"e01":5.0,"e02":6.0,"e03":7.0}"#,
+        )
+    );
+
+    let n: Motor = round_trip(m);
+    assert_eq!(m, n);
+}
+
+#[test]
+fn round_trip_all() {
+    let plane = Plane::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(round_trip(plane), plane);
+
+    let rotor = Rotor::new(1.0, 1.0, 2.0, 3.0);
+    assert_eq!(round_trip(rotor), rotor);
+
+    let line = Line::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    assert_eq!(round_trip(line), line);
+
+    let p = round_trip(Point::new(-1.0, 0.5, 2.0));
+    assert_eq!((p.x(), p.y(), p.z(), p.w()), (-1.0, 0.5, 2.0, 1.0));
+
+    let d = Direction::new(1.0, 2.0, 3.0);
+    let e = round_trip(d);
+    assert_eq!((e.x(), e.y(), e.z()), (d.x(), d.y(), d.z()));
+
+    let b = round_trip(Branch::new(1.0, 2.0, 3.0));
+    assert_eq!((b.x(), b.y(), b.z()), (1.0, 2.0, 3.0));
+
+    let i = round_trip(IdealLine::new(1.0, 2.0, 3.0));
+    assert_eq!((i.e01(), i.e02(), i.e03()), (1.0, 2.0, 3.0));
+
+    let t = Translator::new(2.0, 1.0, 0.0, 0.0);
+    let u = round_trip(t);
+    assert_eq!((u.e01(), u.e02(), u.e03()), (t.e01(), t.e02(), t.e03()));
+
+    let dual = round_trip(Dual::new(1.0, 2.0));
+    assert_eq!((dual.scalar(), dual.e0123()), (1.0, 2.0));
+}
+
+#[test]
+fn sequence_form() {
+    let p: Point = serde_json::from_str("[1.0, 2.0, 3.0, 1.0]").unwrap();
+    assert_eq!((p.x(), p.y(), p.z(), p.w()), (1.0, 2.0, 3.0, 1.0));
+
+    assert!(serde_json::from_str::<Point>("[1.0, 2.0, 3.0]").is_err());
+}
+
+#[test]
+fn rejects_bad_fields() {
+    assert!(serde_json::from_str::<Point>(r#"{"x":1.0,"y":2.0,"z":3.0}"#).is_err());
+    assert!(serde_json::from_str::<Point>(r#"{"x":1.0,"y":2.0,"z":3.0,"w":1.0,"v":0.0}"#).is_err());
+    assert!(serde_json::from_str::<Point>(r#"{"x":1.0,"x":2.0,"z":3.0,"w":1.0}"#).is_err());
+}
+
+#[derive(Serialize, Deserialize)]
+struct Pose {
+    #[serde(with = "klein::serde_array")]
+    motor: Motor,
+    #[serde(with = "klein::serde_array")]
+    origin: Point,
+}
+
+#[test]
+fn array_form() {
+    let pose = Pose {
+        motor: Motor::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0),
+        origin: Point::new(1.0, 2.0, 3.0),
+    };
+
+    let json = serde_json::to_string(&pose).unwrap();
+    assert_eq!(
+        json,
+        r#"{"motor":[1.0,2.0,3.0,4.0,8.0,5.0,6.0,7.0],"origin":[1.0,2.0,3.0,1.0]}"#
+    );
+
+    let back: Pose = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.motor, pose.motor);
+    assert_eq!(back.origin.x(), 1.0);
+    assert_eq!(back.origin.w(), 1.0);
+}
@@ -0,0 +1,148 @@
+use klein::{
+    volume::{Aabb, Obb, Sphere},
+    Motor, Point, Rotor, Translator,
+};
+
+fn motor() -> Motor {
+    Motor::from_translator(Translator::new(3.0, 1.0, -2.0, 0.5))
+        * Motor::from_rotor(Rotor::new(1.1, 0.4, 1.0, -0.3))
+}
+
+fn cloud() -> Vec<Point> {
+    // A flat slab of points stretched along a skew direction.
+    let m = motor();
+    (0..64)
+        .map(|i| {
+            let t = i as f32 / 63.0;
+            let s = ((i * 7) % 11) as f32 / 10.0;
+            m.conj_point(Point::new(4.0 * t - 2.0, s - 0.5, 0.1 * (t - s)))
+        })
+        .collect()
+}
+
+#[test]
+fn aabb_queries() {
+    let points = [
+        Point::new(1.0, -2.0, 0.0),
+        Point::new(-1.0, 3.0, 2.0),
+        Point::new(0.5, 0.0, -1.0),
+    ];
+    let aabb = Aabb::from_points(&points).unwrap();
+    assert_eq!(aabb, Aabb::new([-1.0, -2.0, -1.0], [1.0, 3.0, 2.0]));
+    assert!(Aabb::from_points(&[]).is_none());
+    assert!(Aabb::EMPTY.is_empty());
+    assert_eq!(Aabb::EMPTY.union(&aabb), aabb);
+
+    assert!(aabb.contains_point(Point::new(0.0, 0.0, 0.0)));
+    assert!(!aabb.contains_point(Point::new(0.0, 3.5, 0.0)));
+    assert!(aabb.contains_aabb(&Aabb::new([0.0; 3], [1.0; 3])));
+    assert!(!aabb.contains_aabb(&Aabb::new([0.0; 3], [2.0; 3])));
+    assert!(aabb.intersects_aabb(&Aabb::new([0.5; 3], [5.0; 3])));
+    assert!(!aabb.intersects_aabb(&Aabb::new([1.5, 0.0, 0.0], [5.0; 3])));
+
+    assert!(aabb.intersects_sphere(&Sphere::new(Point::new(2.0, 0.0, 0.0), 1.0)));
+    assert!(!aabb.intersects_sphere(&Sphere::new(Point::new(2.0, 4.0, 0.0), 1.0)));
+}
+
+#[test]
+fn aabb_refit_is_conservative() {
+    let aabb = Aabb::new([-1.0, 0.0, 2.0], [2.0, 1.0, 3.0]);
+    let m = motor();
+    let moved = m.conj_aabb(&aabb);
+    for p in aabb.corners().iter() {
+        assert!(moved.contains_point(m.conj_point(*p)));
+    }
+
+    // A quarter turn about an axis maps the box to a box of the same size.
+    let r = Rotor::new(core::f32::consts::FRAC_PI_2, 0.0, 0.0, 1.0);
+    let turned = r.conj_aabb(&aabb);
+    let [hx, hy, hz] = turned.half_extents();
+    assert!((hx - 0.5).abs() < 1e-5 && (hy - 1.5).abs() < 1e-5 && (hz - 0.5).abs() < 1e-5);
+    assert!(r.conj_aabb(&Aabb::EMPTY).is_empty());
+}
+
+#[test]
+fn obb_transforms_exactly() {
+    let obb = Obb::new(motor(), [1.0, 0.5, 2.0]);
+    let m = Motor::from_rotor(Rotor::new(-0.7, 1.0, 1.0, 0.0));
+    let moved = m.conj_obb(&obb);
+    for (a, b) in obb.corners().iter().zip(moved.corners().iter()) {
+        let a = m.conj_point(*a);
+        assert!((a.x() - b.x()).abs() < 1e-4);
+        assert!((a.y() - b.y()).abs() < 1e-4);
+        assert!((a.z() - b.z()).abs() < 1e-4);
+    }
+
+    let world = obb.motor.conj_point(Point::new(0.9, -0.4, 1.9));
+    assert!(obb.contains_point(world));
+    assert!(moved.contains_point(m.conj_point(world)));
+    assert!(!obb.contains_point(obb.motor.conj_point(Point::new(0.9, -0.6, 1.9))));
+    assert!(obb.aabb().contains_point(world));
+
+    let aabb = Aabb::new([-1.0, 0.0, 2.0], [2.0, 1.0, 3.0]);
+    let from = Obb::from_aabb(&aabb);
+    assert!(from.contains_point(Point::new(1.9, 0.1, 2.9)));
+    assert!(!from.contains_point(Point::new(2.1, 0.1, 2.9)));
+}
+
+#[test]
+fn obb_from_points() {
+    let points = cloud();
+    let obb = Obb::from_points(&points).unwrap();
+    for p in &points {
+        let local = obb.motor.reversed().conj_point(*p);
+        let h = obb.half_extents;
+        assert!(local.x().abs() <= h[0] + 1e-4);
+        assert!(local.y().abs() <= h[1] + 1e-4);
+        assert!(local.z().abs() <= h[2] + 1e-4);
+    }
+    // Aligned with the slab, the box is much thinner than the world box.
+    let volume = |h: [f32; 3]| h[0] * h[1] * h[2];
+    let aabb = Aabb::from_points(&points).unwrap();
+    assert!(volume(obb.half_extents) < 0.5 * volume(aabb.half_extents()));
+    assert!(Obb::from_points(&[]).is_none());
+}
+
+#[test]
+fn obb_overlap() {
+    let a = Obb::new(Motor::IDENTITY, [1.0, 1.0, 1.0]);
+    let near = Motor::from_translator(Translator::new(2.2, 1.0, 0.0, 0.0));
+    let b = Obb::new(near, [1.0, 1.0, 1.0]);
+    assert!(!a.intersects_obb(&b));
+
+    // Turning the second box about z by 45 degrees reaches into the first.
+    let turned = near * Motor::from_rotor(Rotor::new(core::f32::consts::FRAC_PI_4, 0.0, 0.0, 1.0));
+    let c = Obb::new(turned, [1.0, 1.0, 1.0]);
+    assert!(a.intersects_obb(&c));
+    assert!(c.intersects_obb(&a));
+
+    // Separated along an edge-edge axis only.
+    let edge = Motor::from_translator(Translator::new(2.6, 1.0, 1.0, 0.0))
+        * Motor::from_rotor(Rotor::new(core::f32::consts::FRAC_PI_4, 1.0, -1.0, 0.0));
+    let d = Obb::new(edge, [1.0, 0.2, 0.2]);
+    assert_eq!(a.intersects_obb(&d), d.intersects_obb(&a));
+
+    assert!(a.intersects_sphere(&Sphere::new(Point::new(1.5, 1.5, 0.0), 0.75)));
+    assert!(!a.intersects_sphere(&Sphere::new(Point::new(1.5, 1.5, 0.0), 0.6)));
+}
+
+#[test]
+fn sphere_queries() {
+    let points = cloud();
+    let sphere = Sphere::from_points(&points).unwrap();
+    assert!(points
+        .iter()
+        .all(|&p| Sphere::new(sphere.center, sphere.radius + 1e-4).contains_point(p)));
+
+    let a = Sphere::new(Point::new(0.0, 0.0, 0.0), 2.0);
+    let b = Sphere::new(Point::new(1.0, 0.0, 0.0), 0.5);
+    assert!(a.contains_sphere(&b));
+    assert!(!b.contains_sphere(&a));
+    assert!(a.intersects_sphere(&Sphere::new(Point::new(3.0, 0.0, 0.0), 1.1)));
+    assert!(!a.intersects_sphere(&Sphere::new(Point::new(3.0, 0.0, 0.0), 0.9)));
+
+    let m = motor();
+    let moved = m.conj_sphere(&b);
+    assert!(moved.contains_point(m.conj_point(Point::new(1.4, 0.0, 0.0))));
+    assert_eq!(moved.radius, 0.5);
+}